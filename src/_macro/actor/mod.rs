@@ -2,14 +2,25 @@
 macro_rules! create_actor {
   ($data: expr, $name: expr, $description: expr, $gender: expr) => {{
     use $crate::ai::{Ai, MoveRandomly};
+    use $crate::containment::*;
     let actor = $data.entities.create();
     is_an_actor!($data, actor);
     has_name!($data, actor, $name);
+    has_vocabulary_from_name!($data, actor, $name);
     has_initiative!($data, actor, 0, 251);
     has_brief_description!($data, actor, $description);
     has_gender!($data, actor, $gender);
+    has_disposition!($data, actor, Disposition::default());
     has_ai!($data, actor, Ai::MoveRandomly(MoveRandomly {}));
     has_state!($data, actor);
+    has_needs!($data, actor);
+    has_capacity!(
+      $data,
+      actor,
+      Containment::CarriedBy,
+      CARRYING_CAPACITY_COUNT,
+      CARRYING_CAPACITY_WEIGHT
+    );
     actor
   }};
   ($data: expr, $name: expr, $description: expr, $gender: expr, $room_id: expr) => {{
@@ -7,9 +7,12 @@ macro_rules! get_command_event_channel {
 
 #[macro_export]
 macro_rules! write_command_event {
-  ($data: expr, $command: expr) => {{
+  ($data: expr, $player_id: expr, $command: expr) => {{
     #[allow(unused_imports)]
     use $crate::ecs::event::CommandEvent;
-    get_command_event_channel!($data).single_write(CommandEvent { command: $command });
+    get_command_event_channel!($data).single_write(CommandEvent {
+      player_id: $player_id,
+      command: $command,
+    });
   }};
 }
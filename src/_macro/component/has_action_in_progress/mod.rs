@@ -0,0 +1,43 @@
+#[macro_export]
+macro_rules! has_action_in_progress {
+  ($data: expr, $entity: expr, $in_progress: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::ecs::component::*;
+    $data
+      .has_action_in_progress
+      .insert($entity, HasActionInProgress($in_progress))
+      .expect("Unable to insert has-action-in-progress for entity!");
+  }};
+}
+
+#[macro_export]
+macro_rules! is_busy {
+  ($data: expr, $entity: expr) => {{
+    $data.has_action_in_progress.contains($entity)
+  }};
+}
+
+/// Interrupt whatever an entity is in the middle of, if anything.
+///
+/// Effects that have already been released stand; the rest never happen.
+/// Returns whether there was anything to interrupt.
+#[macro_export]
+macro_rules! interrupt_action {
+  ($data: expr, $entity: expr) => {{
+    match $data.has_action_in_progress.remove($entity) {
+      Some(has_action_in_progress) => {
+        if let Some(description) = has_action_in_progress.0.description {
+          let name = get_name!($data, $entity).cloned().unwrap_or_default();
+          narrate!(
+            $data,
+            $entity,
+            format!("You stop trying to {}.", description),
+            format!("{} stops trying to {}.", name, description)
+          );
+        }
+        true
+      },
+      None => false,
+    }
+  }};
+}
@@ -0,0 +1,38 @@
+#[macro_export]
+macro_rules! has_adjectives {
+  ($data: expr, $entity: expr, $adjectives: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::ecs::component::*;
+    let adjectives = $adjectives.iter().map(|adjective| adjective.to_lowercase()).collect();
+    $data
+      .has_adjectives
+      .insert($entity, HasAdjectives(adjectives))
+      .expect("Unable to insert has-adjectives for entity!");
+  }};
+}
+
+#[macro_export]
+macro_rules! get_adjectives {
+  ($data: expr, $entity: expr) => {{
+    $data
+      .has_adjectives
+      .get($entity)
+      .map(|has_adjectives| &has_adjectives.0)
+  }};
+}
+
+/// Give an entity the vocabulary suggested by its name.
+///
+/// The last word of the name is its noun, and any others are adjectives, so
+/// "Green Goblin" is a goblin, and a green one.
+#[macro_export]
+macro_rules! has_vocabulary_from_name {
+  ($data: expr, $entity: expr, $name: expr) => {{
+    let name = $name.to_string();
+    let mut words = name.split_whitespace().collect::<Vec<&str>>();
+    if let Some(noun) = words.pop() {
+      has_nouns!($data, $entity, [noun]);
+      has_adjectives!($data, $entity, words);
+    }
+  }};
+}
@@ -0,0 +1,36 @@
+/// Afflict an entity with an ailment.
+#[macro_export]
+macro_rules! has_ailment {
+  ($data: expr, $entity: expr, $ailment: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::ecs::component::*;
+    $data
+      .has_ailments
+      .entry($entity)
+      .expect("Unable to get has-ailments for entity!")
+      .or_insert_with(HasAilments::default)
+      .0
+      .insert($ailment);
+  }};
+}
+
+/// Cure an entity of an ailment.
+#[macro_export]
+macro_rules! remove_ailment {
+  ($data: expr, $entity: expr, $ailment: expr) => {{
+    if let Some(has_ailments) = $data.has_ailments.get_mut($entity) {
+      has_ailments.0.remove(&$ailment);
+    }
+  }};
+}
+
+/// Whether an entity suffers from an ailment.
+#[macro_export]
+macro_rules! is_ailing {
+  ($data: expr, $entity: expr, $ailment: expr) => {{
+    $data
+      .has_ailments
+      .get($entity)
+      .map_or(false, |has_ailments| has_ailments.0.contains(&$ailment))
+  }};
+}
@@ -0,0 +1,18 @@
+#[macro_export]
+macro_rules! has_camera {
+  ($data: expr, $entity: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::ecs::component::*;
+    $data
+      .has_camera
+      .insert($entity, HasCamera)
+      .expect("Unable to insert has-camera for entity!");
+  }};
+}
+
+#[macro_export]
+macro_rules! remove_has_camera {
+  ($data: expr, $entity: expr) => {{
+    $data.has_camera.remove($entity);
+  }};
+}
@@ -0,0 +1,44 @@
+#[macro_export]
+macro_rules! has_capacity {
+  ($data: expr, $entity: expr, $containment: expr, $maximum_count: expr, $maximum_weight: expr) => {{
+    use $crate::containment::Capacity;
+    #[allow(unused_imports)]
+    use $crate::ecs::component::*;
+    $data
+      .has_capacity
+      .insert(
+        $entity,
+        HasCapacity(Capacity::new($containment, $maximum_count, $maximum_weight)),
+      )
+      .expect("Unable to insert has-capacity for entity!");
+  }};
+}
+
+#[macro_export]
+macro_rules! get_capacity {
+  ($data: expr, $entity: expr) => {{
+    $data.has_capacity.get($entity).map(|has_capacity| has_capacity.0)
+  }};
+}
+
+/// Check whether a container has room for an entity, held in the given way.
+#[macro_export]
+macro_rules! check_capacity {
+  ($data: expr, $container: expr, $containment: expr, $entity: expr) => {{
+    use $crate::containment::ContainmentError;
+    let container = $container;
+    let containment = $containment;
+    let entity = $entity;
+    match get_capacity!($data, container) {
+      Some(capacity) if capacity.containment == containment => {
+        let contents = get_contents!($data, container, containment);
+        let mut weight = 0;
+        for content in contents.iter() {
+          weight += get_total_weight!($data, *content);
+        }
+        capacity.check(contents.len(), weight, get_total_weight!($data, entity))
+      },
+      _ => Err(ContainmentError::NotPossible),
+    }
+  }};
+}
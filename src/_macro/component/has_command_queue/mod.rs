@@ -0,0 +1,42 @@
+#[macro_export]
+macro_rules! queue_commands {
+  ($data: expr, $entity: expr, $commands: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::ecs::component::*;
+    $data
+      .has_command_queue
+      .insert($entity, HasCommandQueue($commands.into_iter().collect()))
+      .expect("Unable to insert has-command-queue for entity!");
+  }};
+}
+
+#[macro_export]
+macro_rules! dequeue_command {
+  ($data: expr, $entity: expr) => {{
+    $data
+      .has_command_queue
+      .get_mut($entity)
+      .and_then(|has_command_queue| has_command_queue.0.pop_front())
+  }};
+}
+
+#[macro_export]
+macro_rules! peek_command {
+  ($data: expr, $entity: expr) => {{
+    $data
+      .has_command_queue
+      .get($entity)
+      .and_then(|has_command_queue| has_command_queue.0.front())
+  }};
+}
+
+#[macro_export]
+macro_rules! cancel_commands {
+  ($data: expr, $entity: expr) => {{
+    $data
+      .has_command_queue
+      .get_mut($entity)
+      .map(|has_command_queue| std::mem::take(&mut has_command_queue.0).len())
+      .unwrap_or(0)
+  }};
+}
@@ -0,0 +1,24 @@
+#[macro_export]
+macro_rules! has_disposition {
+  ($data: expr, $entity: expr, $disposition: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::disposition::Disposition;
+    #[allow(unused_imports)]
+    use $crate::ecs::component::*;
+    $data
+      .has_disposition
+      .insert($entity, HasDisposition($disposition))
+      .expect("Unable to insert has-disposition for entity!");
+  }};
+}
+
+#[macro_export]
+macro_rules! get_disposition {
+  ($data: expr, $entity: expr) => {{
+    $data
+      .has_disposition
+      .get($entity)
+      .map(|has_disposition| has_disposition.0)
+      .unwrap_or_default()
+  }};
+}
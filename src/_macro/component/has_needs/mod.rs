@@ -0,0 +1,16 @@
+#[macro_export]
+macro_rules! has_needs {
+  ($data: expr, $entity: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::ecs::component::*;
+    use $crate::need::Need;
+    let needs = [Need::Food, Need::Water, Need::Sleep]
+      .into_iter()
+      .map(|need| (need, 0))
+      .collect();
+    $data
+      .has_needs
+      .insert($entity, HasNeeds(needs))
+      .expect("Unable to insert has-needs for entity!");
+  }};
+}
@@ -0,0 +1,19 @@
+#[macro_export]
+macro_rules! has_nouns {
+  ($data: expr, $entity: expr, $nouns: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::ecs::component::*;
+    let nouns = $nouns.iter().map(|noun| noun.to_lowercase()).collect();
+    $data
+      .has_nouns
+      .insert($entity, HasNouns(nouns))
+      .expect("Unable to insert has-nouns for entity!");
+  }};
+}
+
+#[macro_export]
+macro_rules! get_nouns {
+  ($data: expr, $entity: expr) => {{
+    $data.has_nouns.get($entity).map(|has_nouns| &has_nouns.0)
+  }};
+}
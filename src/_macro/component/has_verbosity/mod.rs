@@ -0,0 +1,22 @@
+#[macro_export]
+macro_rules! has_verbosity {
+  ($data: expr, $entity: expr, $verbosity: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::ecs::component::*;
+    $data
+      .has_verbosity
+      .insert($entity, HasVerbosity($verbosity))
+      .expect("Unable to insert has-verbosity for entity!");
+  }};
+}
+
+#[macro_export]
+macro_rules! get_verbosity {
+  ($data: expr, $entity: expr) => {{
+    $data
+      .has_verbosity
+      .get($entity)
+      .map(|has_verbosity| has_verbosity.0)
+      .unwrap_or_default()
+  }};
+}
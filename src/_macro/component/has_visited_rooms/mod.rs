@@ -0,0 +1,25 @@
+#[macro_export]
+macro_rules! has_visited_room {
+  ($data: expr, $entity: expr, $room_id: expr) => {{
+    $data
+      .has_visited_rooms
+      .get($entity)
+      .map(|has_visited_rooms| has_visited_rooms.0.contains(&$room_id))
+      .unwrap_or(false)
+  }};
+}
+
+#[macro_export]
+macro_rules! visit_room {
+  ($data: expr, $entity: expr, $room_id: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::ecs::component::*;
+    $data
+      .has_visited_rooms
+      .entry($entity)
+      .expect("Unable to get has-visited-rooms for entity!")
+      .or_insert_with(HasVisitedRooms::default)
+      .0
+      .insert($room_id);
+  }};
+}
@@ -0,0 +1,44 @@
+#[macro_export]
+macro_rules! has_weight {
+  ($data: expr, $entity: expr, $weight: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::ecs::component::*;
+    $data
+      .has_weight
+      .insert($entity, HasWeight($weight))
+      .expect("Unable to insert has-weight for entity!");
+  }};
+}
+
+#[macro_export]
+macro_rules! get_weight {
+  ($data: expr, $entity: expr) => {{
+    $data
+      .has_weight
+      .get($entity)
+      .map(|has_weight| has_weight.0)
+      .unwrap_or($crate::containment::DEFAULT_WEIGHT)
+  }};
+}
+
+/// Get the weight of an entity and everything it holds.
+///
+/// An actor's weight doesn't count, only what they carry, since no one is
+/// going to pick them up.
+#[macro_export]
+macro_rules! get_total_weight {
+  ($data: expr, $entity: expr) => {{
+    let mut result = match $data.is_an_actor.get($entity) {
+      Some(_) => 0,
+      None => get_weight!($data, $entity),
+    };
+    let mut containers = vec![$entity];
+    while let Some(container) = containers.pop() {
+      for content in get_all_contents!($data, container) {
+        result += get_weight!($data, content);
+        containers.push(content);
+      }
+    }
+    result
+  }};
+}
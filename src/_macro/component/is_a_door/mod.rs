@@ -0,0 +1,84 @@
+#[macro_export]
+macro_rules! is_a_door {
+  ($data: expr, $entity: expr, $door: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::ecs::component::*;
+    $data
+      .is_a_door
+      .insert($entity, IsADoor($door))
+      .expect("Unable to insert is-a-door for entity!");
+  }};
+}
+
+/// Get the state of a door, if the entity is one.
+#[macro_export]
+macro_rules! get_door {
+  ($data: expr, $entity: expr) => {{
+    $data.is_a_door.get($entity).map(|is_a_door| is_a_door.0)
+  }};
+}
+
+/// Get the doors across the visible passages out of a room, and their
+/// directions.
+#[macro_export]
+macro_rules! get_room_doors {
+  ($data: expr, $room: expr) => {{
+    get_passages!($data, $room)
+      .map(|passages| {
+        passages
+          .get_visible_passages()
+          .into_iter()
+          .filter_map(|passage| {
+            passage
+              .door
+              .map(|door_id| (passage.direction, get_entity!($data, door_id)))
+          })
+          .collect::<Vec<_>>()
+      })
+      .unwrap_or_default()
+  }};
+}
+
+/// Get the passages a door stands across; one from each room it's between.
+#[macro_export]
+macro_rules! get_door_passages {
+  ($data: expr, $door: expr) => {{
+    use specs::prelude::*;
+    let door_id = $crate::ecs::entity::EntityId($door.id());
+    (&$data.has_passages)
+      .join()
+      .flat_map(|has_passages| has_passages.get_passages().into_iter().cloned().collect::<Vec<_>>())
+      .filter(|passage| passage.door == Some(door_id))
+      .collect::<Vec<$crate::map::Passage>>()
+  }};
+}
+
+/// Tell anyone on the far side of a door, from a room, what they can make
+/// out of something happening to it.
+///
+/// The message is given the direction of the door from their side.
+#[macro_export]
+macro_rules! narrate_beyond_door {
+  ($data: expr, $door: expr, $room_id: expr, $message: expr) => {{
+    use $crate::perception::{Rendering, Sensation};
+    let room_id = $room_id;
+    for passage in get_door_passages!($data, $door) {
+      if Some(passage.from) == room_id {
+        continue;
+      }
+      write_sensation!(
+        $data,
+        Sensation {
+          actor_id: $crate::ecs::entity::EntityId($door.id()),
+          target_id: None,
+          room_id: Some(passage.from),
+          rendering: Rendering {
+            first_person: None,
+            second_person: None,
+            third_person: Some($message(passage.direction.get_lowercase())),
+          },
+        }
+      );
+    }
+  }};
+}
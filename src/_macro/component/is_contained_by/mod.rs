@@ -0,0 +1,133 @@
+#[macro_export]
+macro_rules! is_contained_by {
+  ($data: expr, $entity: expr, $containment: expr, $container_id: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::ecs::component::*;
+    $data
+      .is_contained_by
+      .insert($entity, IsContainedBy($containment, $container_id))
+      .expect("Unable to insert is-contained-by for entity!");
+  }};
+}
+
+#[macro_export]
+macro_rules! remove_is_contained_by {
+  ($data: expr, $entity: expr) => {{
+    $data.is_contained_by.remove($entity);
+  }};
+}
+
+/// Get how, and by what, an entity is held, if it is.
+#[macro_export]
+macro_rules! get_container {
+  ($data: expr, $entity: expr) => {{
+    $data
+      .is_contained_by
+      .get($entity)
+      .map(|is_contained_by| (is_contained_by.0, is_contained_by.1))
+  }};
+}
+
+/// Get everything held by an entity in the given way, e.g. on the table.
+#[macro_export]
+macro_rules! get_contents {
+  ($data: expr, $container: expr, $containment: expr) => {{
+    use specs::prelude::*;
+    use $crate::ecs::entity::EntityId;
+    let container_id = EntityId($container.id());
+    (&$data.entities, &$data.is_contained_by)
+      .join()
+      .filter(|(_entity, is_contained_by)| is_contained_by.0 == $containment && is_contained_by.1 == container_id)
+      .map(|(entity, _is_contained_by)| entity)
+      .collect::<Vec<Entity>>()
+  }};
+}
+
+/// Get everything held by an entity, however it's held.
+#[macro_export]
+macro_rules! get_all_contents {
+  ($data: expr, $container: expr) => {{
+    use specs::prelude::*;
+    use $crate::ecs::entity::EntityId;
+    let container_id = EntityId($container.id());
+    (&$data.entities, &$data.is_contained_by)
+      .join()
+      .filter(|(_entity, is_contained_by)| is_contained_by.1 == container_id)
+      .map(|(entity, _is_contained_by)| entity)
+      .collect::<Vec<Entity>>()
+  }};
+}
+
+/// Get the entities holding an entity, innermost first.
+///
+/// The mushroom in the basket carried by the goblin is held by the basket and
+/// then the goblin.
+#[macro_export]
+macro_rules! get_containers {
+  ($data: expr, $entity: expr) => {{
+    let mut result = Vec::new();
+    let mut current = $entity;
+    while let Some((_containment, container_id)) = get_container!($data, current) {
+      current = get_entity!($data, container_id);
+      if current == $entity || result.contains(&current) {
+        break;
+      }
+      result.push(current);
+    }
+    result
+  }};
+}
+
+/// Get the room an entity is in, or the room its outermost container is in.
+#[macro_export]
+macro_rules! get_outermost_room_id {
+  ($data: expr, $entity: expr) => {{
+    let outermost = get_containers!($data, $entity).pop().unwrap_or($entity);
+    get_current_room_id!($data, outermost)
+  }};
+}
+
+/// Describe everything an entity holds, one sentence for each way it holds
+/// things, e.g. "On the table is the cup.", and then whatever those hold.
+#[macro_export]
+macro_rules! describe_contents {
+  ($data: expr, $container: expr) => {{
+    use $crate::containment::Containment;
+    let mut result = Vec::new();
+    let mut containers = vec![$container];
+    let mut index = 0;
+    while index < containers.len() {
+      let container = containers[index];
+      index += 1;
+      for containment in [Containment::In, Containment::On, Containment::CarriedBy] {
+        let contents = get_contents!($data, container, containment);
+        let content_names = contents
+          .iter()
+          .filter_map(|content| get_lc_name!($data, *content))
+          .collect::<Vec<String>>();
+        containers.extend(contents);
+        if content_names.is_empty() {
+          continue;
+        }
+        let container_name = match containment {
+          Containment::CarriedBy => get_name!($data, container).cloned(),
+          _ => get_lc_name!($data, container),
+        };
+        if let Some(container_name) = container_name {
+          result.push(containment.describe_contents(&container_name, &content_names));
+        }
+      }
+    }
+    result
+  }};
+}
+
+/// Get the actor holding an entity, if any, even by way of a container.
+#[macro_export]
+macro_rules! get_holder {
+  ($data: expr, $entity: expr) => {{
+    get_containers!($data, $entity)
+      .into_iter()
+      .find(|container| $data.is_an_actor.get(*container).is_some())
+  }};
+}
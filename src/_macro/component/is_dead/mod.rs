@@ -0,0 +1,11 @@
+#[macro_export]
+macro_rules! is_dead {
+  ($data: expr, $entity: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::ecs::component::*;
+    $data
+      .is_dead
+      .insert($entity, IsDead)
+      .expect("Unable to insert is-dead for entity!");
+  }};
+}
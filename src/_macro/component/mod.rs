@@ -1,8 +1,22 @@
 #[macro_use]
+pub mod has_action_in_progress;
+#[macro_use]
+pub mod has_adjectives;
+#[macro_use]
+pub mod has_ailments;
+#[macro_use]
 pub mod has_ai;
 #[macro_use]
 pub mod has_brief_description;
 #[macro_use]
+pub mod has_camera;
+#[macro_use]
+pub mod has_capacity;
+#[macro_use]
+pub mod has_command_queue;
+#[macro_use]
+pub mod has_disposition;
+#[macro_use]
 pub mod has_gender;
 #[macro_use]
 pub mod has_initiative;
@@ -11,10 +25,22 @@ pub mod has_intent;
 #[macro_use]
 pub mod has_name;
 #[macro_use]
+pub mod has_needs;
+#[macro_use]
+pub mod has_nouns;
+#[macro_use]
 pub mod has_passages;
 #[macro_use]
 pub mod has_state;
 #[macro_use]
+pub mod has_verbosity;
+#[macro_use]
+pub mod has_visited_rooms;
+#[macro_use]
+pub mod has_weight;
+#[macro_use]
+pub mod is_a_door;
+#[macro_use]
 pub mod is_a_player;
 #[macro_use]
 pub mod is_a_room;
@@ -25,4 +51,8 @@ pub mod is_an_actor;
 #[macro_use]
 pub mod is_an_object;
 #[macro_use]
+pub mod is_contained_by;
+#[macro_use]
+pub mod is_dead;
+#[macro_use]
 pub mod is_in_room;
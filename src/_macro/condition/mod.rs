@@ -0,0 +1,42 @@
+/// Check whether a condition holds of an entity, and if not, say why not.
+#[macro_export]
+macro_rules! check_condition {
+  ($data: expr, $entity: expr, $condition: expr) => {{
+    use $crate::condition::Condition::*;
+    use $crate::condition::ConditionError;
+    match $condition {
+      HasAilment(ailment) if !is_ailing!($data, $entity, ailment) => Err(ConditionError::NotAiling(ailment)),
+      DoesNotHaveAilment(ailment) if is_ailing!($data, $entity, ailment) => Err(ConditionError::Ailing(ailment)),
+      // Nothing can fly or swim yet.
+      InMidair => Err(ConditionError::NotInMidair),
+      NotInMidair => Ok(()),
+      Underwater => Err(ConditionError::NotUnderwater),
+      NotUnderwater => Ok(()),
+      _ => Ok(()),
+    }
+  }};
+}
+
+/// Check each of several conditions in turn, stopping at the first that
+/// doesn't hold.
+#[macro_export]
+macro_rules! check_conditions {
+  ($data: expr, $entity: expr, $conditions: expr) => {{
+    let mut result: Result<(), $crate::condition::ConditionError> = Ok(());
+    for condition in $conditions {
+      result = check_condition!($data, $entity, condition);
+      if result.is_err() {
+        break;
+      }
+    }
+    result
+  }};
+}
+
+/// Whether a condition holds of an entity.
+#[macro_export]
+macro_rules! is_condition_met {
+  ($data: expr, $entity: expr, $condition: expr) => {{
+    check_condition!($data, $entity, $condition).is_ok()
+  }};
+}
@@ -6,3 +6,73 @@ macro_rules! create_effect {
     Effect(Arc::new($obj))
   }};
 }
+
+#[macro_export]
+macro_rules! get_set_room_inverse {
+  ($data: expr, $entity_id: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::effect::EntitySetRoom;
+    let entity = get_entity!($data, $entity_id);
+    create_effect!(EntitySetRoom {
+      entity_id: $entity_id.into(),
+      room_id: get_current_room_id!($data, entity),
+    })
+  }};
+}
+
+#[macro_export]
+macro_rules! get_set_container_inverse {
+  ($data: expr, $entity_id: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::effect::EntitySetContainer;
+    let entity = get_entity!($data, $entity_id);
+    create_effect!(EntitySetContainer {
+      entity_id: $entity_id.into(),
+      container: get_container!($data, entity),
+    })
+  }};
+}
+
+#[macro_export]
+macro_rules! get_set_state_inverse {
+  ($data: expr, $entity_id: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::effect::EntitySetState;
+    let entity = get_entity!($data, $entity_id);
+    get_state!($data, entity).map(|state| {
+      create_effect!(EntitySetState {
+        entity_id: $entity_id.into(),
+        state: *state,
+      })
+    })
+  }};
+}
+
+#[macro_export]
+macro_rules! get_set_door_inverse {
+  ($data: expr, $entity_id: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::effect::EntitySetDoor;
+    let entity = get_entity!($data, $entity_id);
+    get_door!($data, entity).map(|door| {
+      create_effect!(EntitySetDoor {
+        entity_id: $entity_id.into(),
+        door,
+      })
+    })
+  }};
+}
+
+#[macro_export]
+macro_rules! get_set_ailment_inverse {
+  ($data: expr, $entity_id: expr, $ailment: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::effect::EntitySetAilment;
+    let entity = get_entity!($data, $entity_id);
+    create_effect!(EntitySetAilment {
+      entity_id: $entity_id.into(),
+      ailment: $ailment,
+      value: is_ailing!($data, entity, $ailment),
+    })
+  }};
+}
@@ -0,0 +1,13 @@
+#[macro_export]
+macro_rules! get_meta_event_channel {
+  ($data: expr) => {{
+    &mut $data.meta_event_channel
+  }};
+}
+
+#[macro_export]
+macro_rules! write_meta_event {
+  ($data: expr, $event: expr) => {{
+    get_meta_event_channel!($data).single_write($event);
+  }};
+}
@@ -13,6 +13,8 @@ pub mod command_event;
 #[macro_use]
 pub mod component;
 #[macro_use]
+pub mod condition;
+#[macro_use]
 pub mod effect;
 #[macro_use]
 pub mod effect_event;
@@ -25,18 +27,26 @@ pub mod format;
 #[macro_use]
 pub mod log;
 #[macro_use]
+pub mod meta_event;
+#[macro_use]
 pub mod object;
 #[macro_use]
+pub mod order_event;
+#[macro_use]
 pub mod output;
 #[macro_use]
 pub mod output_event;
 #[macro_use]
 pub mod passage;
 #[macro_use]
+pub mod perception_event;
+#[macro_use]
 pub mod player;
 #[macro_use]
 pub mod room;
 #[macro_use]
+pub mod sensation;
+#[macro_use]
 pub mod show;
 #[macro_use]
 pub mod they;
@@ -3,6 +3,7 @@ macro_rules! create_object {
   ($data: expr, $name: expr, $brief_description: expr) => {{
     let object = $data.entities.create();
     has_name!($data, object, $name);
+    has_vocabulary_from_name!($data, object, $name);
     has_brief_description!($data, object, $brief_description);
     is_an_object!($data, object);
     object
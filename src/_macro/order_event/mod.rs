@@ -0,0 +1,19 @@
+#[macro_export]
+macro_rules! get_order_event_channel {
+  ($data: expr) => {{
+    &mut $data.order_event_channel
+  }};
+}
+
+#[macro_export]
+macro_rules! write_order_event {
+  ($data: expr, $giver_id: expr, $addressee_id: expr, $action: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::ecs::event::OrderEvent;
+    get_order_event_channel!($data).single_write(OrderEvent {
+      giver_id: $giver_id,
+      addressee_id: $addressee_id,
+      action: $action,
+    });
+  }};
+}
@@ -5,12 +5,30 @@ macro_rules! get_output_event_channel {
   }};
 }
 
+/// Write output, either for everyone or for a single recipient.
+///
+/// The recipient can be anything that converts into an `EntityId`, e.g. a
+/// `PlayerId`, and the output anything that converts into a `Document`, e.g.
+/// a `String`.
 #[macro_export]
 macro_rules! write_output_event {
-  ($data: expr, $string: expr) => {{
+  ($data: expr, $output: expr) => {{
     #[allow(unused_imports)]
     use $crate::ecs::event::OutputEvent;
-    get_output_event_channel!($data).single_write(OutputEvent { string: $string.into() });
+    get_output_event_channel!($data).single_write(OutputEvent {
+      recipient: None,
+      document: $output.into(),
+    });
+  }};
+  ($data: expr, $recipient: expr, $output: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::ecs::entity::EntityId;
+    #[allow(unused_imports)]
+    use $crate::ecs::event::OutputEvent;
+    get_output_event_channel!($data).single_write(OutputEvent {
+      recipient: Some(EntityId::from($recipient)),
+      document: $output.into(),
+    });
   }};
 }
 
@@ -20,7 +38,8 @@ macro_rules! write_output_error {
     #[allow(unused_imports)]
     use $crate::ecs::event::OutputEvent;
     get_output_event_channel!($data).single_write(OutputEvent {
-      string: format!("{}", $error),
+      recipient: None,
+      document: format!("{}", $error).into(),
     });
   }};
 }
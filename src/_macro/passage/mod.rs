@@ -1,6 +1,6 @@
 #[macro_export]
 macro_rules! create_passage {
-  (@inner $data: expr, $from: expr, $to: expr, $direction: expr) => {{
+  (@inner $data: expr, $from: expr, $to: expr, $direction: expr, $properties: expr) => {{
     use $crate::map::Passage;
     use $crate::map::PassageDestination;
     use $crate::ecs::entity::RoomId;
@@ -11,14 +11,20 @@ macro_rules! create_passage {
           direction: $direction.to_owned(),
           from: RoomId($from.id()),
           to: PassageDestination::Room(RoomId($to.id())),
+          door: None,
+          properties: $properties,
         }),
       );
     }
   }};
   ($data: expr, $from: expr, $to: expr, $direction: expr, $bidirectional: expr) => {{
-    create_passage!(@inner $data, $from, $to, $direction);
+    create_passage!($data, $from, $to, $direction, $bidirectional, $crate::map::PassageProperties::default());
+  }};
+  // The properties are only for the way there; any way back is ordinary.
+  ($data: expr, $from: expr, $to: expr, $direction: expr, $bidirectional: expr, $properties: expr) => {{
+    create_passage!(@inner $data, $from, $to, $direction, $properties);
     if $bidirectional {
-      create_passage!(@inner $data, $to, $from, &$direction.get_inverse());
+      create_passage!(@inner $data, $to, $from, &$direction.get_inverse(), $crate::map::PassageProperties::default());
     }
   }};
 }
@@ -46,3 +52,51 @@ macro_rules! get_passage_to {
     result
   }};
 }
+
+/// Create a door across an existing passage and the one leading back.
+///
+/// The door starts out closed, and locked if it has a key.
+#[macro_export]
+macro_rules! create_door {
+  ($data: expr, $from: expr, $direction: expr, $name: expr, $brief_description: expr, $key_id: expr) => {{
+    use $crate::map::{Door, PassageDestination};
+    let door = $data.entities.create();
+    has_name!($data, door, $name);
+    has_vocabulary_from_name!($data, door, $name);
+    has_brief_description!($data, door, $brief_description);
+    is_a_door!($data, door, Door::new($key_id));
+    let mut to_option = None;
+    if let Some(has_passages) = $data.has_passages.get_mut($from) {
+      if let Some(mut passage) = has_passages.get_passage_to($direction).clone() {
+        passage.door = Some($crate::ecs::entity::EntityId(door.id()));
+        if let PassageDestination::Room(to_id) = passage.to {
+          to_option = Some(to_id);
+        }
+        has_passages.set_passage($direction, Some(passage));
+      }
+    }
+    if let Some(to_id) = to_option {
+      let inverse = $direction.get_inverse();
+      if let Some(has_passages) = $data.has_passages.get_mut(get_entity!($data, to_id)) {
+        if let Some(mut passage) = has_passages.get_passage_to(&inverse).clone() {
+          passage.door = Some($crate::ecs::entity::EntityId(door.id()));
+          has_passages.set_passage(&inverse, Some(passage));
+        }
+      }
+    }
+    door
+  }};
+}
+
+/// Hide or reveal the passage out of a room in some direction.
+#[macro_export]
+macro_rules! set_passage_hidden {
+  ($data: expr, $room: expr, $direction: expr, $is_hidden: expr) => {{
+    if let Some(has_passages) = $data.has_passages.get_mut($room) {
+      if let Some(mut passage) = has_passages.get_passage_to($direction).clone() {
+        passage.properties.is_hidden = $is_hidden;
+        has_passages.set_passage($direction, Some(passage));
+      }
+    }
+  }};
+}
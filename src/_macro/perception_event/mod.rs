@@ -0,0 +1,17 @@
+#[macro_export]
+macro_rules! get_perception_event_channel {
+  ($data: expr) => {{
+    &mut $data.perception_event_channel
+  }};
+}
+
+#[macro_export]
+macro_rules! write_perception_event {
+  ($data: expr, $perception: expr) => {{
+    #[allow(unused_imports)]
+    use $crate::ecs::event::PerceptionEvent;
+    get_perception_event_channel!($data).single_write(PerceptionEvent {
+      perception: $perception,
+    });
+  }};
+}
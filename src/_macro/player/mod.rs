@@ -1,6 +1,7 @@
 #[macro_export]
 macro_rules! create_player {
   ($data: expr, $gender: expr) => {{
+    use $crate::containment::*;
     let player = $data.entities.create();
     is_an_actor!($data, player);
     is_a_player!($data, player);
@@ -8,12 +9,19 @@ macro_rules! create_player {
     has_initiative!($data, player, 0, 1);
     has_brief_description!($data, player, "It's you, you idiot!");
     has_gender!($data, player, $gender);
+    has_needs!($data, player);
+    has_capacity!(
+      $data,
+      player,
+      Containment::CarriedBy,
+      CARRYING_CAPACITY_COUNT,
+      CARRYING_CAPACITY_WEIGHT
+    );
     player
   }};
-  ($data: expr, $in_room: expr, $gender: expr) => {{
-    use $crate::ecs::component::*;
-    let player = create_player!($data);
-    is_in_room!($data, player, $room_id, $gender);
+  ($data: expr, $gender: expr, $room_id: expr) => {{
+    let player = create_player!($data, $gender);
+    is_in_room!($data, player, $room_id);
     player
   }};
 }
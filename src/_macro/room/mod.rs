@@ -10,21 +10,30 @@ macro_rules! create_room {
   }};
 }
 
+/// Describe a room as a structured document.
+///
+/// A brief description leaves out the room's own description, on the theory
+/// that the reader has seen it before.  Whatever the things in the room hold
+/// is described after them.
 #[macro_export]
 macro_rules! format_room {
   ($data: expr, $room: expr) => {{
+    format_room!($data, $room, false)
+  }};
+  ($data: expr, $room: expr, $brief: expr) => {{
     use specs::prelude::*;
-    use $crate::ecs::entity::RoomId;
-    let mut string = String::new();
+    use $crate::ecs::entity::{EntityId, RoomId};
+    use $crate::output::{Block, Color, Document, Span, Style};
+    let mut document = Document::new();
     let room_id = RoomId($room.id());
     if let Some(name) = get_name!($data, $room) {
-      string.push_str(format!("<bold>{}<reset>\n", name).as_str());
+      document.push(Block::RoomHeader(name.to_string()));
     }
-    if let Some(description) = get_brief_description!($data, $room) {
-      string.push_str(format!("{}\n", description.0).as_str());
+    if let Some(description) = get_brief_description!($data, $room).filter(|_| !$brief) {
+      document.push(Block::Paragraph(vec![Span::new(&description.0)]));
     }
     {
-      for (_entities, _is_in_room, _is_an_object, has_brief_description) in (
+      for (entity, _is_in_room, _is_an_object, has_brief_description) in (
         &$data.entities,
         &$data.is_in_room,
         &$data.is_an_object,
@@ -33,11 +42,18 @@ macro_rules! format_room {
         .join()
         .filter(|(_entity, is_in_room, _is_an_object, _has_brief_description)| is_in_room.0 == room_id)
       {
-        string.push_str(format!("<fg_ext180>{}<reset>\n", has_brief_description.0).as_str());
+        document.push(Block::Paragraph(vec![Span::entity(
+          EntityId(entity.id()),
+          &has_brief_description.0,
+          Style::foreground(Color::Extended(180)),
+        )]));
+        for sentence in describe_contents!($data, entity) {
+          document.push(Block::Paragraph(vec![Span::new(&sentence)]));
+        }
       }
     }
     {
-      for (_entities, _is_in_room, _is_an_actor, has_brief_description, _is_a_player) in (
+      for (entity, _is_in_room, _is_an_actor, has_brief_description, _is_a_player) in (
         &$data.entities,
         &$data.is_in_room,
         &$data.is_an_actor,
@@ -47,19 +63,37 @@ macro_rules! format_room {
         .join()
         .filter(|(_entity, is_in_room, _is_an_actor, _has_brief_description, _)| is_in_room.0 == room_id)
       {
-        string.push_str(format!("<fg_ext162>{}<reset>\n", has_brief_description.0).as_str());
+        document.push(Block::Paragraph(vec![Span::entity(
+          EntityId(entity.id()),
+          &has_brief_description.0,
+          Style::foreground(Color::Extended(162)),
+        )]));
+        for sentence in describe_contents!($data, entity) {
+          document.push(Block::Paragraph(vec![Span::new(&sentence)]));
+        }
+      }
+    }
+    for (direction, door) in get_room_doors!($data, $room) {
+      if let (Some(lc_name), Some(state)) = (get_lc_name!($data, door), get_door!($data, door)) {
+        let sentence = format!(
+          "The {} to the {} is {}.",
+          lc_name,
+          direction.get_lowercase(),
+          state.get_lowercase()
+        );
+        document.push(Block::Paragraph(vec![Span::new(&sentence)]));
       }
     }
     if let Some(passages) = get_passages!($data, $room) {
-      string.push_str(format!("<green>{}<reset>\n", passages).as_str());
+      document.push(Block::Exits(passages.get_visible_directions()));
     }
     let tile_map_resource_option = &mut $data.tile_map_resource.0;
     if let Some(ref mut tile_map) = tile_map_resource_option {
       let room_coords = tile_map.room_coords_map.get(&room_id).cloned().unwrap();
       tile_map.player_coordinates = Some(room_coords);
       tile_map.mark_visible(room_coords.0, room_coords.1);
-      string.push_str(format!("{}", $data.tile_map_resource.0.as_ref().unwrap()).as_str());
+      document.push(Block::Preformatted(tile_map.get_spans()));
     }
-    format!("{}", string)
+    document
   }};
 }
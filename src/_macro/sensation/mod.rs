@@ -0,0 +1,90 @@
+/// Every entity in a room that could perceive something happening there, and
+/// whether each has a camera.
+#[macro_export]
+macro_rules! get_perceivers {
+  ($data: expr, $room_id: expr) => {{
+    use specs::prelude::*;
+    let room_id = $room_id;
+    (&$data.entities, &$data.is_in_room)
+      .join()
+      .filter(|(_entity, is_in_room)| room_id == Some(is_in_room.0))
+      .filter(|(entity, _is_in_room)| $data.is_an_actor.contains(*entity) || $data.has_camera.contains(*entity))
+      .map(|(entity, _is_in_room)| (entity, $data.has_camera.contains(entity)))
+      .collect::<Vec<(specs::Entity, bool)>>()
+  }};
+}
+
+/// Deliver a sensation to everyone who can perceive it.
+///
+/// Each entity in the room gets the rendering for its own perspective, as
+/// output if it has a camera and as a `PerceptionEvent` for its AI.  This is
+/// done immediately, so that it's interleaved correctly with other output.
+#[macro_export]
+macro_rules! write_sensation {
+  ($data: expr, $sensation: expr) => {{
+    #[allow(unused_imports)]
+    use specs::prelude::*;
+    use $crate::perception::Perception;
+    let sensation = $sensation;
+    let perceivers = get_perceivers!($data, sensation.room_id);
+    for (perceiver, has_camera) in perceivers {
+      let perceiver_id = $crate::ecs::entity::EntityId(perceiver.id());
+      let perspective = sensation.get_perspective(perceiver_id);
+      if let Some(string) = sensation.rendering.get(perspective) {
+        let string = string.to_owned();
+        if has_camera {
+          write_output_event!($data, perceiver_id, string.clone());
+        }
+        write_perception_event!(
+          $data,
+          Perception {
+            perceiver_id,
+            perspective,
+            string,
+            sensation: sensation.clone(),
+          }
+        );
+      }
+    }
+  }};
+}
+
+/// Describe something an entity does, to the entity and to any onlookers.
+///
+/// The six-argument form also names the entity it's done to, which gets its
+/// own description.
+#[macro_export]
+macro_rules! narrate {
+  ($data: expr, $entity: expr, $first_person: expr, $third_person: expr) => {{
+    use $crate::perception::{Rendering, Sensation};
+    write_sensation!(
+      $data,
+      Sensation {
+        actor_id: $crate::ecs::entity::EntityId($entity.id()),
+        target_id: None,
+        room_id: get_current_room_id!($data, $entity),
+        rendering: Rendering {
+          first_person: Some(String::from($first_person)),
+          second_person: None,
+          third_person: Some(String::from($third_person)),
+        },
+      }
+    );
+  }};
+  ($data: expr, $entity: expr, $target: expr, $first_person: expr, $second_person: expr, $third_person: expr) => {{
+    use $crate::perception::{Rendering, Sensation};
+    write_sensation!(
+      $data,
+      Sensation {
+        actor_id: $crate::ecs::entity::EntityId($entity.id()),
+        target_id: Some($crate::ecs::entity::EntityId($target.id())),
+        room_id: get_current_room_id!($data, $entity),
+        rendering: Rendering {
+          first_person: Some(String::from($first_person)),
+          second_person: Some(String::from($second_person)),
+          third_person: Some(String::from($third_person)),
+        },
+      }
+    );
+  }};
+}
@@ -10,9 +10,9 @@
 macro_rules! show {
   ($data: expr, $entity: expr, $string: expr) => {{
     #[allow(unused_imports)]
-    use $crate::ecs::event::OutputEvent;
+    use $crate::ecs::entity::EntityId;
     if entity_has_camera!($data, $entity) {
-      write_output_event!($data, $string);
+      write_output_event!($data, EntityId($entity.id()), $string);
     }
   }};
 }
@@ -1,8 +1,19 @@
+/// Describe something an entity does, to any onlookers only.
 #[macro_export]
 macro_rules! they {
   ($data: expr, $entity: expr, $string: expr) => {{
-    if camera_sees_entity!($data, $entity) {
-      write_output_event!($data, $string);
-    }
+    use $crate::perception::{Rendering, Sensation};
+    write_sensation!(
+      $data,
+      Sensation {
+        actor_id: $crate::ecs::entity::EntityId($entity.id()),
+        target_id: None,
+        room_id: get_current_room_id!($data, $entity),
+        rendering: Rendering {
+          third_person: Some(String::from($string)),
+          ..Rendering::default()
+        },
+      }
+    );
   }};
 }
@@ -14,9 +14,9 @@
 macro_rules! you {
   ($data: expr, $entity: expr, $string: expr) => {{
     #[allow(unused_imports)]
-    use $crate::ecs::event::OutputEvent;
+    use $crate::ecs::entity::EntityId;
     if entity_has_camera!($data, $entity) {
-      write_output_event!($data, format!("You {}", $string));
+      write_output_event!($data, EntityId($entity.id()), format!("You {}", $string));
     }
   }};
 }
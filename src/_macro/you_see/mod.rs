@@ -5,9 +5,9 @@
 macro_rules! you_see {
   ($data: expr, $entity: expr, $string: expr) => {{
     #[allow(unused_imports)]
-    use $crate::ecs::event::OutputEvent;
+    use $crate::ecs::entity::EntityId;
     if entity_has_camera!($data, $entity) {
-      write_output_event!($data, format!("You see {}", $string));
+      write_output_event!($data, EntityId($entity.id()), format!("You see {}", $string));
     }
   }};
 }
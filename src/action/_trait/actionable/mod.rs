@@ -1,3 +1,4 @@
+use crate::condition::Condition;
 use crate::ecs::entity::EntityId;
 use crate::ecs::AllData;
 use crate::effect::Effect;
@@ -14,6 +15,29 @@ pub trait Actionable: Debug + Send + Sync {
   /// These will be created on an individual basis based on the data available.
   fn get_effects(&self, data: &mut AllData) -> Result<Vec<Effect>, AnyError>;
 
+  /// The number of ticks this action takes.
+  ///
+  /// An action that takes no time is carried out all at once.  Anything else
+  /// is begun, and its effects are spread over its duration; until it ends,
+  /// it can be interrupted.
+  fn get_duration(&self, _data: &mut AllData) -> usize {
+    0
+  }
+
+  /// Describe what the actor is doing, e.g. "put the mushroom in the basket",
+  /// for onlookers to be told when it begins or is interrupted.
+  fn describe(&self, _data: &mut AllData) -> Option<String> {
+    None
+  }
+
+  /// The conditions the actor must meet to perform this action.
+  ///
+  /// These are checked before `can_execute()`, and the first that isn't met
+  /// is given as the reason the action failed, e.g. "your legs are broken".
+  fn get_conditions(&self, _data: &mut AllData) -> Vec<Condition> {
+    vec![]
+  }
+
   /// Indicates whether this action can be executed.
   ///
   /// This is highly context-sensitive.  If Ok(()), no further information is
@@ -0,0 +1,39 @@
+use super::check_door;
+use crate::action::Actionable;
+use crate::ecs::entity::EntityId;
+use crate::ecs::AllData;
+use crate::effect::*;
+use anyhow::Error as AnyError;
+
+/// The `Close` action.
+///
+/// Close a door that's open.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Close {
+  pub entity_id: EntityId,
+  pub target_entity_id: EntityId,
+}
+
+impl Actionable for Close {
+  fn get_actor_entity_id(&self) -> EntityId {
+    self.entity_id
+  }
+
+  fn get_effects(&self, _data: &mut AllData) -> Result<Vec<Effect>, AnyError> {
+    Ok(vec![
+      create_effect!(EntityClosesDoor {
+        entity_id: self.entity_id,
+        target_entity_id: self.target_entity_id,
+      }),
+      create_effect!(EntitySetInitiative {
+        entity_id: self.entity_id,
+        value: 0,
+      }),
+    ])
+  }
+
+  fn can_execute(&self, data: &mut AllData) -> Result<(), AnyError> {
+    check_door(data, self.entity_id, self.target_entity_id)?.check_close()?;
+    Ok(())
+  }
+}
@@ -0,0 +1,43 @@
+use super::check_door;
+use crate::action::Actionable;
+use crate::ecs::entity::EntityId;
+use crate::ecs::AllData;
+use crate::effect::*;
+use crate::map::DoorError;
+use anyhow::Error as AnyError;
+
+/// The `Knock` action.
+///
+/// Knock on a closed door, for whoever's on the other side.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Knock {
+  pub entity_id: EntityId,
+  pub target_entity_id: EntityId,
+}
+
+impl Actionable for Knock {
+  fn get_actor_entity_id(&self) -> EntityId {
+    self.entity_id
+  }
+
+  fn get_effects(&self, _data: &mut AllData) -> Result<Vec<Effect>, AnyError> {
+    Ok(vec![
+      create_effect!(EntityKnocksOnDoor {
+        entity_id: self.entity_id,
+        target_entity_id: self.target_entity_id,
+      }),
+      create_effect!(EntitySetInitiative {
+        entity_id: self.entity_id,
+        value: 0,
+      }),
+    ])
+  }
+
+  fn can_execute(&self, data: &mut AllData) -> Result<(), AnyError> {
+    let door = check_door(data, self.entity_id, self.target_entity_id)?;
+    if door.is_open {
+      return Err(DoorError::AlreadyOpen.into());
+    }
+    Ok(())
+  }
+}
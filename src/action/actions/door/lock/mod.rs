@@ -0,0 +1,52 @@
+use super::{check_door, get_key_id};
+use crate::action::Actionable;
+use crate::ecs::entity::EntityId;
+use crate::ecs::AllData;
+use crate::effect::*;
+use anyhow::Error as AnyError;
+
+/// The `Lock` action.
+///
+/// Lock a closed door with its key.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Lock {
+  pub entity_id: EntityId,
+  pub target_entity_id: EntityId,
+  /// The key to use, if the actor said which.
+  pub key_entity_id: Option<EntityId>,
+}
+
+impl Lock {
+  pub fn get_key_id(&self, data: &mut AllData) -> Result<EntityId, AnyError> {
+    let door = check_door(data, self.entity_id, self.target_entity_id)?;
+    get_key_id(data, self.entity_id, &door, self.key_entity_id)
+  }
+}
+
+impl Actionable for Lock {
+  fn get_actor_entity_id(&self) -> EntityId {
+    self.entity_id
+  }
+
+  fn get_effects(&self, data: &mut AllData) -> Result<Vec<Effect>, AnyError> {
+    let key_entity_id = self.get_key_id(data)?;
+    Ok(vec![
+      create_effect!(EntityLocksDoor {
+        entity_id: self.entity_id,
+        target_entity_id: self.target_entity_id,
+        key_entity_id,
+      }),
+      create_effect!(EntitySetInitiative {
+        entity_id: self.entity_id,
+        value: 0,
+      }),
+    ])
+  }
+
+  fn can_execute(&self, data: &mut AllData) -> Result<(), AnyError> {
+    let door = check_door(data, self.entity_id, self.target_entity_id)?;
+    let key_entity_id = get_key_id(data, self.entity_id, &door, self.key_entity_id)?;
+    door.check_lock(key_entity_id)?;
+    Ok(())
+  }
+}
@@ -0,0 +1,149 @@
+use crate::ecs::entity::EntityId;
+use crate::ecs::AllData;
+use crate::map::{Door, DoorError, Passage};
+use anyhow::Error as AnyError;
+
+pub mod close;
+pub use close::Close as CloseAction;
+pub mod knock;
+pub use knock::Knock as KnockAction;
+pub mod lock;
+pub use lock::Lock as LockAction;
+pub mod open;
+pub use open::Open as OpenAction;
+pub mod unlock;
+pub use unlock::Unlock as UnlockAction;
+
+/// Check that the target is a door across one of the ways out of the actor's
+/// room, and get its state.
+pub fn check_door(data: &mut AllData, entity_id: EntityId, target_entity_id: EntityId) -> Result<Door, AnyError> {
+  let entity = get_entity!(data, entity_id);
+  let target_entity = get_entity!(data, target_entity_id);
+  let door = get_door!(data, target_entity).ok_or_else(|| anyhow!("that is not a door"))?;
+  let doors = get_current_room_id!(data, entity)
+    .map(|room_id| get_room_doors!(data, get_entity!(data, room_id)))
+    .unwrap_or_default();
+  if !doors
+    .iter()
+    .any(|(_direction, door_entity)| *door_entity == target_entity)
+  {
+    return Err(anyhow!("that is not here"));
+  }
+  Ok(door)
+}
+
+/// Check that there's no closed door in the way of a passage.
+pub fn check_passage(data: &mut AllData, passage: &Passage) -> Result<(), AnyError> {
+  if let Some(door_id) = passage.door {
+    let door_entity = get_entity!(data, door_id);
+    if get_door!(data, door_entity).map_or(false, |door| !door.is_open) {
+      let lc_name = get_lc_name!(data, door_entity).unwrap_or_default();
+      return Err(anyhow!("the {} is closed", lc_name));
+    }
+  }
+  Ok(())
+}
+
+/// Get the key to use on a door's lock.
+///
+/// If the actor named one, it has to be something they have; otherwise, they
+/// have to have the one that fits.
+pub fn get_key_id(
+  data: &mut AllData,
+  entity_id: EntityId,
+  door: &Door,
+  key_entity_id: Option<EntityId>,
+) -> Result<EntityId, AnyError> {
+  let entity = get_entity!(data, entity_id);
+  match key_entity_id {
+    Some(key_entity_id) => {
+      let key_entity = get_entity!(data, key_entity_id);
+      if get_holder!(data, key_entity) != Some(entity) {
+        return Err(anyhow!("you do not have that"));
+      }
+      Ok(key_entity_id)
+    },
+    None => {
+      let key_id = door.key_id.ok_or(DoorError::NoLock)?;
+      let key_entity = get_entity!(data, key_id);
+      if get_holder!(data, key_entity) != Some(entity) {
+        return Err(anyhow!("you do not have the key"));
+      }
+      Ok(key_id)
+    },
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::ecs::component::{HasPassages, IsInRoom};
+  use crate::map::Direction;
+  use crate::test::*;
+  use specs::prelude::*;
+
+  #[test]
+  pub fn test_doors() {
+    init();
+    let mut headless = compass_rose_headless();
+    headless.input("w");
+    assert_response_contains(&headless, "The door to the west is closed.");
+    headless.input("w");
+    assert_response_contains(&headless, "The door is closed.");
+    headless.input("open the door");
+    assert_response_contains(&headless, "It is locked.");
+    headless.input("knock on the door");
+    assert_response_contains(&headless, "You knock on the door.");
+    headless.input("unlock the door");
+    assert_response_contains(&headless, "You do not have the key.");
+    headless.input("e");
+    headless.input("take the key");
+    headless.input("take the mushroom");
+    headless.input("w");
+    headless.input("unlock the door with the mushroom");
+    assert_response_contains(&headless, "That does not fit the lock.");
+    headless.input("unlock the door");
+    assert_response_contains(&headless, "You unlock the door with the key.");
+    headless.input("open the door");
+    assert_response_contains(&headless, "You open the door.");
+    headless.input("lock the door");
+    assert_response_contains(&headless, "You will have to close it first.");
+    // The door is the same from the other side.
+    headless.input("w");
+    assert_response_contains(&headless, "Shed");
+    assert_response_contains(&headless, "The door to the east is open.");
+    headless.input("close the door");
+    assert_response_contains(&headless, "You close the door.");
+    headless.input("lock the door with the key");
+    assert_response_contains(&headless, "You lock the door with the key.");
+    headless.input("e");
+    assert_response_contains(&headless, "The door is closed.");
+  }
+
+  #[test]
+  pub fn test_hidden_door() {
+    init();
+    let mut headless = compass_rose_headless();
+    headless.input("w");
+    // Hide the door as though it had been behind a tapestry all along.
+    {
+      let ecs = &headless.game.ecs;
+      let room_id = ecs.read_storage::<IsInRoom>().get(get_player(&headless)).unwrap().0;
+      let room = ecs.entities().entity(room_id.0);
+      let mut has_passages = ecs.write_storage::<HasPassages>();
+      let has_passages = has_passages.get_mut(room).unwrap();
+      let mut passage = has_passages.get_passage_to(&Direction::West).clone().unwrap();
+      passage.properties.is_hidden = true;
+      passage.properties.concealment = 0;
+      has_passages.set_passage(&Direction::West, Some(passage));
+    }
+    headless.input("look");
+    assert_response_lacks(&headless, "The door to the west");
+    headless.input("knock on the door");
+    assert_response_lacks(&headless, "You knock on the door.");
+    headless.input("search");
+    assert_response_contains(&headless, "You find a way west");
+    headless.input("knock on the door");
+    assert_response_contains(&headless, "You knock on the door.");
+  }
+}
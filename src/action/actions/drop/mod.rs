@@ -0,0 +1,51 @@
+use crate::action::Actionable;
+use crate::containment::Containment;
+use crate::ecs::entity::EntityId;
+use crate::ecs::entity::RoomId;
+use crate::ecs::AllData;
+use crate::effect::*;
+use anyhow::Error as AnyError;
+
+/// The `Drop` action.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Drop {
+  pub entity_id: EntityId,
+  pub target_entity_id: EntityId,
+}
+
+impl Drop {
+  pub fn get_room_id(&self, data: &mut AllData) -> Result<RoomId, AnyError> {
+    let entity = get_entity!(data, self.entity_id);
+    get_current_room_id!(data, entity).ok_or_else(|| anyhow!("there is nowhere to drop that"))
+  }
+}
+
+impl Actionable for Drop {
+  fn get_actor_entity_id(&self) -> EntityId {
+    self.entity_id
+  }
+
+  fn get_effects(&self, data: &mut AllData) -> Result<Vec<Effect>, AnyError> {
+    let room_id = self.get_room_id(data)?;
+    Ok(vec![
+      create_effect!(EntityDropsEntity {
+        entity_id: self.entity_id,
+        target_entity_id: self.target_entity_id,
+        room_id,
+      }),
+      create_effect!(EntitySetInitiative {
+        entity_id: self.entity_id,
+        value: 0,
+      }),
+    ])
+  }
+
+  fn can_execute(&self, data: &mut AllData) -> Result<(), AnyError> {
+    let target_entity = get_entity!(data, self.target_entity_id);
+    if get_container!(data, target_entity) != Some((Containment::CarriedBy, self.entity_id)) {
+      return Err(anyhow!("you are not carrying that"));
+    }
+    self.get_room_id(data)?;
+    Ok(())
+  }
+}
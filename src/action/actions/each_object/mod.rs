@@ -0,0 +1,45 @@
+use super::super::{Action, Actionable};
+use crate::ecs::entity::EntityId;
+use crate::ecs::AllData;
+use crate::effect::*;
+use anyhow::Error as AnyError;
+
+/// The `EachObject` action.
+///
+/// This is one action for each of a set of objects, e.g. from "look at all".
+/// Each is attempted in turn, and one failing doesn't stop the others; the
+/// failure is reported with the name of the object it concerned.
+#[derive(Clone, Debug)]
+pub struct EachObject {
+  pub entity_id: EntityId,
+  /// Each object's name, and the action to perform on it.
+  pub actions: Vec<(String, Action)>,
+}
+
+impl Actionable for EachObject {
+  fn get_actor_entity_id(&self) -> EntityId {
+    self.entity_id
+  }
+
+  fn get_effects(&self, data: &mut AllData) -> Result<Vec<Effect>, AnyError> {
+    let mut result = Vec::new();
+    for (_name, action) in self.actions.iter() {
+      if action.can_execute(data).is_ok() {
+        result.append(&mut action.get_effects(data)?);
+      }
+    }
+    Ok(result)
+  }
+
+  fn execute(&self, data: &mut AllData) -> Result<(), AnyError> {
+    use inflector::Inflector;
+    for (name, action) in self.actions.iter() {
+      if let Err(error) = action.execute(data) {
+        show!(data, get_entity!(data, self.entity_id), {
+          format!("{}: {}.", name, format!("{}", error).to_sentence_case())
+        });
+      }
+    }
+    Ok(())
+  }
+}
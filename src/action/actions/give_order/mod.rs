@@ -0,0 +1,60 @@
+use super::super::{Action, Actionable};
+use crate::ecs::entity::EntityId;
+use crate::ecs::AllData;
+use crate::effect::*;
+use anyhow::Error as AnyError;
+
+/// The `GiveOrder` action.
+///
+/// Telling another actor to do something.  The order is given here, but it's
+/// only carried out if the addressee's AI decides to go along with it.
+#[derive(Clone, Debug)]
+pub struct GiveOrder {
+  pub entity_id: EntityId,
+  pub addressee_id: EntityId,
+  /// The order, as it was said, e.g. "go north".
+  pub order: String,
+  /// The action the addressee is being told to perform.
+  pub action: Action,
+}
+
+impl Actionable for GiveOrder {
+  fn get_actor_entity_id(&self) -> EntityId {
+    self.entity_id
+  }
+
+  fn get_effects(&self, _data: &mut AllData) -> Result<Vec<Effect>, AnyError> {
+    Ok(vec![
+      create_effect!(EntityGivesOrder {
+        entity_id: self.entity_id,
+        target_entity_id: self.addressee_id,
+        order: self.order.clone(),
+      }),
+      create_effect!(EntitySetInitiative {
+        entity_id: self.entity_id,
+        value: 0,
+      }),
+    ])
+  }
+
+  fn can_execute(&self, data: &mut AllData) -> Result<(), AnyError> {
+    let entity = get_entity!(data, self.entity_id);
+    let addressee = get_entity!(data, self.addressee_id);
+    let lc_name = get_lc_name!(data, addressee).unwrap_or_default();
+    if get_current_room_id!(data, entity) != get_current_room_id!(data, addressee) {
+      return Err(anyhow!("you don't see {} here", lc_name));
+    }
+    if !data.has_ai.contains(addressee) {
+      return Err(anyhow!("you can't tell {} what to do", lc_name));
+    }
+    Ok(())
+  }
+
+  fn execute(&self, data: &mut AllData) -> Result<(), AnyError> {
+    for effect in self.get_effects(data)? {
+      write_effect_event!(data, effect);
+    }
+    write_order_event!(data, self.entity_id, self.addressee_id, self.action.clone());
+    Ok(())
+  }
+}
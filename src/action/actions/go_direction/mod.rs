@@ -1,10 +1,13 @@
+use crate::action::actions::door::check_passage;
 use crate::action::Actionable;
+use crate::ailment::Ailment;
+use crate::condition::Condition;
 use crate::ecs::entity::EntityId;
 use crate::ecs::entity::RoomId;
 use crate::ecs::AllData;
 use crate::effect::*;
 use crate::map::Direction;
-use crate::map::PassageDestination;
+use crate::map::{Passage, PassageDestination};
 use anyhow::Error as AnyError;
 
 /// The `GoDirection` action.
@@ -25,17 +28,21 @@ impl GoDirection {
     Ok(room_id)
   }
 
-  pub fn get_destination_id(&self, data: &mut AllData) -> Result<RoomId, AnyError> {
+  /// Get the passage in that direction, if it can be seen.
+  pub fn get_passage(&self, data: &mut AllData) -> Result<Passage, AnyError> {
     let room_id = self.get_room_id(data)?;
     let room = get_entity!(data, room_id);
-    let passage_option = get_passage_to!(data, room, &self.direction);
-    if passage_option.is_none() {
-      return Err(anyhow!("you are unable to move in that direction"));
-    }
-    let passage = passage_option.unwrap();
+    get_passage_to!(data, room, &self.direction)
+      .filter(|passage| !passage.properties.is_hidden)
+      .ok_or_else(|| anyhow!("you are unable to move in that direction"))
+  }
+
+  pub fn get_destination_id(&self, data: &mut AllData) -> Result<RoomId, AnyError> {
+    let passage = self.get_passage(data)?;
     if passage.to.is_message() {
       return Err(anyhow!("{:#?}", passage.to));
     }
+    check_passage(data, &passage)?;
     let destination_id = if let PassageDestination::Room(destination_id) = passage.to {
       destination_id
     } else {
@@ -54,6 +61,10 @@ impl Actionable for GoDirection {
     let room_id = self.get_room_id(data)?;
     let destination_id = self.get_destination_id(data)?;
     Ok(vec![
+      create_effect!(EntitySetInitiative {
+        entity_id: self.entity_id,
+        value: 0,
+      }),
       create_effect!(EntityWalksOutOfRoom {
         entity_id: self.entity_id,
         direction: self.direction,
@@ -66,16 +77,80 @@ impl Actionable for GoDirection {
       }),
       create_effect!(EntityLooksAround {
         entity_id: self.entity_id,
-      }),
-      create_effect!(EntitySetInitiative {
-        entity_id: self.entity_id,
-        value: 0,
+        verbose: false,
       }),
     ])
   }
 
+  /// A passage that's hard going takes longer to get through.
+  ///
+  /// The actor only changes rooms once they're all the way through.
+  fn get_duration(&self, data: &mut AllData) -> usize {
+    self
+      .get_passage(data)
+      .map(|passage| passage.properties.extra_cost)
+      .unwrap_or_default()
+  }
+
+  fn describe(&self, _data: &mut AllData) -> Option<String> {
+    Some(format!("go {}", self.direction.get_lowercase()))
+  }
+
+  /// Some passages ask more of the actor than working legs.
+  fn get_conditions(&self, data: &mut AllData) -> Vec<Condition> {
+    let mut result = vec![
+      Condition::DoesNotHaveAilment(Ailment::LegsBroken),
+      Condition::DoesNotHaveAilment(Ailment::Paralyzed),
+    ];
+    if let Some(condition) = self
+      .get_passage(data)
+      .ok()
+      .and_then(|passage| passage.properties.condition)
+    {
+      result.push(condition);
+    }
+    result
+  }
+
   fn can_execute(&self, data: &mut AllData) -> Result<(), AnyError> {
     self.get_destination_id(data)?;
     Ok(())
   }
 }
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::ecs::component::{HasPassages, IsInRoom};
+  use crate::map::Direction;
+  use crate::test::*;
+  use specs::prelude::*;
+
+  #[test]
+  pub fn test_stop_partway() {
+    init();
+    let mut headless = compass_rose_headless();
+    headless.input("s");
+    // Skip the search for the gap among the roots.
+    {
+      let ecs = &headless.game.ecs;
+      let room_id = ecs.read_storage::<IsInRoom>().get(get_player(&headless)).unwrap().0;
+      let room = ecs.entities().entity(room_id.0);
+      let mut has_passages = ecs.write_storage::<HasPassages>();
+      let has_passages = has_passages.get_mut(room).unwrap();
+      let mut passage = has_passages.get_passage_to(&Direction::Down).clone().unwrap();
+      passage.properties.is_hidden = false;
+      has_passages.set_passage(&Direction::Down, Some(passage));
+    }
+    // Squeezing through takes a while, and can be given up on partway.
+    headless.game.write_input("down");
+    headless.advance(10);
+    assert!(headless.game.has_actions_in_progress());
+    headless.input("stop");
+    assert_response_contains(&headless, "You stop trying to go down.");
+    assert_response_lacks(&headless, "You squeeze down between the roots.");
+    assert_eq!(get_player_room_name(&headless), "South Room");
+    headless.input("look");
+    assert_response_contains(&headless, "South Room");
+  }
+}
@@ -19,6 +19,7 @@ impl Actionable for LookAround {
     Ok(vec![
       create_effect!(EntityLooksAround {
         entity_id: self.entity_id,
+        verbose: true,
       }),
       create_effect!(EntitySetInitiative {
         entity_id: self.entity_id,
@@ -2,6 +2,7 @@ use crate::action::Actionable;
 use crate::ecs::entity::EntityId;
 use crate::ecs::AllData;
 use crate::effect::*;
+use crate::input::Preposition;
 use anyhow::Error;
 
 /// The `LookAtEntity` command.
@@ -9,6 +10,7 @@ use anyhow::Error;
 pub struct LookAtEntity {
   pub entity_id: EntityId,
   pub target_entity_id: EntityId,
+  pub preposition: Preposition,
 }
 
 impl Actionable for LookAtEntity {
@@ -21,6 +23,7 @@ impl Actionable for LookAtEntity {
       create_effect!(EntityLooksAtEntity {
         entity_id: self.entity_id,
         target_entity_id: self.target_entity_id,
+        preposition: self.preposition,
       }),
       create_effect!(EntitySetInitiative {
         entity_id: self.entity_id,
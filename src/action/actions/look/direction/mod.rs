@@ -1,4 +1,7 @@
+use crate::action::actions::door::check_passage;
 use crate::action::Actionable;
+use crate::ailment::Ailment;
+use crate::condition::Condition;
 use crate::ecs::entity::{EntityId, RoomId};
 use crate::ecs::AllData;
 use crate::effect::*;
@@ -27,7 +30,7 @@ impl LookDirection {
   pub fn get_destination_id(&self, data: &mut AllData) -> Result<RoomId, AnyError> {
     let room_id = self.get_room_id(data)?;
     let room = get_entity!(data, room_id);
-    let passage_option = get_passage_to!(data, room, &self.direction);
+    let passage_option = get_passage_to!(data, room, &self.direction).filter(|passage| !passage.properties.is_hidden);
     if passage_option.is_none() {
       return Err(anyhow!("you are unable to move in that direction"));
     }
@@ -35,6 +38,7 @@ impl LookDirection {
     if passage.to.is_message() {
       return Err(anyhow!("{:#?}", passage.to));
     }
+    check_passage(data, &passage)?;
     let destination_id = if let PassageDestination::Room(destination_id) = passage.to {
       destination_id
     } else {
@@ -49,6 +53,10 @@ impl Actionable for LookDirection {
     self.entity_id
   }
 
+  fn get_conditions(&self, _data: &mut AllData) -> Vec<Condition> {
+    vec![Condition::DoesNotHaveAilment(Ailment::Blind)]
+  }
+
   fn can_execute(&self, data: &mut AllData) -> Result<(), AnyError> {
     self.get_destination_id(data)?;
     Ok(())
@@ -1,6 +1,22 @@
+pub mod door;
+pub use door::{CloseAction, KnockAction, LockAction, OpenAction, UnlockAction};
+pub mod drop;
+pub use drop::Drop as DropAction;
+pub mod each_object;
+pub use each_object::EachObject as EachObjectAction;
+pub mod give_order;
+pub use give_order::GiveOrder as GiveOrderAction;
 pub mod go_direction;
 pub use go_direction::GoDirection as GoDirectionAction;
 pub mod idle;
 pub use idle::Idle as IdleAction;
 pub mod look;
 pub use look::*;
+pub mod put;
+pub use put::{PutInAction, PutOnAction};
+pub mod search;
+pub use search::Search as SearchAction;
+pub mod stop;
+pub use stop::Stop as StopAction;
+pub mod take;
+pub use take::Take as TakeAction;
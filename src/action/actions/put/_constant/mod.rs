@@ -0,0 +1,2 @@
+/// The number of ticks it takes to put something in or on something else.
+pub const PUT_DURATION: usize = 20;
@@ -0,0 +1,144 @@
+use super::take::check_reach;
+use crate::containment::{Containment, ContainmentError};
+use crate::ecs::entity::EntityId;
+use crate::ecs::AllData;
+use crate::effect::*;
+use anyhow::Error as AnyError;
+
+pub mod _constant;
+pub use _constant::*;
+pub mod in_entity;
+pub use in_entity::PutIn as PutInAction;
+pub mod on_entity;
+pub use on_entity::PutOn as PutOnAction;
+
+/// Get the effects of putting a target in or on a container.
+pub fn get_put_effects(
+  entity_id: EntityId,
+  target_entity_id: EntityId,
+  container_entity_id: EntityId,
+  containment: Containment,
+) -> Vec<Effect> {
+  vec![
+    create_effect!(EntitySetInitiative { entity_id, value: 0 }),
+    create_effect!(EntityPutsEntity {
+      entity_id,
+      target_entity_id,
+      container_entity_id,
+      containment,
+    }),
+  ]
+}
+
+/// Describe putting a target in or on a container.
+pub fn describe_put(
+  data: &mut AllData,
+  target_entity_id: EntityId,
+  container_entity_id: EntityId,
+  containment: Containment,
+) -> Option<String> {
+  let target_entity = get_entity!(data, target_entity_id);
+  let container_entity = get_entity!(data, container_entity_id);
+  let lc_name = get_lc_name!(data, target_entity)?;
+  let container_lc_name = get_lc_name!(data, container_entity)?;
+  Some(format!("put the {} {} the {}", lc_name, containment, container_lc_name))
+}
+
+/// Check that a target can be put in or on a container.
+///
+/// Both have to be within reach, and the container has to have room for the
+/// target and not be the target, or anywhere in or on it.
+pub fn check_put(
+  data: &mut AllData,
+  entity_id: EntityId,
+  target_entity_id: EntityId,
+  container_entity_id: EntityId,
+  containment: Containment,
+) -> Result<(), AnyError> {
+  let target_entity = get_entity!(data, target_entity_id);
+  let container_entity = get_entity!(data, container_entity_id);
+  if data.is_an_object.get(target_entity).is_none() {
+    return Err(anyhow!("that is not something you can move"));
+  }
+  check_reach(data, entity_id, target_entity_id)?;
+  check_reach(data, entity_id, container_entity_id)?;
+  if target_entity == container_entity || get_containers!(data, container_entity).contains(&target_entity) {
+    return Err(ContainmentError::ContainsItself.into());
+  }
+  if get_container!(data, target_entity) == Some((containment, container_entity_id)) {
+    return Err(anyhow!("that is already there"));
+  }
+  check_capacity!(data, container_entity, containment, target_entity)?;
+  Ok(())
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::containment::Containment;
+  use crate::ecs::component::{IsContainedBy, IsInRoom};
+  use crate::ecs::entity::EntityId;
+  use crate::test::*;
+  use specs::prelude::*;
+
+  #[test]
+  pub fn test_put() {
+    init();
+    let mut headless = compass_rose_headless();
+    headless.input("look in the basket");
+    assert_response_contains(&headless, "There's nothing in basket.");
+    // Putting something away takes a little while.
+    headless.input("put the mushroom in the basket");
+    assert_response_contains(&headless, "You begin to put the mushroom in the basket.");
+    assert_response_contains(&headless, "You put the mushroom in the basket.");
+    assert!(!headless.game.has_actions_in_progress());
+    headless.input("look in the basket");
+    assert_response_contains(&headless, "In the basket is the mushroom.");
+    headless.input("put the basket into the basket");
+    assert_response_contains(&headless, "That would be a neat trick.");
+    headless.input("put the stone on the basket");
+    assert_response_contains(&headless, "That is not possible.");
+    headless.input("put the basket under the stone");
+    assert_response_contains(&headless, "You can't put anything under anything else.");
+    headless.input("put the basket on the flat stone");
+    assert_response_contains(&headless, "You put the basket on the stone.");
+    // The room shows where everything is.
+    headless.input("look");
+    assert_response_contains(&headless, "On the stone is the basket.");
+    assert_response_contains(&headless, "In the basket is the mushroom.");
+    assert_response_lacks(&headless, "speckled mushroom grows");
+    // Things in or on other things can still be taken, and go with them.
+    headless.input("take the basket");
+    assert_response_contains(&headless, "You take the basket.");
+    headless.input("inventory");
+    assert_response_contains(&headless, "Basket. In the basket is the mushroom.");
+    headless.input("take the mushroom");
+    assert_response_contains(&headless, "You take the mushroom.");
+  }
+
+  #[test]
+  pub fn test_put_interrupted() {
+    init();
+    let mut headless = compass_rose_headless();
+    headless.game.write_input("put the mushroom in the basket");
+    headless.advance(10);
+    assert!(headless.game.has_actions_in_progress());
+    // The goblin, wherever it has got to, makes off with the basket partway
+    // through.
+    let basket = get_named_entity(&headless, "Basket");
+    let goblin = get_named_entity(&headless, "Goblin");
+    headless.game.ecs.write_storage::<IsInRoom>().remove(basket);
+    headless
+      .game
+      .ecs
+      .write_storage::<IsContainedBy>()
+      .insert(basket, IsContainedBy(Containment::CarriedBy, EntityId(goblin.id())))
+      .unwrap();
+    headless.input("look");
+    assert_response_contains(&headless, "You stop trying to put the mushroom in the basket.");
+    assert_response_contains(&headless, "That is not here.");
+    assert_response_lacks(&headless, "You put the mushroom in the basket.");
+    assert!(!headless.game.has_actions_in_progress());
+    assert_response_contains(&headless, "speckled mushroom grows");
+  }
+}
@@ -0,0 +1,48 @@
+use super::{check_put, describe_put, get_put_effects, PUT_DURATION};
+use crate::action::Actionable;
+use crate::containment::Containment;
+use crate::ecs::entity::EntityId;
+use crate::ecs::AllData;
+use crate::effect::*;
+use anyhow::Error as AnyError;
+
+/// The `PutOn` action.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct PutOn {
+  pub entity_id: EntityId,
+  pub target_entity_id: EntityId,
+  pub container_entity_id: EntityId,
+}
+
+impl Actionable for PutOn {
+  fn get_actor_entity_id(&self) -> EntityId {
+    self.entity_id
+  }
+
+  fn get_effects(&self, _data: &mut AllData) -> Result<Vec<Effect>, AnyError> {
+    Ok(get_put_effects(
+      self.entity_id,
+      self.target_entity_id,
+      self.container_entity_id,
+      Containment::On,
+    ))
+  }
+
+  fn get_duration(&self, _data: &mut AllData) -> usize {
+    PUT_DURATION
+  }
+
+  fn describe(&self, data: &mut AllData) -> Option<String> {
+    describe_put(data, self.target_entity_id, self.container_entity_id, Containment::On)
+  }
+
+  fn can_execute(&self, data: &mut AllData) -> Result<(), AnyError> {
+    check_put(
+      data,
+      self.entity_id,
+      self.target_entity_id,
+      self.container_entity_id,
+      Containment::On,
+    )
+  }
+}
@@ -0,0 +1,16 @@
+use crate::ailment::Ailment;
+
+/// The number of ticks it takes to search the area.
+pub const SEARCH_DURATION: usize = 20;
+
+/// The ailments that make it harder to notice anything while searching.
+pub const SEARCH_HINDRANCES: [Ailment; 5] = [
+  Ailment::Blind,
+  Ailment::Confused,
+  Ailment::Delusional,
+  Ailment::Exhausted,
+  Ailment::Stunned,
+];
+
+/// How much harder each of those makes it, out of a hundred.
+pub const SEARCH_HINDRANCE_PENALTY: u32 = 50;
@@ -0,0 +1,148 @@
+use crate::action::Actionable;
+use crate::ecs::entity::EntityId;
+use crate::ecs::entity::RoomId;
+use crate::ecs::AllData;
+use crate::effect::*;
+use anyhow::Error as AnyError;
+use rand::prelude::*;
+
+pub mod _constant;
+pub use _constant::*;
+
+/// The `Search` action.
+///
+/// Look high and low for any hidden ways out of the room.  Each is found or
+/// not by a roll against how well it's concealed, made harder if the searcher
+/// is in no state to look, e.g. blind or confused; the rolls are made when the
+/// search begins, so the result is settled by the time it's reported.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Search {
+  pub entity_id: EntityId,
+}
+
+impl Search {
+  pub fn get_room_id(&self, data: &mut AllData) -> Result<RoomId, AnyError> {
+    let entity = get_entity!(data, self.entity_id);
+    get_current_room_id!(data, entity).ok_or_else(|| anyhow!("there is nowhere to search"))
+  }
+}
+
+impl Actionable for Search {
+  fn get_actor_entity_id(&self) -> EntityId {
+    self.entity_id
+  }
+
+  fn get_effects(&self, data: &mut AllData) -> Result<Vec<Effect>, AnyError> {
+    let room_id = self.get_room_id(data)?;
+    let room = get_entity!(data, room_id);
+    let hidden_passages = get_passages!(data, room)
+      .map(|passages| {
+        passages
+          .get_passages()
+          .into_iter()
+          .filter(|passage| passage.properties.is_hidden)
+          .cloned()
+          .collect::<Vec<_>>()
+      })
+      .unwrap_or_default();
+    let entity = get_entity!(data, self.entity_id);
+    let penalty = SEARCH_HINDRANCES
+      .iter()
+      .filter(|ailment| is_ailing!(data, entity, **ailment))
+      .count() as u32
+      * SEARCH_HINDRANCE_PENALTY;
+    let mut result = vec![create_effect!(EntitySetInitiative {
+      entity_id: self.entity_id,
+      value: 0,
+    })];
+    for passage in hidden_passages {
+      let roll: u32 = data.random_resource.0.gen_range(0..100);
+      if roll >= passage.properties.concealment as u32 + penalty {
+        result.push(create_effect!(EntityFindsPassage {
+          entity_id: self.entity_id,
+          room_id,
+          direction: passage.direction,
+        }));
+      }
+    }
+    if result.len() == 1 {
+      result.push(create_effect!(EntityFindsNothing {
+        entity_id: self.entity_id,
+      }));
+    }
+    Ok(result)
+  }
+
+  fn get_duration(&self, _data: &mut AllData) -> usize {
+    SEARCH_DURATION
+  }
+
+  fn describe(&self, _data: &mut AllData) -> Option<String> {
+    Some("search the area".to_string())
+  }
+
+  fn can_execute(&self, data: &mut AllData) -> Result<(), AnyError> {
+    self.get_room_id(data)?;
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::ailment::Ailment;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_search() {
+    init();
+    let mut headless = compass_rose_headless();
+    headless.input("s");
+    assert_response_lacks(&headless, "down");
+    headless.input("down");
+    assert_response_contains(&headless, "You are unable to move in that direction.");
+    // The gap is well hidden, but not so well that it can't be found.
+    headless.input("search");
+    assert_response_contains(&headless, "You begin to search the area.");
+    for _ in 0..20 {
+      if response_contains(&headless, "You find a way down") {
+        break;
+      }
+      assert_response_contains(&headless, "You find nothing");
+      headless.input("search");
+    }
+    headless.input("look");
+    assert_response_contains(&headless, "down");
+    // It's a tight squeeze, and a dead end; the only way out is back up.
+    headless.input("down");
+    assert_response_contains(&headless, "You begin to go down.");
+    assert_response_contains(&headless, "You squeeze down between the roots.");
+    assert_response_contains(&headless, "Burrow");
+    headless.input("down");
+    assert_response_contains(&headless, "You are unable to move in that direction.");
+    headless.input("up");
+    assert_response_contains(&headless, "You scramble up through the roots.");
+    assert_response_contains(&headless, "South Room");
+  }
+
+  #[test]
+  pub fn test_search_hindered() {
+    init();
+    let mut headless = compass_rose_headless();
+    headless.input("s");
+    // Someone blind and confused isn't going to find anything.
+    set_player_ailments(&mut headless, &[Ailment::Blind, Ailment::Confused]);
+    for _ in 0..20 {
+      headless.input("search");
+      assert_response_contains(&headless, "You find nothing");
+    }
+    set_player_ailments(&mut headless, &[]);
+    for _ in 0..20 {
+      headless.input("search");
+      if response_contains(&headless, "You find a way down") {
+        return;
+      }
+    }
+    panic!("never found the way down");
+  }
+}
@@ -0,0 +1,61 @@
+use super::super::Actionable;
+use crate::ecs::entity::EntityId;
+use crate::ecs::AllData;
+use crate::effect::*;
+use anyhow::Error as AnyError;
+
+/// The `Stop` action.
+///
+/// Abandon whatever the actor is in the middle of doing.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Stop {
+  pub entity_id: EntityId,
+}
+
+impl Actionable for Stop {
+  fn get_actor_entity_id(&self) -> EntityId {
+    self.entity_id
+  }
+
+  fn get_effects(&self, _data: &mut AllData) -> Result<Vec<Effect>, AnyError> {
+    Ok(vec![])
+  }
+
+  fn can_execute(&self, data: &mut AllData) -> Result<(), AnyError> {
+    let entity = get_entity!(data, self.entity_id);
+    if !is_busy!(data, entity) {
+      return Err(anyhow!("you are not doing anything"));
+    }
+    Ok(())
+  }
+
+  fn execute(&self, data: &mut AllData) -> Result<(), AnyError> {
+    self.can_execute(data)?;
+    let entity = get_entity!(data, self.entity_id);
+    interrupt_action!(data, entity);
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::test::*;
+
+  #[test]
+  pub fn test_stop() {
+    init();
+    let mut headless = compass_rose_headless();
+    headless.input("stop");
+    assert_response_contains(&headless, "You are not doing anything.");
+    // Begin putting the mushroom away, but think better of it partway.
+    headless.game.write_input("put the mushroom in the basket");
+    headless.advance(10);
+    assert!(headless.game.has_actions_in_progress());
+    headless.input("stop");
+    assert_response_contains(&headless, "You stop trying to put the mushroom in the basket.");
+    assert!(!headless.game.has_actions_in_progress());
+    headless.input("look in the basket");
+    assert_response_contains(&headless, "There's nothing in basket.");
+  }
+}
@@ -0,0 +1,138 @@
+use crate::action::Actionable;
+use crate::containment::Containment;
+use crate::ecs::entity::EntityId;
+use crate::ecs::AllData;
+use crate::effect::*;
+use anyhow::Error as AnyError;
+
+/// The `Take` action.
+///
+/// The target can be taken from the room, or from in or on something else,
+/// but not from anyone else.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Take {
+  pub entity_id: EntityId,
+  pub target_entity_id: EntityId,
+}
+
+impl Actionable for Take {
+  fn get_actor_entity_id(&self) -> EntityId {
+    self.entity_id
+  }
+
+  fn get_effects(&self, _data: &mut AllData) -> Result<Vec<Effect>, AnyError> {
+    Ok(vec![
+      create_effect!(EntityTakesEntity {
+        entity_id: self.entity_id,
+        target_entity_id: self.target_entity_id,
+      }),
+      create_effect!(EntitySetInitiative {
+        entity_id: self.entity_id,
+        value: 0,
+      }),
+    ])
+  }
+
+  fn can_execute(&self, data: &mut AllData) -> Result<(), AnyError> {
+    let entity = get_entity!(data, self.entity_id);
+    let target_entity = get_entity!(data, self.target_entity_id);
+    if data.is_an_object.get(target_entity).is_none() {
+      return Err(anyhow!("that is not something you can take"));
+    }
+    if get_container!(data, target_entity) == Some((Containment::CarriedBy, self.entity_id)) {
+      return Err(anyhow!("you already have that"));
+    }
+    check_reach(data, self.entity_id, self.target_entity_id)?;
+    check_capacity!(data, entity, Containment::CarriedBy, target_entity)?;
+    Ok(())
+  }
+}
+
+/// Check that an entity can lay hands on a target.
+///
+/// It has to be in the same room, or in or on something that is, and not held
+/// by anyone else.
+pub fn check_reach(data: &mut AllData, entity_id: EntityId, target_entity_id: EntityId) -> Result<(), AnyError> {
+  let entity = get_entity!(data, entity_id);
+  let target_entity = get_entity!(data, target_entity_id);
+  let room_id = get_current_room_id!(data, entity);
+  if room_id.is_none() || get_outermost_room_id!(data, target_entity) != room_id {
+    return Err(anyhow!("that is not here"));
+  }
+  match get_holder!(data, target_entity) {
+    Some(holder) if holder != entity => Err(anyhow!("someone else has that")),
+    _ => Ok(()),
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::test::*;
+
+  #[test]
+  pub fn test_take_and_drop() {
+    init();
+    let mut headless = compass_rose_headless();
+    headless.input("take the mushroom");
+    assert_response_contains(&headless, "You take the mushroom.");
+    headless.input("inventory");
+    assert_response_contains(&headless, "Mushroom");
+    headless.input("look");
+    assert_response_lacks(&headless, "speckled mushroom grows");
+    headless.input("get mushroom");
+    assert_response_contains(&headless, "You already have that.");
+    headless.input("drop it");
+    assert_response_contains(&headless, "You drop the mushroom.");
+    headless.input("inventory");
+    assert_response_contains(&headless, "You aren't carrying anything.");
+    headless.input("look");
+    assert_response_contains(&headless, "speckled mushroom grows");
+    headless.input("take the stone");
+    assert_response_contains(&headless, "That is too heavy.");
+    // Taking something can be undone like anything else.
+    headless.input("pick up the basket");
+    assert_response_contains(&headless, "You take the basket.");
+    headless.input("undo");
+    headless.input("inventory");
+    assert_response_contains(&headless, "You aren't carrying anything.");
+  }
+
+  #[test]
+  pub fn test_take_all() {
+    init();
+    let mut headless = compass_rose_headless();
+    headless.input("take all");
+    assert_response_contains(&headless, "You take the mushroom.");
+    assert_response_contains(&headless, "You take the basket.");
+    // Actors and doors aren't what anyone means by "all".
+    assert_response_lacks(&headless, "Goblin:");
+    assert_response_lacks(&headless, "Door:");
+    assert_response_lacks(&headless, "not something you can take");
+    // Nor is what the player already has.
+    headless.input("take all");
+    assert_response_contains(&headless, "That is too heavy.");
+    assert_response_lacks(&headless, "You already have that.");
+  }
+
+  #[test]
+  pub fn test_drop_all() {
+    init();
+    let mut headless = compass_rose_headless();
+    headless.input("take all");
+    headless.input("drop all but the basket");
+    assert_response_contains(&headless, "You drop the mushroom.");
+    assert_response_lacks(&headless, "You drop the basket.");
+    headless.input("inventory");
+    assert_response_contains(&headless, "Basket");
+    assert_response_lacks(&headless, "Mushroom");
+    // What the player is carrying is still there to be examined.
+    headless.input("examine everything");
+    assert_response_contains(&headless, "You look at basket...");
+    headless.input("drop all");
+    assert_response_contains(&headless, "You drop the basket.");
+    assert_response_lacks(&headless, "You drop the mushroom.");
+    headless.input("inventory");
+    assert_response_contains(&headless, "You aren't carrying anything.");
+  }
+}
@@ -0,0 +1,76 @@
+use crate::action::Action;
+use crate::effect::Effect;
+
+/// The `InProgress` type.
+///
+/// An action with a nonzero duration that an actor has begun but not yet
+/// finished.  Its effects are worked out when it begins, but held back until
+/// it ends and then released together, so that nobody ever sees it half done
+/// and interrupting it leaves the world as it was.
+#[derive(Clone, Debug)]
+pub struct InProgress {
+  /// The action being performed.
+  pub action: Action,
+  /// What the actor is doing, e.g. "put the mushroom in the basket".
+  pub description: Option<String>,
+  /// The effects to be released when the action ends.
+  pub effects: Vec<Effect>,
+  /// The ticks elapsed since the action began.
+  pub elapsed: usize,
+  /// The ticks the action takes in all.
+  pub duration: usize,
+}
+
+impl InProgress {
+  /// Constructor.
+  pub fn new(action: Action, description: Option<String>, effects: Vec<Effect>, duration: usize) -> Self {
+    Self {
+      action,
+      description,
+      effects,
+      elapsed: 0,
+      duration,
+    }
+  }
+
+  /// Advance by a tick and take the effects, if they've come due.
+  pub fn advance(&mut self) -> Vec<Effect> {
+    self.elapsed += 1;
+    match self.is_complete() {
+      true => std::mem::take(&mut self.effects),
+      false => Vec::new(),
+    }
+  }
+
+  /// Whether the action has run its course.
+  pub fn is_complete(&self) -> bool {
+    self.elapsed >= self.duration
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::action::IdleAction;
+  use crate::ecs::entity::EntityId;
+  use crate::effect::EntitySetInitiative;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_advance() {
+    init();
+    let entity_id = EntityId(1);
+    let effects = (0..2)
+      .map(|value| create_effect!(EntitySetInitiative { entity_id, value }))
+      .collect::<Vec<Effect>>();
+    let action = create_action!(IdleAction { entity_id });
+    let mut in_progress = InProgress::new(action, None, effects, 4);
+    for _ in 0..3 {
+      assert!(in_progress.advance().is_empty());
+    }
+    assert!(!in_progress.is_complete());
+    assert_eq!(in_progress.advance().len(), 2);
+    assert!(in_progress.is_complete());
+  }
+}
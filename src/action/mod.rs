@@ -1,3 +1,4 @@
+use crate::condition::Condition;
 use crate::ecs::entity::EntityId;
 use crate::ecs::AllData;
 use crate::effect::Effect;
@@ -8,6 +9,8 @@ pub mod _trait;
 pub use _trait::actionable::Actionable;
 pub mod actions;
 pub use actions::*;
+pub mod in_progress;
+pub use in_progress::InProgress as ActionInProgress;
 
 /// The `Action` enum.
 ///
@@ -30,9 +33,39 @@ pub use actions::*;
 /// - actions may need to be reported to observers.
 /// - actions' effects can be divided over their duration, discretely or con-
 ///   tinuously.
+///
+/// Such an action is begun rather than executed, and tracked on its actor as
+/// an `ActionInProgress` until it ends or is interrupted.
 #[derive(Clone, Debug)]
 pub struct Action(pub Arc<dyn Actionable>);
 
+impl Action {
+  /// Begin an action that takes time.
+  ///
+  /// Its effects are worked out now and released when it ends, provided it
+  /// can still be carried out then.  Whatever else the actor was in the
+  /// middle of is abandoned.
+  pub fn begin(&self, data: &mut AllData) -> Result<(), AnyError> {
+    self.can_execute(data)?;
+    let effects = self.get_effects(data)?;
+    let description = self.describe(data);
+    let entity = get_entity!(data, self.get_actor_entity_id());
+    interrupt_action!(data, entity);
+    if let Some(description) = &description {
+      let name = get_name!(data, entity).cloned().unwrap_or_default();
+      narrate!(
+        data,
+        entity,
+        format!("You begin to {}.", description),
+        format!("{} begins to {}.", name, description)
+      );
+    }
+    let in_progress = ActionInProgress::new(self.clone(), description, effects, self.get_duration(data));
+    has_action_in_progress!(data, entity, in_progress);
+    Ok(())
+  }
+}
+
 impl Actionable for Action {
   /// Get the actor entity ID.
   fn get_actor_entity_id(&self) -> EntityId {
@@ -44,14 +77,35 @@ impl Actionable for Action {
     (*self.0).get_effects(data)
   }
 
+  /// Get the number of ticks this action takes.
+  fn get_duration(&self, data: &mut AllData) -> usize {
+    (*self.0).get_duration(data)
+  }
+
+  /// Describe what the actor is doing.
+  fn describe(&self, data: &mut AllData) -> Option<String> {
+    (*self.0).describe(data)
+  }
+
+  /// Get the conditions the actor must meet.
+  fn get_conditions(&self, data: &mut AllData) -> Vec<Condition> {
+    (*self.0).get_conditions(data)
+  }
+
   /// Can this action be executed?
+  ///
+  /// Every action is held to its conditions first, so that the reason given
+  /// for failure is the same whichever action falls foul of them.
   fn can_execute(&self, data: &mut AllData) -> Result<(), AnyError> {
+    let entity = get_entity!(data, self.get_actor_entity_id());
+    let conditions = self.get_conditions(data);
+    check_conditions!(data, entity, conditions)?;
     (*self.0).can_execute(data)
   }
 
   /// Execute the action.
   fn execute(&self, data: &mut AllData) -> Result<(), AnyError> {
-    (*self.0).can_execute(data)?;
+    self.can_execute(data)?;
     (*self.0).execute(data)
   }
 }
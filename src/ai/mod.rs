@@ -1,6 +1,7 @@
 use crate::action::Action;
 use crate::ecs::entity::EntityId;
 use crate::ecs::system::ai_processor::Data;
+use crate::perception::Perception;
 use anyhow::Error as AnyError;
 
 pub mod move_randomly;
@@ -22,4 +23,20 @@ impl Ai {
       MoveRandomly(engine) => Ok(engine.get_action(entity_id, data)?),
     }
   }
+
+  /// Decide whether to do what another actor has ordered.
+  pub fn consider_order(&self, entity_id: EntityId, giver_id: EntityId, data: &mut Data) -> bool {
+    use Ai::*;
+    match self {
+      MoveRandomly(engine) => engine.consider_order(entity_id, giver_id, data),
+    }
+  }
+
+  /// Let this AI know about something its entity perceived.
+  pub fn perceive(&self, perception: &Perception, data: &mut Data) {
+    use Ai::*;
+    match self {
+      MoveRandomly(engine) => engine.perceive(perception, data),
+    }
+  }
 }
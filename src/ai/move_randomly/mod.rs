@@ -1,10 +1,12 @@
 use crate::action::{Action, GoDirectionAction, LookAroundAction, LookDirectionAction};
+use crate::disposition::Disposition;
 use crate::ecs::entity::EntityId;
 use crate::ecs::system::ai_processor::Data;
 use crate::goap::ActionOption;
 use crate::goap::Planner;
 use crate::goap::State;
 use crate::map::Direction;
+use crate::perception::Perception;
 use anyhow::Error as AnyError;
 use rand::prelude::*;
 
@@ -58,4 +60,20 @@ impl MoveRandomly {
     }
     Ok(None)
   }
+
+  /// Decide whether to do what another actor has ordered.
+  ///
+  /// With nowhere in particular to be, it'll go along with anyone it's well-
+  /// disposed toward.
+  pub fn consider_order(&self, entity_id: EntityId, _giver_id: EntityId, data: &mut Data) -> bool {
+    let entity = get_entity!(data, entity_id);
+    get_disposition!(data, entity) >= Disposition::Friendly
+  }
+
+  /// Let this AI know about something its entity perceived.
+  ///
+  /// Wandering aimlessly, it doesn't much care.
+  pub fn perceive(&self, perception: &Perception, _data: &mut Data) {
+    debug!("{:?} perceived {:?}", perception.perceiver_id, perception.string);
+  }
 }
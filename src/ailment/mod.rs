@@ -4,7 +4,7 @@
 ///
 /// Some may or may not make sense depending on species, etc.  It's not likely
 /// that a stone golem will contract a disease.
-#[derive(Clone, Copy, Debug, Deserialize, Display, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Display, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum Ailment {
   Anosmic,
   Asleep,
@@ -34,3 +34,40 @@ pub enum Ailment {
   Stunned,
   Unconscious,
 }
+
+impl Ailment {
+  /// Describe the ailment to the one suffering from it, e.g. "your legs are
+  /// broken".
+  pub fn describe(&self) -> &'static str {
+    use Ailment::*;
+    match self {
+      Anosmic => "you cannot smell anything",
+      Asleep => "you are asleep",
+      Blind => "you are blind",
+      Confused => "you are too confused",
+      Deaf => "you are deaf",
+      Decapitated => "you have been decapitated",
+      Delusional => "you are delusional",
+      Exhausted => "you are exhausted",
+      Febrile => "you are feverish",
+      Handless => "you have no hands",
+      HandsBroken => "your hands are broken",
+      HandsDisabled => "your hands are useless",
+      HandsSevered => "your hands have been severed",
+      Hemorrhagic => "you are bleeding heavily",
+      Hungry => "you are hungry",
+      Insensate => "you cannot feel anything",
+      Legless => "you have no legs",
+      LegsBroken => "your legs are broken",
+      LegsDisabled => "your legs are useless",
+      LegsSevered => "your legs have been severed",
+      Mute => "you are mute",
+      Nauseous => "you are nauseous",
+      Paralyzed => "you are paralyzed",
+      Ravenous => "you are ravenous",
+      Starving => "you are starving",
+      Stunned => "you are stunned",
+      Unconscious => "you are unconscious",
+    }
+  }
+}
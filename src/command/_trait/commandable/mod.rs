@@ -7,4 +7,10 @@ use std::fmt::Debug;
 pub trait Commandable: Debug + Send + Sync {
   /// Execute the extra-diegetic command or return the intra-diegetic action.
   fn get_action(&self, data: &mut Data) -> Result<Option<Action>, Error>;
+
+  /// Whether this command goes ahead even while the player is busy, e.g.
+  /// `stop`.
+  fn is_urgent(&self) -> bool {
+    false
+  }
 }
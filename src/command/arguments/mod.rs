@@ -0,0 +1,41 @@
+use crate::ecs::entity::{EntityId, PlayerId};
+use crate::input::Preposition;
+use crate::map::Direction;
+
+/// The `Arguments` type.
+///
+/// What the parser made out of a sentence, for a `CommandKind` to create a
+/// command from.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Arguments {
+  pub player_id: PlayerId,
+  pub original_input: String,
+  /// What each noun phrase referred to, in order, if it could be made out;
+  /// the direct object comes before the indirect object.
+  pub entity_ids: Vec<Option<EntityId>>,
+  /// The direction, if there was one.
+  pub direction: Option<Direction>,
+  /// The preposition, if there was one, e.g. "on" in "put X on Y".
+  pub preposition: Option<Preposition>,
+  /// Whatever was taken verbatim, e.g. a file path.
+  pub text: Option<String>,
+}
+
+impl Arguments {
+  /// Constructor.
+  pub fn new(player_id: PlayerId, original_input: &str) -> Self {
+    Self {
+      player_id,
+      original_input: original_input.to_owned(),
+      entity_ids: Vec::new(),
+      direction: None,
+      preposition: None,
+      text: None,
+    }
+  }
+
+  /// Get what the noun phrase at the given position referred to.
+  pub fn get_entity_id(&self, index: usize) -> Option<EntityId> {
+    self.entity_ids.get(index).copied().flatten()
+  }
+}
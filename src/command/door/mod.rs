@@ -0,0 +1,10 @@
+pub mod close;
+pub use close::Close as CloseCommand;
+pub mod knock;
+pub use knock::Knock as KnockCommand;
+pub mod lock;
+pub use lock::Lock as LockCommand;
+pub mod open;
+pub use open::Open as OpenCommand;
+pub mod unlock;
+pub use unlock::Unlock as UnlockCommand;
@@ -0,0 +1,26 @@
+use crate::action::Action;
+use crate::action::UnlockAction;
+use crate::command::Commandable;
+use crate::ecs::entity::EntityId;
+use crate::ecs::entity::PlayerId;
+use crate::ecs::system::command_processor::Data as CommandProcessorData;
+use anyhow::Error;
+
+/// The `Unlock` command.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Unlock {
+  pub player_id: PlayerId,
+  pub target_entity_id: EntityId,
+  pub key_entity_id: Option<EntityId>,
+  pub original_input: String,
+}
+
+impl Commandable for Unlock {
+  fn get_action(&self, _data: &mut CommandProcessorData) -> Result<Option<Action>, Error> {
+    Ok(Some(create_action!(UnlockAction {
+      entity_id: self.player_id.into(),
+      target_entity_id: self.target_entity_id,
+      key_entity_id: self.key_entity_id,
+    })))
+  }
+}
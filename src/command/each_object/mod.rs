@@ -0,0 +1,50 @@
+use super::Command;
+use crate::action::{Action, EachObjectAction};
+use crate::command::Commandable;
+use crate::ecs::entity::PlayerId;
+use crate::ecs::system::command_processor::Data as CommandProcessorData;
+use anyhow::Error;
+
+/// The `EachObject` command.
+///
+/// This is a command given for a set of objects at once, e.g. "look at all
+/// but the goblin", split into one command for each object.
+#[derive(Clone, Debug)]
+pub struct EachObject {
+  pub player_id: PlayerId,
+  /// Each object's name, and the command for it.
+  pub commands: Vec<(String, Command)>,
+}
+
+impl Commandable for EachObject {
+  fn get_action(&self, data: &mut CommandProcessorData) -> Result<Option<Action>, Error> {
+    let mut actions = Vec::new();
+    for (name, command) in self.commands.iter() {
+      if let Some(action) = command.get_action(data)? {
+        actions.push((name.clone(), action));
+      }
+    }
+    if actions.is_empty() {
+      return Ok(None);
+    }
+    Ok(Some(create_action!(EachObjectAction {
+      entity_id: self.player_id.into(),
+      actions,
+    })))
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::test::*;
+
+  #[test]
+  pub fn test_each_object() {
+    init();
+    let mut headless = compass_rose_headless();
+    headless.input("look at everything but the goblin");
+    assert_response_contains(&headless, "speckled mushroom grows");
+    assert_response_lacks(&headless, "goblin is short");
+  }
+}
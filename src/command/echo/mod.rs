@@ -14,7 +14,7 @@ pub struct Echo {
 
 impl Commandable for Echo {
   fn get_action(&self, data: &mut CommandProcessorData) -> Result<Option<Action>, Error> {
-    write_output_event!(data, self.string.clone());
+    write_output_event!(data, self.player_id, self.string.clone());
     Ok(None)
   }
 }
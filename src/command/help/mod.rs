@@ -0,0 +1,105 @@
+use crate::action::Action;
+use crate::command::Commandable;
+use crate::ecs::entity::PlayerId;
+use crate::ecs::system::command_processor::Data as CommandProcessorData;
+use crate::input::grammar::{GrammarVerb, GRAMMAR};
+use crate::output::{Block, Document, Span};
+use anyhow::Error;
+
+/// The `Help` command.
+///
+/// This lists the verbs the parser knows, or, given one of them, e.g. `help
+/// look`, the sentences that can be made with it.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Help {
+  pub player_id: PlayerId,
+  pub topic: String,
+  pub original_input: String,
+}
+
+impl Help {
+  /// List every verb, those done in the world apart from those that aren't.
+  pub fn get_verbs_document(&self) -> Document {
+    let list = |in_character: bool| {
+      Block::List(
+        GRAMMAR
+          .verbs
+          .iter()
+          .filter(|verb| verb.is_in_character() == in_character)
+          .map(|verb| vec![Span::new(&describe_synonyms(verb))])
+          .collect(),
+      )
+    };
+    let mut document = Document::from("Things you can do in the world:");
+    document.push(list(true));
+    document.append(Document::from("Things you can do with the game itself:"));
+    document.push(list(false));
+    document.append(Document::from(
+      "Type \"help\" and a verb, e.g. \"help look\", to learn more.",
+    ));
+    document
+  }
+
+  /// Describe one verb, and everything that can be said with it.
+  pub fn get_verb_document(&self, verb: &GrammarVerb) -> Document {
+    let mut document = Document::from(format!("{}:", describe_synonyms(verb)));
+    document.push(Block::List(
+      verb
+        .describe_syntaxes()
+        .iter()
+        .map(|description| vec![Span::new(description)])
+        .collect(),
+    ));
+    document
+  }
+}
+
+impl Commandable for Help {
+  fn get_action(&self, data: &mut CommandProcessorData) -> Result<Option<Action>, Error> {
+    let topic = self.topic.trim().to_lowercase();
+    if topic.is_empty() {
+      write_output_event!(data, self.player_id, self.get_verbs_document());
+      return Ok(None);
+    }
+    match GRAMMAR.get_verb(&topic) {
+      Some(verb) => write_output_event!(data, self.player_id, self.get_verb_document(verb)),
+      None => write_output_event!(data, self.player_id, format!("I don't know the verb \"{}\".", topic)),
+    }
+    Ok(None)
+  }
+}
+
+/// Describe a verb by its names, e.g. "look (or l, examine, x)".
+fn describe_synonyms(verb: &GrammarVerb) -> String {
+  match verb.synonyms.split_first() {
+    Some((first, [])) => first.clone(),
+    Some((first, others)) => format!("{} (or {})", first, others.join(", ")),
+    None => String::new(),
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::test::*;
+
+  #[test]
+  pub fn test_help() {
+    init();
+    let mut headless = compass_rose_headless();
+    headless.input("help");
+    let response = get_response(&headless).join("\n");
+    assert!(response.contains("look (or l, examine, x)"));
+    assert!(response.find("go (or walk)").unwrap() < response.find("Things you can do with the game").unwrap());
+    headless.input("help examine");
+    let response = get_response(&headless).join("\n");
+    assert!(response.contains("look at <something>"));
+    assert!(response.contains("look <direction>"));
+    headless.input("help put");
+    assert!(get_response(&headless)
+      .join("\n")
+      .contains("put <something> <preposition> <something>"));
+    headless.input("help frobnicate");
+    assert_eq!(get_response(&headless)[0], "I don't know the verb \"frobnicate\".");
+  }
+}
@@ -0,0 +1,41 @@
+use crate::action::Action;
+use crate::command::Commandable;
+use crate::containment::Containment;
+use crate::ecs::entity::PlayerId;
+use crate::ecs::system::command_processor::Data as CommandProcessorData;
+use crate::output::{Block, Document, Span};
+use anyhow::Error;
+
+/// The `Inventory` command.
+///
+/// This lists what the player is carrying, and whatever is in or on that.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Inventory {
+  pub player_id: PlayerId,
+  pub original_input: String,
+}
+
+impl Commandable for Inventory {
+  fn get_action(&self, data: &mut CommandProcessorData) -> Result<Option<Action>, Error> {
+    let player = get_entity!(data, self.player_id);
+    let carried = get_contents!(data, player, Containment::CarriedBy);
+    if carried.is_empty() {
+      write_output_event!(data, self.player_id, "You aren't carrying anything.");
+      return Ok(None);
+    }
+    let mut items = Vec::new();
+    for entity in carried {
+      let name = get_name!(data, entity).cloned().unwrap_or_default();
+      let contents = describe_contents!(data, entity);
+      let item = match contents.is_empty() {
+        true => name,
+        false => format!("{}. {}", name, contents.join(" ")),
+      };
+      items.push(vec![Span::new(&item)]);
+    }
+    let mut document = Document::from("You are carrying:");
+    document.push(Block::List(items));
+    write_output_event!(data, self.player_id, document);
+    Ok(None)
+  }
+}
@@ -0,0 +1,260 @@
+use super::*;
+use crate::containment::Containment;
+use crate::ecs::entity::EntityId;
+use crate::input::{InputError, Preposition, Referent};
+use crate::output::Verbosity;
+
+/// The `Kind` enum.
+///
+/// Each variant is a command that a sentence in the grammar can stand for,
+/// and knows how to create that command from the sentence's arguments.
+#[derive(Clone, Copy, Debug, Deserialize, Display, Eq, Hash, PartialEq, Serialize)]
+pub enum Kind {
+  Brief,
+  Close,
+  Drop,
+  Echo,
+  Eval,
+  GoDirection,
+  Help,
+  Inventory,
+  Knock,
+  Lock,
+  LookAround,
+  LookAtEntity,
+  LookDirection,
+  Map,
+  Open,
+  Pause,
+  Put,
+  Quit,
+  Restore,
+  Resume,
+  Save,
+  Score,
+  Search,
+  Speed,
+  Stop,
+  Take,
+  Time,
+  Undo,
+  Unlock,
+  Verbose,
+  Wait,
+}
+
+impl Kind {
+  /// Is this command done within the game world?
+  pub fn is_in_character(&self) -> bool {
+    use Kind::*;
+    matches!(
+      self,
+      Close
+        | Drop
+        | GoDirection
+        | Knock
+        | Lock
+        | LookAround
+        | LookAtEntity
+        | LookDirection
+        | Open
+        | Put
+        | Search
+        | Stop
+        | Take
+        | Unlock
+    )
+  }
+
+  /// Whether "all" takes in something the player might refer to, given this
+  /// command; e.g. "drop all" means everything the player is carrying, and
+  /// "take all" everything lying around that could be picked up.
+  pub fn is_included_in_all(&self, referent: &Referent, player_id: EntityId) -> bool {
+    use Kind::*;
+    match self {
+      Drop | Put => referent.container_id == Some(player_id),
+      Take => referent.is_an_object && referent.owner_id.is_none(),
+      _ => true,
+    }
+  }
+
+  /// Create the command.
+  pub fn create(&self, arguments: CommandArguments) -> Result<Command, AnyError> {
+    use Kind::*;
+    let player_id = arguments.player_id;
+    let original_input = arguments.original_input.clone();
+    let get_direction = || arguments.direction.ok_or_else(|| anyhow!("Expected a direction."));
+    let get_entity_id = |index| arguments.get_entity_id(index).ok_or(InputError::NotHere);
+    match self {
+      Brief => Ok(create_command!(SetVerbosityCommand {
+        player_id,
+        verbosity: Verbosity::Brief,
+        original_input,
+      })),
+      Close => Ok(create_command!(CloseCommand {
+        player_id,
+        target_entity_id: get_entity_id(0)?,
+        original_input,
+      })),
+      Drop => Ok(create_command!(DropCommand {
+        player_id,
+        target_entity_id: get_entity_id(0)?,
+        original_input,
+      })),
+      Echo => Ok(create_command!(EchoCommand {
+        player_id,
+        string: arguments.text.unwrap_or_default(),
+        original_input,
+      })),
+      Eval => Ok(create_command!(EvalCommand {
+        player_id,
+        string: arguments.text.unwrap_or_default(),
+        original_input,
+      })),
+      GoDirection => Ok(create_command!(GoDirectionCommand {
+        player_id,
+        direction: get_direction()?,
+        original_input,
+      })),
+      Help => Ok(create_command!(HelpCommand {
+        player_id,
+        topic: arguments.text.unwrap_or_default(),
+        original_input,
+      })),
+      Inventory => Ok(create_command!(InventoryCommand {
+        player_id,
+        original_input,
+      })),
+      Knock => Ok(create_command!(KnockCommand {
+        player_id,
+        target_entity_id: get_entity_id(0)?,
+        original_input,
+      })),
+      Lock => Ok(create_command!(LockCommand {
+        player_id,
+        target_entity_id: get_entity_id(0)?,
+        key_entity_id: arguments.get_entity_id(1),
+        original_input,
+      })),
+      LookAround => Ok(create_command!(LookAroundCommand {
+        player_id,
+        original_input,
+      })),
+      // A noun phrase that couldn't be made out leaves nothing in particular
+      // to look at.
+      LookAtEntity => match arguments.get_entity_id(0) {
+        Some(target_entity_id) => Ok(create_command!(LookAtEntityCommand {
+          player_id,
+          target_entity_id,
+          preposition: arguments.preposition.unwrap_or(Preposition::At),
+          original_input,
+        })),
+        None => LookAround.create(arguments),
+      },
+      LookDirection => Ok(create_command!(LookDirectionCommand {
+        player_id,
+        direction: get_direction()?,
+        original_input,
+      })),
+      Map => Ok(create_command!(MapCommand {
+        player_id,
+        original_input,
+      })),
+      Open => Ok(create_command!(OpenCommand {
+        player_id,
+        target_entity_id: get_entity_id(0)?,
+        original_input,
+      })),
+      Pause => Ok(create_command!(PauseCommand {
+        player_id,
+        original_input,
+      })),
+      // The preposition says how the direct object is to be held by the
+      // indirect object, if that's something one thing can do for another.
+      Put => {
+        let preposition = arguments
+          .preposition
+          .ok_or_else(|| anyhow!("Expected a preposition."))?;
+        match Containment::from_preposition(preposition) {
+          Some(Containment::In) => Ok(create_command!(PutInCommand {
+            player_id,
+            target_entity_id: get_entity_id(0)?,
+            container_entity_id: get_entity_id(1)?,
+            original_input,
+          })),
+          Some(Containment::On) => Ok(create_command!(PutOnCommand {
+            player_id,
+            target_entity_id: get_entity_id(0)?,
+            container_entity_id: get_entity_id(1)?,
+            original_input,
+          })),
+          _ => Err(InputError::CannotPut(preposition).into()),
+        }
+      },
+      Quit => Ok(create_command!(QuitCommand {
+        player_id,
+        original_input,
+      })),
+      Restore => Ok(create_command!(RestoreCommand {
+        player_id,
+        path: arguments.text.unwrap_or_else(|| DEFAULT_SAVE_FILE.to_owned()),
+        original_input,
+      })),
+      Resume => Ok(create_command!(ResumeCommand {
+        player_id,
+        original_input,
+      })),
+      Save => Ok(create_command!(SaveCommand {
+        player_id,
+        path: arguments.text.unwrap_or_else(|| DEFAULT_SAVE_FILE.to_owned()),
+        original_input,
+      })),
+      Score => Ok(create_command!(ScoreCommand {
+        player_id,
+        original_input,
+      })),
+      Search => Ok(create_command!(SearchCommand {
+        player_id,
+        original_input,
+      })),
+      Speed => Ok(create_command!(SpeedCommand {
+        player_id,
+        string: arguments.text.unwrap_or_default(),
+        original_input,
+      })),
+      Stop => Ok(create_command!(StopCommand {
+        player_id,
+        original_input,
+      })),
+      Take => Ok(create_command!(TakeCommand {
+        player_id,
+        target_entity_id: get_entity_id(0)?,
+        original_input,
+      })),
+      Time => Ok(create_command!(TimeCommand {
+        player_id,
+        original_input,
+      })),
+      Undo => Ok(create_command!(UndoCommand {
+        player_id,
+        original_input,
+      })),
+      Unlock => Ok(create_command!(UnlockCommand {
+        player_id,
+        target_entity_id: get_entity_id(0)?,
+        key_entity_id: arguments.get_entity_id(1),
+        original_input,
+      })),
+      Verbose => Ok(create_command!(SetVerbosityCommand {
+        player_id,
+        verbosity: Verbosity::Verbose,
+        original_input,
+      })),
+      Wait => Ok(create_command!(WaitCommand {
+        player_id,
+        string: arguments.text.unwrap_or_default(),
+        original_input,
+      })),
+    }
+  }
+}
@@ -4,6 +4,7 @@ use crate::command::Commandable;
 use crate::ecs::entity::EntityId;
 use crate::ecs::entity::PlayerId;
 use crate::ecs::system::command_processor::Data as CommandProcessorData;
+use crate::input::Preposition;
 use anyhow::Error;
 
 /// The `LookAtEntity` command.
@@ -11,6 +12,7 @@ use anyhow::Error;
 pub struct LookAtEntity {
   pub player_id: PlayerId,
   pub target_entity_id: EntityId,
+  pub preposition: Preposition,
   pub original_input: String,
 }
 
@@ -19,6 +21,7 @@ impl Commandable for LookAtEntity {
     Ok(Some(create_action!(LookAtEntityAction {
       entity_id: self.player_id.into(),
       target_entity_id: self.target_entity_id,
+      preposition: self.preposition,
     })))
   }
 }
@@ -0,0 +1,57 @@
+use crate::action::Action;
+use crate::command::Commandable;
+use crate::ecs::entity::PlayerId;
+use crate::ecs::system::command_processor::Data as CommandProcessorData;
+use crate::output::{Block, Document};
+use anyhow::Error;
+
+/// The `Map` command.
+///
+/// This shows the map of the world, as far as it has been explored.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Map {
+  pub player_id: PlayerId,
+  pub original_input: String,
+}
+
+impl Commandable for Map {
+  fn get_action(&self, data: &mut CommandProcessorData) -> Result<Option<Action>, Error> {
+    match &data.tile_map_resource.0 {
+      Some(tile_map) => {
+        let mut document = Document::new();
+        document.push(Block::Preformatted(tile_map.get_spans()));
+        write_output_event!(data, self.player_id, document);
+      },
+      None => write_output_event!(data, self.player_id, "There's no map of this world."),
+    }
+    Ok(None)
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::ecs::resource::TileMapResource;
+  use crate::game::{GameSettings, Headless};
+  use crate::map::MapBuilderKind;
+  use crate::test::*;
+  use specs::prelude::*;
+
+  #[test]
+  pub fn test_map() {
+    init();
+    let mut headless = compass_rose_headless();
+    headless.input("map");
+    assert_eq!(get_response(&headless)[0], "There's no map of this world.");
+    let settings = GameSettings {
+      map_builder: MapBuilderKind::TrivialMaze,
+      ..GameSettings::default()
+    };
+    let mut headless = Headless::from_settings(&settings);
+    headless.input("map");
+    let response = get_response(&headless);
+    let tile_map = headless.game.ecs.read_resource::<TileMapResource>();
+    let expected = tile_map.0.as_ref().unwrap().to_string();
+    assert!(response.join("\n").contains(expected.trim_end()));
+  }
+}
@@ -1,26 +1,71 @@
 use crate::action::Action;
 use crate::ecs::system::command_processor::Data as CommandProcessorData;
-use crate::input::ParserData;
-use crate::input::{Token, TokenType};
 use anyhow::Error as AnyError;
 use std::sync::Arc;
 
 pub mod _trait;
 pub use _trait::*;
+pub mod arguments;
+pub use arguments::Arguments as CommandArguments;
+pub mod door;
+pub use door::*;
+pub mod drop;
+pub use drop::Drop as DropCommand;
+pub mod each_object;
+pub use each_object::EachObject as EachObjectCommand;
 pub mod echo;
 pub use echo::Echo as EchoCommand;
 pub mod eval;
 pub use eval::Eval as EvalCommand;
 pub mod go_direction;
 pub use go_direction::GoDirection as GoDirectionCommand;
+pub mod help;
+pub use help::Help as HelpCommand;
 pub mod idle;
 pub use idle::Idle as IdleCommand;
+pub mod inventory;
+pub use inventory::Inventory as InventoryCommand;
+pub mod kind;
+pub use kind::Kind as CommandKind;
 pub mod look;
 pub use look::*;
+pub mod map;
+pub use map::Map as MapCommand;
 pub mod order;
 pub use order::Order as OrderCommand;
+pub mod pause;
+pub use pause::Pause as PauseCommand;
+pub mod put;
+pub use put::*;
 pub mod quit;
 pub use quit::Quit as QuitCommand;
+pub mod restore;
+pub use restore::Restore as RestoreCommand;
+pub mod resume;
+pub use resume::Resume as ResumeCommand;
+pub mod save;
+pub use save::Save as SaveCommand;
+pub mod score;
+pub use score::Score as ScoreCommand;
+pub mod search;
+pub use search::Search as SearchCommand;
+pub mod set_verbosity;
+pub use set_verbosity::SetVerbosity as SetVerbosityCommand;
+pub mod speed;
+pub use speed::Speed as SpeedCommand;
+pub mod stop;
+pub use stop::Stop as StopCommand;
+pub mod take;
+pub use take::Take as TakeCommand;
+pub mod time;
+pub use time::Time as TimeCommand;
+pub mod undo;
+pub use undo::Undo as UndoCommand;
+pub mod wait;
+pub use wait::Wait as WaitCommand;
+
+/// The file used by `save` and `restore` when none is given.
+pub const DEFAULT_SAVE_FILE: &str = "hornvale.sav";
 
 /// The `Command` enum.
 ///
@@ -45,51 +90,6 @@ pub use quit::Quit as QuitCommand;
 #[derive(Clone, Debug)]
 pub struct Command(pub Arc<dyn Commandable>);
 
-impl Command {
-  /// Create a command based on the parser tokens and the passed data.
-  pub fn from_data(
-    original_input: String,
-    string: String,
-    tokens: Vec<Token<'_>>,
-    data: &impl ParserData,
-  ) -> Result<Command, AnyError> {
-    let player_id = data.get_player_id()?;
-    if let Some(first) = tokens.first() {
-      match first.r#type {
-        TokenType::Echo => Ok(create_command!(EchoCommand {
-          player_id,
-          string,
-          original_input,
-        })),
-        TokenType::Eval => Ok(create_command!(EvalCommand {
-          player_id,
-          string,
-          original_input,
-        })),
-        TokenType::Go => Ok(create_command!(GoDirectionCommand::from_data(
-          original_input,
-          string,
-          tokens,
-          data
-        )?)),
-        TokenType::Look => Ok(create_command!(LookCommandFactory::from_data(
-          original_input,
-          string,
-          tokens,
-          data
-        )?)),
-        TokenType::Quit => Ok(create_command!(QuitCommand {
-          player_id,
-          original_input,
-        })),
-        _ => Err(anyhow!("Couldn't match first token: {:#?}", tokens)),
-      }
-    } else {
-      bail!("Couldn't get first token.")
-    }
-  }
-}
-
 impl Commandable for Command {
   /// Retrieve an action for this command, or evaluate it.
   ///
@@ -107,4 +107,9 @@ impl Commandable for Command {
   fn get_action(&self, data: &mut CommandProcessorData) -> Result<Option<Action>, AnyError> {
     (*self.0).get_action(data)
   }
+
+  /// Whether this command goes ahead even while the player is busy.
+  fn is_urgent(&self) -> bool {
+    (*self.0).is_urgent()
+  }
 }
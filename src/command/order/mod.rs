@@ -1,19 +1,95 @@
 use super::Command;
-use crate::action::Action;
+use crate::action::{Action, GiveOrderAction};
 use crate::command::Commandable;
-use crate::ecs::entity::PlayerId;
+use crate::ecs::entity::{EntityId, PlayerId};
 use crate::ecs::system::command_processor::Data as CommandProcessorData;
 use anyhow::Error;
 
 /// The `Order` command.
+///
+/// This tells another actor to do something, e.g. "goblin, go north".  The
+/// inner command was parsed on the addressee's behalf, so its action is the
+/// addressee's to perform; the player's part is just to give the order.
 #[derive(Clone, Debug)]
 pub struct Order {
   pub player_id: PlayerId,
+  /// The actor being given the order.
+  pub addressee_id: EntityId,
+  /// The order, as it was said, e.g. "go north".
+  pub order: String,
   pub command: Box<Command>,
 }
 
 impl Commandable for Order {
   fn get_action(&self, data: &mut CommandProcessorData) -> Result<Option<Action>, Error> {
-    self.command.get_action(data)
+    let action = self
+      .command
+      .get_action(data)?
+      .ok_or_else(|| anyhow!("You can't tell someone else to do that."))?;
+    Ok(Some(create_action!(GiveOrderAction {
+      entity_id: self.player_id.into(),
+      addressee_id: self.addressee_id,
+      order: self.order.clone(),
+      action,
+    })))
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::disposition::Disposition;
+  use crate::ecs::component::*;
+  use crate::test::*;
+  use specs::prelude::*;
+
+  #[test]
+  pub fn test_order() {
+    init();
+    let mut headless = compass_rose_headless();
+    let player = get_player(&headless);
+    let ecs = &mut headless.game.ecs;
+    let goblin = (&ecs.entities(), &ecs.read_storage::<HasAi>())
+      .join()
+      .map(|(entity, _)| entity)
+      .next()
+      .unwrap();
+    // Bring the goblin back, and keep it from wandering off on its own.
+    let room = ecs.read_storage::<IsInRoom>().get(player).cloned().unwrap();
+    ecs.write_storage::<IsInRoom>().insert(goblin, room.clone()).unwrap();
+    ecs
+      .write_storage::<HasInitiative>()
+      .get_mut(goblin)
+      .unwrap()
+      .0
+      .increment = 0;
+    headless.input("goblin, go north");
+    headless.advance(headless.ticks_per_input);
+    assert!(headless
+      .transcript
+      .contains(&"You tell goblin to go north.".to_string()));
+    assert!(headless.transcript.contains(&"Goblin ignores you.".to_string()));
+    let ecs = &mut headless.game.ecs;
+    assert_eq!(ecs.read_storage::<IsInRoom>().get(goblin), Some(&room));
+    ecs
+      .write_storage::<HasDisposition>()
+      .insert(goblin, HasDisposition(Disposition::Friendly))
+      .unwrap();
+    headless.input("goblin, go north");
+    let ecs = &mut headless.game.ecs;
+    ecs
+      .write_storage::<HasInitiative>()
+      .get_mut(goblin)
+      .unwrap()
+      .0
+      .increment = 251;
+    headless.advance(headless.ticks_per_input);
+    let ecs = &headless.game.ecs;
+    let room_id = ecs.read_storage::<IsInRoom>().get(goblin).unwrap().0;
+    let room_name = ecs
+      .read_storage::<HasName>()
+      .get(ecs.entities().entity(room_id.0))
+      .cloned();
+    assert_eq!(room_name.unwrap().0, "North Room");
   }
 }
@@ -0,0 +1,27 @@
+use crate::action::Action;
+use crate::command::Commandable;
+use crate::ecs::entity::PlayerId;
+use crate::ecs::system::command_processor::Data as CommandProcessorData;
+use anyhow::Error;
+
+/// The `Pause` command.
+///
+/// This stops the clock.  Input is still processed, but the world advances
+/// only as far as it must to process it.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Pause {
+  pub player_id: PlayerId,
+  pub original_input: String,
+}
+
+impl Commandable for Pause {
+  fn get_action(&self, data: &mut CommandProcessorData) -> Result<Option<Action>, Error> {
+    if data.multiplayer_resource.0 {
+      write_output_event!(data, self.player_id, "You can't pause while others are playing.");
+      return Ok(None);
+    }
+    data.time_scale_resource.paused = true;
+    write_output_event!(data, "The world holds its breath.");
+    Ok(None)
+  }
+}
@@ -0,0 +1,4 @@
+pub mod in_entity;
+pub use in_entity::PutIn as PutInCommand;
+pub mod on_entity;
+pub use on_entity::PutOn as PutOnCommand;
@@ -1,10 +1,14 @@
 use crate::action::Action;
 use crate::command::Commandable;
 use crate::ecs::entity::PlayerId;
+use crate::ecs::event::MetaEvent;
 use crate::ecs::system::command_processor::Data as CommandProcessorData;
 use anyhow::Error;
 
 /// The `Quit` command.
+///
+/// This ends the game, or, when others are playing, just the player's part
+/// in it.  Either way, it's up to whatever is running the game.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct Quit {
   pub player_id: PlayerId,
@@ -12,8 +16,25 @@ pub struct Quit {
 }
 
 impl Commandable for Quit {
-  fn get_action(&self, _data: &mut CommandProcessorData) -> Result<Option<Action>, Error> {
-    panic!("WTF");
-    // Ok(None)
+  fn get_action(&self, data: &mut CommandProcessorData) -> Result<Option<Action>, Error> {
+    write_output_event!(data, self.player_id, "Farewell.");
+    write_meta_event!(data, MetaEvent::Quit(self.player_id));
+    Ok(None)
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::test::*;
+
+  #[test]
+  pub fn test_quit() {
+    init();
+    let mut headless = compass_rose_headless();
+    assert!(!headless.game.quitting);
+    headless.input("quit");
+    assert_response_contains(&headless, "Farewell.");
+    assert!(headless.game.quitting);
   }
 }
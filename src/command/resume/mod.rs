@@ -0,0 +1,27 @@
+use crate::action::Action;
+use crate::command::Commandable;
+use crate::ecs::entity::PlayerId;
+use crate::ecs::system::command_processor::Data as CommandProcessorData;
+use anyhow::Error;
+
+/// The `Resume` command.
+///
+/// This restarts the clock after a pause, and leaves turn-based mode.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Resume {
+  pub player_id: PlayerId,
+  pub original_input: String,
+}
+
+impl Commandable for Resume {
+  fn get_action(&self, data: &mut CommandProcessorData) -> Result<Option<Action>, Error> {
+    if data.multiplayer_resource.0 {
+      write_output_event!(data, self.player_id, "You can't resume while others are playing.");
+      return Ok(None);
+    }
+    data.time_scale_resource.paused = false;
+    data.time_scale_resource.turn_based = false;
+    write_output_event!(data, "The world breathes again.");
+    Ok(None)
+  }
+}
@@ -0,0 +1,28 @@
+use crate::action::Action;
+use crate::command::Commandable;
+use crate::ecs::entity::PlayerId;
+use crate::ecs::event::MetaEvent;
+use crate::ecs::system::command_processor::Data as CommandProcessorData;
+use anyhow::Error;
+
+/// The `Save` command.
+///
+/// This saves the world to a file.  The save itself is performed by the game
+/// once the current tick is done.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Save {
+  pub player_id: PlayerId,
+  pub path: String,
+  pub original_input: String,
+}
+
+impl Commandable for Save {
+  fn get_action(&self, data: &mut CommandProcessorData) -> Result<Option<Action>, Error> {
+    if data.multiplayer_resource.0 {
+      write_output_event!(data, self.player_id, "You can't save while others are playing.");
+      return Ok(None);
+    }
+    write_meta_event!(data, MetaEvent::Save(self.path.clone()));
+    Ok(None)
+  }
+}
@@ -0,0 +1,34 @@
+use crate::action::Action;
+use crate::command::Commandable;
+use crate::ecs::entity::PlayerId;
+use crate::ecs::system::command_processor::Data as CommandProcessorData;
+use anyhow::Error;
+use specs::prelude::*;
+
+/// The `Score` command.
+///
+/// There's no winning or losing, so this reports how much of the world the
+/// player has explored.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Score {
+  pub player_id: PlayerId,
+  pub original_input: String,
+}
+
+impl Commandable for Score {
+  fn get_action(&self, data: &mut CommandProcessorData) -> Result<Option<Action>, Error> {
+    let player = get_entity!(data, self.player_id);
+    let visited = data
+      .has_visited_rooms
+      .get(player)
+      .map(|has_visited_rooms| has_visited_rooms.0.len())
+      .unwrap_or_default();
+    let total = (&data.entities, &data.is_a_room).join().count();
+    write_output_event!(
+      data,
+      self.player_id,
+      format!("You have explored {} of {} rooms.", visited, total)
+    );
+    Ok(None)
+  }
+}
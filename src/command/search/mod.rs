@@ -0,0 +1,20 @@
+use crate::action::{Action, SearchAction};
+use crate::command::Commandable;
+use crate::ecs::entity::PlayerId;
+use crate::ecs::system::command_processor::Data as CommandProcessorData;
+use anyhow::Error;
+
+/// The `Search` command.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Search {
+  pub player_id: PlayerId,
+  pub original_input: String,
+}
+
+impl Commandable for Search {
+  fn get_action(&self, _data: &mut CommandProcessorData) -> Result<Option<Action>, Error> {
+    Ok(Some(create_action!(SearchAction {
+      entity_id: self.player_id.into(),
+    })))
+  }
+}
@@ -0,0 +1,61 @@
+use crate::action::Action;
+use crate::command::Commandable;
+use crate::ecs::entity::PlayerId;
+use crate::ecs::system::command_processor::Data as CommandProcessorData;
+use crate::output::Verbosity;
+use anyhow::Error;
+
+/// The `SetVerbosity` command.
+///
+/// This sets whether rooms the player has visited are described in full on
+/// their return, i.e. `verbose`, or only named, i.e. `brief`.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct SetVerbosity {
+  pub player_id: PlayerId,
+  pub verbosity: Verbosity,
+  pub original_input: String,
+}
+
+impl Commandable for SetVerbosity {
+  fn get_action(&self, data: &mut CommandProcessorData) -> Result<Option<Action>, Error> {
+    let player = get_entity!(data, self.player_id);
+    has_verbosity!(data, player, self.verbosity);
+    match self.verbosity {
+      Verbosity::Brief => write_output_event!(
+        data,
+        self.player_id,
+        "Rooms you've visited before will be described briefly."
+      ),
+      Verbosity::Verbose => write_output_event!(data, self.player_id, "Rooms will be described in full every time."),
+    }
+    Ok(None)
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::ecs::component::HasVerbosity;
+  use crate::game::Headless;
+  use crate::output::Verbosity;
+  use crate::test::*;
+  use specs::prelude::*;
+
+  #[test]
+  pub fn test_set_verbosity() {
+    init();
+    let mut headless = compass_rose_headless();
+    let get_verbosity = |headless: &Headless| {
+      let ecs = &headless.game.ecs;
+      let player = get_player(headless);
+      ecs
+        .read_storage::<HasVerbosity>()
+        .get(player)
+        .map(|has_verbosity| has_verbosity.0)
+    };
+    headless.input("brief");
+    assert_eq!(get_verbosity(&headless), Some(Verbosity::Brief));
+    headless.input("verbose");
+    assert_eq!(get_verbosity(&headless), Some(Verbosity::Verbose));
+  }
+}
@@ -0,0 +1,73 @@
+use crate::action::Action;
+use crate::command::Commandable;
+use crate::ecs::entity::PlayerId;
+use crate::ecs::resource::time_scale::MAX_SPEED;
+use crate::ecs::system::command_processor::Data as CommandProcessorData;
+use anyhow::Error;
+
+/// The `Speed` command.
+///
+/// This sets how quickly time passes, e.g. `speed 2x` or `speed 0.5`, or
+/// switches to turn-based mode with `speed turns`.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Speed {
+  pub player_id: PlayerId,
+  pub string: String,
+  pub original_input: String,
+}
+
+impl Commandable for Speed {
+  fn get_action(&self, data: &mut CommandProcessorData) -> Result<Option<Action>, Error> {
+    if data.multiplayer_resource.0 {
+      write_output_event!(
+        data,
+        self.player_id,
+        "You can't change the speed of time while others are playing."
+      );
+      return Ok(None);
+    }
+    let argument = self.string.trim().trim_end_matches(['x', 'X']).trim();
+    if argument == "turns" || argument == "turn" {
+      data.time_scale_resource.turn_based = true;
+      write_output_event!(data, "Time now passes only when you act.");
+      return Ok(None);
+    }
+    match argument.parse::<f64>() {
+      Ok(speed) if speed > 0.0 && speed <= MAX_SPEED => {
+        data.time_scale_resource.speed = speed;
+        data.time_scale_resource.turn_based = false;
+        write_output_event!(data, format!("Time now passes at {}x.", speed));
+      },
+      Ok(speed) if speed > MAX_SPEED => {
+        write_output_event!(data, format!("Time can pass at most {}x.", MAX_SPEED))
+      },
+      _ => write_output_event!(data, format!("\"{}\" is not a speed I understand.", self.string.trim())),
+    }
+    Ok(None)
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::ecs::resource::TimeScaleResource;
+  use crate::game::Headless;
+  use crate::test::*;
+  use specs::prelude::*;
+
+  #[test]
+  pub fn test_speed_limits() {
+    init();
+    let mut headless = compass_rose_headless();
+    let get_speed = |headless: &Headless| headless.game.ecs.read_resource::<TimeScaleResource>().speed;
+    headless.input("speed 1000000");
+    assert_response_contains(&headless, "Time can pass at most 100x.");
+    assert_eq!(get_speed(&headless), 1.0);
+    headless.input("speed 100x");
+    assert_response_contains(&headless, "Time now passes at 100x.");
+    assert_eq!(get_speed(&headless), 100.0);
+    headless.input("speed 0");
+    assert_response_contains(&headless, "\"0\" is not a speed I understand.");
+    assert_eq!(get_speed(&headless), 100.0);
+  }
+}
@@ -0,0 +1,25 @@
+use crate::action::{Action, StopAction};
+use crate::command::Commandable;
+use crate::ecs::entity::PlayerId;
+use crate::ecs::system::command_processor::Data as CommandProcessorData;
+use anyhow::Error;
+
+/// The `Stop` command.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Stop {
+  pub player_id: PlayerId,
+  pub original_input: String,
+}
+
+impl Commandable for Stop {
+  fn get_action(&self, _data: &mut CommandProcessorData) -> Result<Option<Action>, Error> {
+    Ok(Some(create_action!(StopAction {
+      entity_id: self.player_id.into(),
+    })))
+  }
+
+  /// There'd be no point in waiting until the player was done.
+  fn is_urgent(&self) -> bool {
+    true
+  }
+}
@@ -0,0 +1,60 @@
+use crate::action::Action;
+use crate::command::Commandable;
+use crate::ecs::entity::PlayerId;
+use crate::ecs::resource::time_of_day::{HOURS_PER_DAY, TICKS_PER_HOUR};
+use crate::ecs::resource::TimeOfDayResource;
+use crate::ecs::system::command_processor::Data as CommandProcessorData;
+use anyhow::Error;
+
+/// The `Time` command.
+///
+/// This tells the time in the world, along with the tick it's reckoned from.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Time {
+  pub player_id: PlayerId,
+  pub original_input: String,
+}
+
+impl Time {
+  /// Describe the time at a tick, e.g. "It's 13:30 on day 2, during the day."
+  pub fn describe(tick: u64) -> String {
+    let day = tick / (TICKS_PER_HOUR * HOURS_PER_DAY) + 1;
+    let hour = TimeOfDayResource::get_hour(tick);
+    let minute = (tick % TICKS_PER_HOUR) * 60 / TICKS_PER_HOUR;
+    let time_of_day = match TimeOfDayResource::from_tick(tick) {
+      TimeOfDayResource::Night => "at night",
+      TimeOfDayResource::Dawn => "at dawn",
+      TimeOfDayResource::Day => "during the day",
+      TimeOfDayResource::Dusk => "at dusk",
+    };
+    format!(
+      "It's {:02}:{:02} on day {}, {}. (Tick {}.)",
+      hour, minute, day, time_of_day, tick
+    )
+  }
+}
+
+impl Commandable for Time {
+  fn get_action(&self, data: &mut CommandProcessorData) -> Result<Option<Action>, Error> {
+    write_output_event!(data, self.player_id, Self::describe(data.tick_resource.0));
+    Ok(None)
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_describe() {
+    init();
+    assert_eq!(Time::describe(0), "It's 00:00 on day 1, at night. (Tick 0.)");
+    let tick = (HOURS_PER_DAY + 13) * TICKS_PER_HOUR + TICKS_PER_HOUR / 2;
+    assert_eq!(
+      Time::describe(tick),
+      "It's 13:30 on day 2, during the day. (Tick 37500.)"
+    );
+  }
+}
@@ -0,0 +1,26 @@
+use crate::action::Action;
+use crate::command::Commandable;
+use crate::ecs::entity::PlayerId;
+use crate::ecs::event::MetaEvent;
+use crate::ecs::system::command_processor::Data as CommandProcessorData;
+use anyhow::Error;
+
+/// The `Undo` command.
+///
+/// This reverts the player's most recent turn.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Undo {
+  pub player_id: PlayerId,
+  pub original_input: String,
+}
+
+impl Commandable for Undo {
+  fn get_action(&self, data: &mut CommandProcessorData) -> Result<Option<Action>, Error> {
+    if data.multiplayer_resource.0 {
+      write_output_event!(data, self.player_id, "You can't undo while others are playing.");
+      return Ok(None);
+    }
+    write_meta_event!(data, MetaEvent::Undo);
+    Ok(None)
+  }
+}
@@ -0,0 +1,68 @@
+use crate::action::Action;
+use crate::command::Commandable;
+use crate::ecs::entity::PlayerId;
+use crate::ecs::resource::time_scale::MAX_PENDING_TICKS;
+use crate::ecs::system::command_processor::Data as CommandProcessorData;
+use anyhow::Error;
+
+/// The `Wait` command.
+///
+/// This lets a number of ticks pass right away, whether or not the clock is
+/// running, e.g. `wait 50`, up to a day's worth at a time.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Wait {
+  pub player_id: PlayerId,
+  pub string: String,
+  pub original_input: String,
+}
+
+impl Commandable for Wait {
+  fn get_action(&self, data: &mut CommandProcessorData) -> Result<Option<Action>, Error> {
+    if data.multiplayer_resource.0 {
+      write_output_event!(data, self.player_id, "You can't wait while others are playing.");
+      return Ok(None);
+    }
+    match self.string.trim().parse::<u64>() {
+      Ok(ticks) if ticks <= MAX_PENDING_TICKS => {
+        let pending_ticks = &mut data.time_scale_resource.pending_ticks;
+        *pending_ticks = pending_ticks.saturating_add(ticks).min(MAX_PENDING_TICKS);
+        write_output_event!(data, "Time passes...");
+      },
+      Ok(_) => write_output_event!(
+        data,
+        format!("You can wait at most {} ticks at a time.", MAX_PENDING_TICKS)
+      ),
+      Err(_) => write_output_event!(data, format!("\"{}\" is not a number of ticks.", self.string.trim())),
+    }
+    Ok(None)
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::ecs::resource::TimeScaleResource;
+  use crate::test::*;
+  use specs::prelude::*;
+
+  #[test]
+  pub fn test_wait_limits() {
+    init();
+    let mut headless = compass_rose_headless();
+    headless.input("wait 100000");
+    assert_response_contains(&headless, "You can wait at most 24000 ticks at a time.");
+    headless.input("wait 18446744073709551615");
+    assert_response_contains(&headless, "the number 18446744073709551615 is too large");
+    let before = headless.game.tick;
+    headless.input(&format!("wait {}", MAX_PENDING_TICKS));
+    assert_response_contains(&headless, "Time passes...");
+    assert!(headless.game.tick >= before + MAX_PENDING_TICKS);
+    // Waiting again before the last wait has run doesn't pile up past the limit.
+    headless.game.ecs.write_resource::<TimeScaleResource>().pending_ticks = MAX_PENDING_TICKS;
+    headless.game.write_input("wait 10");
+    headless.advance(headless.ticks_per_input);
+    let pending_ticks = headless.game.ecs.read_resource::<TimeScaleResource>().pending_ticks;
+    assert_eq!(pending_ticks, MAX_PENDING_TICKS);
+  }
+}
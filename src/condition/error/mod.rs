@@ -0,0 +1,27 @@
+use crate::ailment::Ailment;
+
+/// Why a condition doesn't hold of an actor.
+///
+/// These are reported as the reason an action failed, so they're lowercase and
+/// unpunctuated, like the other action errors.
+#[derive(Clone, Copy, Debug, Eq, Error, Hash, PartialEq)]
+pub enum Error {
+  /// The actor suffers from an ailment that gets in the way.
+  #[error("{}", .0.describe())]
+  Ailing(Ailment),
+  /// The actor would need to suffer from an ailment, and doesn't.
+  #[error("you can only do that if {}", .0.describe())]
+  NotAiling(Ailment),
+  /// The actor is in midair, and shouldn't be.
+  #[error("you are in midair")]
+  InMidair,
+  /// The actor would need to be in midair.
+  #[error("you are not in midair")]
+  NotInMidair,
+  /// The actor is underwater, and shouldn't be.
+  #[error("you are underwater")]
+  Underwater,
+  /// The actor would need to be underwater.
+  #[error("you are not underwater")]
+  NotUnderwater,
+}
@@ -1,5 +1,8 @@
 use crate::ailment::Ailment;
 
+pub mod error;
+pub use error::Error as ConditionError;
+
 /// The `Condition` enum.
 ///
 /// This is intended to represent the various conditions that can be in play.
@@ -8,5 +11,76 @@ pub enum Condition {
   HasAilment(Ailment),
   DoesNotHaveAilment(Ailment),
   InMidair,
+  NotInMidair,
   Underwater,
+  NotUnderwater,
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::ecs::component::HasAilments;
+  use crate::test::*;
+  use specs::prelude::*;
+
+  #[test]
+  pub fn test_errors() {
+    init();
+    assert_eq!(
+      format!("{}", ConditionError::Ailing(Ailment::LegsBroken)),
+      "your legs are broken"
+    );
+    assert_eq!(
+      format!("{}", ConditionError::NotAiling(Ailment::Asleep)),
+      "you can only do that if you are asleep"
+    );
+  }
+
+  #[test]
+  pub fn test_ailments() {
+    init();
+    let mut headless = compass_rose_headless();
+    set_player_ailments(&mut headless, &[Ailment::LegsBroken]);
+    headless.input("north");
+    assert_response_contains(&headless, "Your legs are broken.");
+    headless.input("look north");
+    assert_response_contains(&headless, "North Room");
+    set_player_ailments(&mut headless, &[Ailment::Paralyzed]);
+    headless.input("north");
+    assert_response_contains(&headless, "You are paralyzed.");
+    headless.input("look north");
+    assert_response_contains(&headless, "North Room");
+    set_player_ailments(&mut headless, &[Ailment::Blind]);
+    headless.input("look north");
+    assert_response_contains(&headless, "You are blind.");
+    assert_response_lacks(&headless, "North Room");
+    headless.input("north");
+    assert_response_contains(&headless, "North Room");
+    set_player_ailments(&mut headless, &[]);
+    headless.input("south");
+    assert_response_contains(&headless, "Spawn Room");
+  }
+
+  #[test]
+  pub fn test_midair_and_underwater() {
+    init();
+    let headless = compass_rose_headless();
+    let player = get_player(&headless);
+    struct Data<'a> {
+      has_ailments: ReadStorage<'a, HasAilments>,
+    }
+    let data = Data {
+      has_ailments: headless.game.ecs.read_storage::<HasAilments>(),
+    };
+    // Nothing can fly or swim yet.
+    assert!(!is_condition_met!(data, player, Condition::InMidair));
+    assert!(is_condition_met!(data, player, Condition::NotInMidair));
+    assert!(!is_condition_met!(data, player, Condition::Underwater));
+    assert!(is_condition_met!(data, player, Condition::NotUnderwater));
+    assert_eq!(
+      format!("{}", check_condition!(data, player, Condition::InMidair).unwrap_err()),
+      "you are not in midair"
+    );
+  }
 }
@@ -0,0 +1,8 @@
+/// The number of things an actor can carry at once.
+pub const CARRYING_CAPACITY_COUNT: usize = 10;
+
+/// The total weight an actor can carry at once.
+pub const CARRYING_CAPACITY_WEIGHT: usize = 50;
+
+/// The weight of an object that no one has weighed.
+pub const DEFAULT_WEIGHT: usize = 1;
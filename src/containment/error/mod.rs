@@ -0,0 +1,19 @@
+/// Errors encountered in putting one thing in, on, or in the hands of another.
+///
+/// These are reported as the reason an action failed, so they're lowercase and
+/// unpunctuated, like the other action errors.
+#[derive(Clone, Copy, Debug, Eq, Error, Hash, PartialEq)]
+pub enum Error {
+  /// Something can't contain itself, or anything containing it.
+  #[error("that would be a neat trick")]
+  ContainsItself,
+  /// Something can't be held in a way its container doesn't allow.
+  #[error("that is not possible")]
+  NotPossible,
+  /// The container already holds as many things as it can.
+  #[error("there is no more room for that")]
+  NoRoom,
+  /// The container can't bear any more weight.
+  #[error("that is too heavy")]
+  TooHeavy,
+}
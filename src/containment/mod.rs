@@ -0,0 +1,146 @@
+pub mod _constant;
+pub use _constant::*;
+pub mod error;
+pub use error::Error as ContainmentError;
+
+use crate::input::Preposition;
+
+/// The `Containment` enum.
+///
+/// The ways in which one entity can hold another: carried by an actor, inside
+/// a container, or on top of a surface.  An entity that's held in any of these
+/// ways isn't in a room itself; it's wherever its container is.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum Containment {
+  CarriedBy,
+  In,
+  On,
+}
+
+impl Containment {
+  /// Describe the relation, e.g. "in" for "in the basket".
+  pub fn get_lowercase(&self) -> &'static str {
+    use Containment::*;
+    match self {
+      CarriedBy => "carried by",
+      In => "in",
+      On => "on",
+    }
+  }
+
+  /// The containment meant by a preposition, e.g. "in" for `Containment::In`.
+  pub fn from_preposition(preposition: Preposition) -> Option<Self> {
+    match preposition {
+      Preposition::In => Some(Containment::In),
+      Preposition::On => Some(Containment::On),
+      _ => None,
+    }
+  }
+
+  /// Describe what's held this way, e.g. "On the table are the cup and the
+  /// saucer."
+  ///
+  /// The container's name is used as it is for an actor, and as a common noun
+  /// for anything else.
+  pub fn describe_contents(&self, container_name: &str, content_names: &[String]) -> String {
+    use Containment::*;
+    let contents = join_names(content_names);
+    let verb = if content_names.len() == 1 { "is" } else { "are" };
+    match self {
+      CarriedBy => format!("{} is carrying {}.", container_name, contents),
+      In => format!("In the {} {} {}.", container_name, verb, contents),
+      On => format!("On the {} {} {}.", container_name, verb, contents),
+    }
+  }
+}
+
+/// Join names into a phrase, e.g. "the cup, the saucer, and the spoon".
+pub fn join_names(names: &[String]) -> String {
+  let names = names
+    .iter()
+    .map(|name| format!("the {}", name))
+    .collect::<Vec<String>>();
+  match names.as_slice() {
+    [] => String::new(),
+    [only] => only.clone(),
+    [first, second] => format!("{} and {}", first, second),
+    [rest @ .., last] => format!("{}, and {}", rest.join(", "), last),
+  }
+}
+
+impl std::fmt::Display for Containment {
+  fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(formatter, "{}", self.get_lowercase())
+  }
+}
+
+/// The `Capacity` type.
+///
+/// How much an entity can hold, and how.  A basket holds things in it, a table
+/// holds things on it, and an actor carries things.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Capacity {
+  /// The way in which things are held.
+  pub containment: Containment,
+  /// The greatest number of things that can be held at once.
+  pub maximum_count: usize,
+  /// The greatest total weight that can be held at once.
+  pub maximum_weight: usize,
+}
+
+impl Capacity {
+  /// Constructor.
+  pub fn new(containment: Containment, maximum_count: usize, maximum_weight: usize) -> Self {
+    Self {
+      containment,
+      maximum_count,
+      maximum_weight,
+    }
+  }
+
+  /// Check whether something of the given weight can be added to what's held.
+  pub fn check(&self, count: usize, weight: usize, added_weight: usize) -> Result<(), ContainmentError> {
+    if count >= self.maximum_count {
+      return Err(ContainmentError::NoRoom);
+    }
+    if weight + added_weight > self.maximum_weight {
+      return Err(ContainmentError::TooHeavy);
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_check() {
+    init();
+    let capacity = Capacity::new(Containment::In, 2, 10);
+    assert_eq!(capacity.check(0, 0, 10), Ok(()));
+    assert_eq!(capacity.check(1, 4, 6), Ok(()));
+    assert_eq!(capacity.check(1, 4, 7), Err(ContainmentError::TooHeavy));
+    assert_eq!(capacity.check(2, 0, 0), Err(ContainmentError::NoRoom));
+  }
+
+  #[test]
+  pub fn test_describe_contents() {
+    init();
+    let names = |names: &[&str]| names.iter().map(|name| name.to_string()).collect::<Vec<String>>();
+    assert_eq!(
+      Containment::On.describe_contents("table", &names(&["cup"])),
+      "On the table is the cup."
+    );
+    assert_eq!(
+      Containment::In.describe_contents("basket", &names(&["cup", "saucer", "spoon"])),
+      "In the basket are the cup, the saucer, and the spoon."
+    );
+    assert_eq!(
+      Containment::CarriedBy.describe_contents("Goblin", &names(&["cup", "saucer"])),
+      "Goblin is carrying the cup and the saucer."
+    );
+  }
+}
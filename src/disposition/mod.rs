@@ -0,0 +1,19 @@
+/// The `Disposition` enum.
+///
+/// How an actor is inclined to treat others, and so whether it will do what
+/// it's told.  This is deliberately coarse; one day it should be a relation
+/// between two particular actors rather than an attitude toward the world.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Display, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum Disposition {
+  /// Wishes others harm.
+  Hostile,
+  /// Would rather others went away.
+  Unfriendly,
+  /// Has no particular feelings one way or the other.
+  #[default]
+  Indifferent,
+  /// Well-disposed, and willing to do a favor.
+  Friendly,
+  /// Eager to help, and will do as it's asked.
+  Helpful,
+}
@@ -14,19 +14,29 @@ use crate::ecs::resource::*;
 #[derive(SystemData)]
 pub struct AllData<'data> {
   pub entities: Entities<'data>,
-  pub camera_resource: Read<'data, CameraResource>,
   pub player_resource: Read<'data, PlayerResource>,
-  pub random_resource: Read<'data, RandomResource>,
+  pub random_resource: Write<'data, RandomResource>,
   pub tile_map_resource: Write<'data, TileMapResource>,
   pub action_event_channel: Write<'data, EventChannel<ActionEvent>>,
   pub effect_event_channel: Write<'data, EventChannel<EffectEvent>>,
+  pub order_event_channel: Write<'data, EventChannel<OrderEvent>>,
   pub output_event_channel: Write<'data, EventChannel<OutputEvent>>,
+  pub perception_event_channel: Write<'data, EventChannel<PerceptionEvent>>,
+  pub has_action_in_progress: WriteStorage<'data, HasActionInProgress>,
+  pub has_ai: ReadStorage<'data, HasAi>,
+  pub has_ailments: WriteStorage<'data, HasAilments>,
   pub has_brief_description: ReadStorage<'data, HasBriefDescription>,
+  pub has_camera: ReadStorage<'data, HasCamera>,
+  pub has_capacity: ReadStorage<'data, HasCapacity>,
+  pub has_command_queue: WriteStorage<'data, HasCommandQueue>,
   pub has_gender: ReadStorage<'data, HasGender>,
   pub has_name: ReadStorage<'data, HasName>,
   pub has_passages: ReadStorage<'data, HasPassages>,
+  pub has_weight: ReadStorage<'data, HasWeight>,
+  pub is_a_door: WriteStorage<'data, IsADoor>,
   pub is_a_player: ReadStorage<'data, IsAPlayer>,
   pub is_an_actor: ReadStorage<'data, IsAnActor>,
   pub is_an_object: ReadStorage<'data, IsAnObject>,
+  pub is_contained_by: WriteStorage<'data, IsContainedBy>,
   pub is_in_room: WriteStorage<'data, IsInRoom>,
 }
@@ -0,0 +1,8 @@
+use crate::action::ActionInProgress;
+use specs::prelude::*;
+
+/// The `HasActionInProgress` component.
+///
+/// An entity with one is busy until the action ends or is interrupted.
+#[derive(Clone, Component, Debug)]
+pub struct HasActionInProgress(pub ActionInProgress);
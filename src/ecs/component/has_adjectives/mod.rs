@@ -0,0 +1,9 @@
+use specs::prelude::*;
+
+/// The `HasAdjectives` component.
+///
+/// The adjectives a player can use to tell an entity apart from others that
+/// share its nouns, in lowercase.
+#[derive(Clone, Component, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[repr(transparent)]
+pub struct HasAdjectives(pub Vec<String>);
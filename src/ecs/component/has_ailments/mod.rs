@@ -0,0 +1,11 @@
+use specs::prelude::*;
+use std::collections::BTreeSet;
+
+use crate::ailment::Ailment;
+
+/// The `HasAilments` component.
+///
+/// Whatever is wrong with an actor at the moment, e.g. a broken leg.
+#[derive(Clone, Component, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[repr(transparent)]
+pub struct HasAilments(pub BTreeSet<Ailment>);
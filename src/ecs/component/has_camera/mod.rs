@@ -0,0 +1,10 @@
+use specs::prelude::*;
+
+/// The `HasCamera` component.
+///
+/// This marks an entity whose experiences are presented to someone, e.g. the
+/// player at the terminal or a player connected over the network.  It drives
+/// output and the first- and second-person behaviors.
+#[derive(Clone, Component, Debug, Default, Deserialize, Display, Eq, Hash, PartialEq, Serialize)]
+#[storage(NullStorage)]
+pub struct HasCamera;
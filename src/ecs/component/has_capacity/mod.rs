@@ -0,0 +1,10 @@
+use specs::prelude::*;
+
+use crate::containment::Capacity;
+
+/// The `HasCapacity` component.
+///
+/// Entities without one can't hold anything.
+#[derive(Clone, Component, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[repr(transparent)]
+pub struct HasCapacity(pub Capacity);
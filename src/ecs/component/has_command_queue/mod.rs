@@ -0,0 +1,16 @@
+use specs::prelude::*;
+use std::collections::VecDeque;
+
+use crate::command::Command;
+
+/// The `HasCommandQueue` component.
+///
+/// The commands a player has given that haven't been carried out yet, e.g.
+/// "look" after "n then look".  They're released one at a time, as the
+/// player's initiative allows.
+///
+/// Like an intent, this isn't saved; it only lasts as long as the player's
+/// attention.
+#[derive(Clone, Component, Debug, Default)]
+#[repr(transparent)]
+pub struct HasCommandQueue(pub VecDeque<Command>);
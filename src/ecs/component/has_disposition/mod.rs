@@ -0,0 +1,9 @@
+use crate::disposition::Disposition;
+use specs::prelude::*;
+
+/// The `HasDisposition` component.
+///
+/// This determines how an actor's AI responds to being told what to do.
+#[derive(Clone, Component, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[repr(transparent)]
+pub struct HasDisposition(pub Disposition);
@@ -0,0 +1,11 @@
+use crate::need::Need;
+use specs::prelude::*;
+use std::collections::BTreeMap;
+
+/// The `HasNeeds` component.
+///
+/// This tracks how pressing each of an actor's needs is, from zero (fully
+/// satisfied) up to `MAX_NEED_LEVEL`.  Needs grow slowly over time.
+#[derive(Clone, Component, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[repr(transparent)]
+pub struct HasNeeds(pub BTreeMap<Need, u8>);
@@ -0,0 +1,9 @@
+use specs::prelude::*;
+
+/// The `HasNouns` component.
+///
+/// The nouns a player can use to refer to an entity, in lowercase.  A noun
+/// can span several words, e.g. "ice cream".
+#[derive(Clone, Component, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[repr(transparent)]
+pub struct HasNouns(pub Vec<String>);
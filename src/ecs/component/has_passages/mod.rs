@@ -3,6 +3,7 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use crate::map::Direction;
 use crate::map::Passage;
+use crate::output::Block;
 
 /// The `HasPassages` component.
 #[derive(Clone, Component, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
@@ -94,29 +95,27 @@ impl HasPassages {
       .map(|exit| exit.direction)
       .collect::<Vec<Direction>>()
   }
+
+  /// The passages that anyone can see, i.e. that aren't hidden.
+  pub fn get_visible_passages(&self) -> Vec<&Passage> {
+    self
+      .get_passages()
+      .into_iter()
+      .filter(|passage| !passage.properties.is_hidden)
+      .collect()
+  }
+
+  pub fn get_visible_directions(&self) -> Vec<Direction> {
+    self
+      .get_visible_passages()
+      .iter()
+      .map(|exit| exit.direction)
+      .collect::<Vec<Direction>>()
+  }
 }
 
 impl Display for HasPassages {
   fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
-    let mut directions = self.get_directions();
-    let string = match directions.len() {
-      0 => "There are no visible passages.".into(),
-      1 => format!("There is a visible passage to the {}.", directions.pop().unwrap()),
-      2 => format!(
-        "There are visible passages to the {} and {}.",
-        directions.pop().unwrap(),
-        directions.pop().unwrap()
-      ),
-      _ => {
-        let last = directions.pop().unwrap();
-        let others = directions
-          .iter()
-          .map(|d| d.get_lowercase())
-          .collect::<Vec<&'static str>>()
-          .join(", ");
-        format!("There are visible passages to the {}, and {}.", others, last)
-      },
-    };
-    write!(formatter, "{}", string)
+    write!(formatter, "{}", Block::describe_exits(&self.get_visible_directions()))
   }
 }
@@ -0,0 +1,10 @@
+use specs::prelude::*;
+
+use crate::output::Verbosity;
+
+/// The `HasVerbosity` component.
+///
+/// Entities without one are told everything, i.e. `Verbosity::Verbose`.
+#[derive(Clone, Component, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[repr(transparent)]
+pub struct HasVerbosity(pub Verbosity);
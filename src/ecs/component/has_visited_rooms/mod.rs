@@ -0,0 +1,12 @@
+use specs::prelude::*;
+use std::collections::BTreeSet;
+
+use crate::ecs::entity::RoomId;
+
+/// The `HasVisitedRooms` component.
+///
+/// The rooms an entity has been in and looked around, so that players with
+/// brief descriptions aren't told about the same room over and over.
+#[derive(Clone, Component, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[repr(transparent)]
+pub struct HasVisitedRooms(pub BTreeSet<RoomId>);
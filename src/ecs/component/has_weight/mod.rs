@@ -0,0 +1,8 @@
+use specs::prelude::*;
+
+/// The `HasWeight` component.
+///
+/// Entities without one weigh `DEFAULT_WEIGHT`.
+#[derive(Clone, Component, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[repr(transparent)]
+pub struct HasWeight(pub usize);
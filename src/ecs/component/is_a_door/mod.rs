@@ -0,0 +1,10 @@
+use specs::prelude::*;
+
+use crate::map::Door;
+
+/// The `IsADoor` component.
+///
+/// The entity stands across a passage, or rather two: one on either side.
+#[derive(Clone, Component, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[repr(transparent)]
+pub struct IsADoor(pub Door);
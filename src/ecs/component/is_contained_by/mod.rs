@@ -0,0 +1,10 @@
+use specs::prelude::*;
+
+use crate::containment::Containment;
+use crate::ecs::entity::EntityId;
+
+/// The `IsContainedBy` component.
+///
+/// How, and by what, the entity is held; e.g. `(Containment::In, basket)`.
+#[derive(Clone, Component, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct IsContainedBy(pub Containment, pub EntityId);
@@ -0,0 +1,9 @@
+use specs::prelude::*;
+
+/// The `IsDead` component.
+///
+/// Dead entities (other than the player) are eventually removed from the
+/// world altogether.
+#[derive(Clone, Component, Debug, Default, Deserialize, Display, Eq, Hash, PartialEq, Serialize)]
+#[storage(NullStorage)]
+pub struct IsDead;
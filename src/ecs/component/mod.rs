@@ -1,9 +1,23 @@
 use specs::prelude::*;
 
+pub mod has_action_in_progress;
+pub use has_action_in_progress::HasActionInProgress;
+pub mod has_adjectives;
+pub use has_adjectives::HasAdjectives;
 pub mod has_ai;
 pub use has_ai::HasAi;
+pub mod has_ailments;
+pub use has_ailments::HasAilments;
 pub mod has_brief_description;
 pub use has_brief_description::HasBriefDescription;
+pub mod has_camera;
+pub use has_camera::HasCamera;
+pub mod has_capacity;
+pub use has_capacity::HasCapacity;
+pub mod has_command_queue;
+pub use has_command_queue::HasCommandQueue;
+pub mod has_disposition;
+pub use has_disposition::HasDisposition;
 pub mod has_gender;
 pub use has_gender::HasGender;
 pub mod has_initiative;
@@ -12,10 +26,22 @@ pub mod has_intent;
 pub use has_intent::HasIntent;
 pub mod has_name;
 pub use has_name::HasName;
+pub mod has_needs;
+pub use has_needs::HasNeeds;
+pub mod has_nouns;
+pub use has_nouns::HasNouns;
 pub mod has_passages;
 pub use has_passages::HasPassages;
 pub mod has_state;
 pub use has_state::HasState;
+pub mod has_verbosity;
+pub use has_verbosity::HasVerbosity;
+pub mod has_visited_rooms;
+pub use has_visited_rooms::HasVisitedRooms;
+pub mod has_weight;
+pub use has_weight::HasWeight;
+pub mod is_a_door;
+pub use is_a_door::IsADoor;
 pub mod is_a_player;
 pub use is_a_player::IsAPlayer;
 pub mod is_a_room;
@@ -26,22 +52,41 @@ pub mod is_an_actor;
 pub use is_an_actor::IsAnActor;
 pub mod is_an_object;
 pub use is_an_object::IsAnObject;
+pub mod is_contained_by;
+pub use is_contained_by::IsContainedBy;
+pub mod is_dead;
+pub use is_dead::IsDead;
 pub mod is_in_room;
 pub use is_in_room::IsInRoom;
 
 pub fn register_components(ecs: &mut World) {
+  ecs.register::<HasActionInProgress>();
+  ecs.register::<HasAdjectives>();
   ecs.register::<HasAi>();
+  ecs.register::<HasAilments>();
   ecs.register::<HasBriefDescription>();
+  ecs.register::<HasCamera>();
+  ecs.register::<HasCapacity>();
+  ecs.register::<HasCommandQueue>();
+  ecs.register::<HasDisposition>();
   ecs.register::<HasGender>();
   ecs.register::<HasInitiative>();
   ecs.register::<HasIntent>();
   ecs.register::<HasName>();
+  ecs.register::<HasNeeds>();
+  ecs.register::<HasNouns>();
   ecs.register::<HasPassages>();
   ecs.register::<HasState>();
+  ecs.register::<HasVerbosity>();
+  ecs.register::<HasVisitedRooms>();
+  ecs.register::<HasWeight>();
   ecs.register::<IsAnActor>();
+  ecs.register::<IsADoor>();
   ecs.register::<IsAPlayer>();
   ecs.register::<IsARoom>();
   ecs.register::<IsASpawnRoom>();
   ecs.register::<IsAnObject>();
+  ecs.register::<IsContainedBy>();
+  ecs.register::<IsDead>();
   ecs.register::<IsInRoom>();
 }
@@ -1,9 +1,11 @@
 use crate::command::Command as CommandObject;
+use crate::ecs::entity::PlayerId;
 
 /// The `CommandEvent` type.
 ///
 /// This represents a command executed by a player.
 #[derive(Clone, Debug)]
 pub struct Command {
+  pub player_id: PlayerId,
   pub command: CommandObject,
 }
@@ -1,7 +1,13 @@
+use crate::ecs::entity::PlayerId;
+
 /// The `InputEvent` type.
 ///
 /// This represents a piece of textual input received from a player.
+///
+/// Input without a player comes from the local player, i.e. whoever is at the
+/// terminal.
 #[derive(Clone, Debug)]
 pub struct Input {
+  pub player_id: Option<PlayerId>,
   pub input: String,
 }
@@ -0,0 +1,18 @@
+use crate::ecs::entity::PlayerId;
+
+/// The `Meta` event.
+///
+/// This represents a request to do something to the game itself rather than
+/// within the game world.  These are handled by the `Game` between ticks,
+/// where the whole world is available.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum Meta {
+  /// Save the world to the specified file.
+  Save(String),
+  /// Restore the world from the specified file.
+  Restore(String),
+  /// Undo the player's most recent turn.
+  Undo,
+  /// Leave the game; in multiplayer, only the specified player leaves.
+  Quit(PlayerId),
+}
@@ -9,8 +9,14 @@ pub mod effect;
 pub use effect::Effect as EffectEvent;
 pub mod input;
 pub use input::Input as InputEvent;
+pub mod meta;
+pub use meta::Meta as MetaEvent;
+pub mod order;
+pub use order::Order as OrderEvent;
 pub mod output;
 pub use output::Output as OutputEvent;
+pub mod perception;
+pub use perception::Perception as PerceptionEvent;
 pub mod script;
 pub use script::Script as ScriptEvent;
 
@@ -19,6 +25,9 @@ pub fn insert_event_channels(ecs: &mut World) {
   ecs.insert(EventChannel::<CommandEvent>::new());
   ecs.insert(EventChannel::<EffectEvent>::new());
   ecs.insert(EventChannel::<InputEvent>::new());
+  ecs.insert(EventChannel::<MetaEvent>::new());
+  ecs.insert(EventChannel::<OrderEvent>::new());
   ecs.insert(EventChannel::<OutputEvent>::new());
+  ecs.insert(EventChannel::<PerceptionEvent>::new());
   ecs.insert(EventChannel::<ScriptEvent>::new());
 }
@@ -0,0 +1,14 @@
+use crate::action::Action as ActionObject;
+use crate::ecs::entity::EntityId;
+
+/// The `OrderEvent` type.
+///
+/// This represents one actor telling another to do something.  The action is
+/// already in the addressee's name; whether it actually happens is up to the
+/// addressee's AI.
+#[derive(Clone, Debug)]
+pub struct Order {
+  pub giver_id: EntityId,
+  pub addressee_id: EntityId,
+  pub action: ActionObject,
+}
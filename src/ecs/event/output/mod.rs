@@ -1,7 +1,14 @@
+use crate::ecs::entity::EntityId;
+use crate::output::Document;
+
 /// The `Output` type.
 ///
-/// This represents a piece of textual output to present to the player.
+/// This represents a piece of output to present to a player.
+///
+/// Output with a recipient is only presented to that entity's camera; output
+/// without one is presented to everyone, e.g. notices about the game itself.
 #[derive(Clone, Debug)]
 pub struct Output {
-  pub string: String,
+  pub recipient: Option<EntityId>,
+  pub document: Document,
 }
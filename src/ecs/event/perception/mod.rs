@@ -0,0 +1,10 @@
+use crate::perception::Perception as PerceptionObject;
+
+/// The `PerceptionEvent` type.
+///
+/// This represents an entity perceiving something, e.g. so that its AI can
+/// react to it.
+#[derive(Clone, Debug)]
+pub struct Perception {
+  pub perception: PerceptionObject,
+}
@@ -4,4 +4,6 @@ pub mod component;
 pub mod entity;
 pub mod event;
 pub mod resource;
+pub mod snapshot;
+pub use snapshot::Snapshot;
 pub mod system;
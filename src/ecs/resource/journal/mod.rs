@@ -0,0 +1,8 @@
+use crate::journal::Journal as JournalObject;
+
+/// The `Journal` resource.
+///
+/// This records every effect processed, for undo and replay.
+#[derive(Clone, Debug, Default)]
+#[repr(transparent)]
+pub struct Journal(pub JournalObject);
@@ -0,0 +1,8 @@
+use crate::map::MapBuilderKind;
+
+/// The `MapBuilder` resource.
+///
+/// This selects the builder used to create the map when the game starts.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[repr(transparent)]
+pub struct MapBuilder(pub MapBuilderKind);
@@ -1,11 +1,14 @@
-use rand_seeder::SipHasher;
 use rustyline_async::Readline;
 use specs::prelude::*;
 
-pub mod camera;
-pub use camera::Camera as CameraResource;
 pub mod input;
 pub use input::Input as InputResource;
+pub mod journal;
+pub use journal::Journal as JournalResource;
+pub mod map_builder;
+pub use map_builder::MapBuilder as MapBuilderResource;
+pub mod multiplayer;
+pub use multiplayer::Multiplayer as MultiplayerResource;
 pub mod output;
 pub use output::Output as OutputResource;
 pub mod player;
@@ -18,16 +21,40 @@ pub mod tick;
 pub use tick::Tick as TickResource;
 pub mod tile_map;
 pub use tile_map::TileMap as TileMapResource;
+pub mod time_of_day;
+pub use time_of_day::TimeOfDay as TimeOfDayResource;
+pub mod time_scale;
+pub use time_scale::TimeScale as TimeScaleResource;
+pub mod weather;
+pub use weather::Weather as WeatherResource;
 
 pub fn insert_resources(ecs: &mut World, seed: &str) {
   let (input, stdout) = Readline::new("> ".to_owned()).unwrap();
-  ecs.insert(CameraResource(None));
   ecs.insert(InputResource(Some(input)));
   ecs.insert(OutputResource(Some(stdout)));
+  insert_world_resources(ecs, seed);
+}
+
+/// Insert resources without attaching a terminal.
+///
+/// Input and output are left to the caller, e.g. a test or a batch job.
+pub fn insert_headless_resources(ecs: &mut World, seed: &str) {
+  ecs.insert(InputResource(None));
+  ecs.insert(OutputResource(None));
+  insert_world_resources(ecs, seed);
+}
+
+/// Insert the resources describing the game world itself.
+pub fn insert_world_resources(ecs: &mut World, seed: &str) {
+  ecs.insert(JournalResource::default());
+  ecs.insert(MapBuilderResource::default());
+  ecs.insert(MultiplayerResource(false));
   ecs.insert(PlayerResource(None));
-  let rng = SipHasher::from(seed).into_rng();
-  ecs.insert(RandomResource(rng));
+  ecs.insert(RandomResource::new(seed));
   ecs.insert(SpawnRoomResource(None));
   ecs.insert(TickResource(0));
   ecs.insert(TileMapResource(None));
+  ecs.insert(TimeOfDayResource::default());
+  ecs.insert(TimeScaleResource::default());
+  ecs.insert(WeatherResource::default());
 }
@@ -0,0 +1,8 @@
+/// The `Multiplayer` resource.
+///
+/// Whether the world is shared by several players, e.g. over the server.
+/// Commands that act on the whole game, like saving or changing the speed of
+/// time, aren't available to any one player when this is set.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[repr(transparent)]
+pub struct Multiplayer(pub bool);
@@ -1,14 +1,22 @@
-use rand_seeder::{SipHasher, SipRng};
+pub mod seeded_rng;
+pub use seeded_rng::SeededRng;
 
 /// The `Random` resource.
 ///
 /// This makes a random number generator available to processes that need one.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[repr(transparent)]
-pub struct Random(pub SipRng);
+pub struct Random(pub SeededRng);
+
+impl Random {
+  /// Constructor.
+  pub fn new(seed: &str) -> Self {
+    Self(SeededRng::new(seed))
+  }
+}
 
 impl Default for Random {
   fn default() -> Self {
-    Self(SipHasher::from("goat boy").into_rng())
+    Self::new("goat boy")
   }
 }
@@ -0,0 +1,5 @@
+/// How many ticks make up an hour of game time.
+pub const TICKS_PER_HOUR: u64 = 1_000;
+
+/// How many hours make up a day of game time.
+pub const HOURS_PER_DAY: u64 = 24;
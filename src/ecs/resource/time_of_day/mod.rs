@@ -0,0 +1,50 @@
+pub mod _constant;
+pub use _constant::*;
+
+/// The `TimeOfDay` resource.
+///
+/// This is the broad phase of the day, derived from the tick.  The world
+/// begins at midnight.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Display, Eq, Hash, PartialEq, Serialize)]
+pub enum TimeOfDay {
+  #[default]
+  Night,
+  Dawn,
+  Day,
+  Dusk,
+}
+
+impl TimeOfDay {
+  /// The hour of the day (0-23) at the specified tick.
+  pub fn get_hour(tick: u64) -> u64 {
+    (tick / TICKS_PER_HOUR) % HOURS_PER_DAY
+  }
+
+  /// The time of day at the specified tick.
+  pub fn from_tick(tick: u64) -> Self {
+    use TimeOfDay::*;
+    match Self::get_hour(tick) {
+      5..=6 => Dawn,
+      7..=17 => Day,
+      18..=19 => Dusk,
+      _ => Night,
+    }
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_from_tick() {
+    init();
+    assert_eq!(TimeOfDay::from_tick(0), TimeOfDay::Night);
+    assert_eq!(TimeOfDay::from_tick(5 * TICKS_PER_HOUR), TimeOfDay::Dawn);
+    assert_eq!(TimeOfDay::from_tick(12 * TICKS_PER_HOUR), TimeOfDay::Day);
+    assert_eq!(TimeOfDay::from_tick(19 * TICKS_PER_HOUR), TimeOfDay::Dusk);
+    assert_eq!(TimeOfDay::from_tick(HOURS_PER_DAY * TICKS_PER_HOUR), TimeOfDay::Night);
+  }
+}
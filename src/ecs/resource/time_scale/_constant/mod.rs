@@ -0,0 +1,10 @@
+use crate::ecs::resource::time_of_day::{HOURS_PER_DAY, TICKS_PER_HOUR};
+
+/// The most ticks that can be waiting to run at once, e.g. with `wait`.
+///
+/// This is a day of game time; anything longer would freeze the game while
+/// it caught up.
+pub const MAX_PENDING_TICKS: u64 = TICKS_PER_HOUR * HOURS_PER_DAY;
+
+/// The fastest time can be made to pass, in ticks per firing of the timer.
+pub const MAX_SPEED: f64 = 100.0;
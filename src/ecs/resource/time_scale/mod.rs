@@ -0,0 +1,38 @@
+pub mod _constant;
+pub use _constant::*;
+
+/// The `TimeScale` resource.
+///
+/// This controls how quickly the world advances relative to the wall clock.
+///
+/// The game's timer fires every `TICK_INTERVAL` milliseconds regardless; this
+/// decides how many ticks each firing is worth.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct TimeScale {
+  /// Whether the clock is stopped.
+  pub paused: bool,
+  /// Whether the world only advances in response to the player's input.
+  pub turn_based: bool,
+  /// How many ticks each firing of the timer is worth; may be fractional.
+  pub speed: f64,
+  /// Ticks that should run as soon as possible, whatever the above says.
+  pub pending_ticks: u64,
+}
+
+impl TimeScale {
+  /// Whether the world advances on its own.
+  pub fn is_running(&self) -> bool {
+    !self.paused && !self.turn_based
+  }
+}
+
+impl Default for TimeScale {
+  fn default() -> Self {
+    Self {
+      paused: false,
+      turn_based: false,
+      speed: 1.0,
+      pending_ticks: 0,
+    }
+  }
+}
@@ -0,0 +1,54 @@
+use rand::prelude::*;
+
+/// The `Weather` resource.
+///
+/// This is the weather across the whole world; it drifts a step at a time,
+/// so a clear sky never turns into a storm all at once.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Display, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum Weather {
+  #[default]
+  Clear,
+  Cloudy,
+  Rain,
+  Storm,
+}
+
+impl Weather {
+  /// Every kind of weather, from fairest to foulest.
+  pub fn get_all() -> Vec<Self> {
+    use Weather::*;
+    vec![Clear, Cloudy, Rain, Storm]
+  }
+
+  /// Pick the weather that follows this one.
+  pub fn get_next<R: Rng + ?Sized>(&self, rng: &mut R) -> Self {
+    let all = Self::get_all();
+    let index = all.iter().position(|weather| weather == self).unwrap();
+    match rng.gen_range(0..4) {
+      0 if index > 0 => all[index - 1],
+      1 if index < all.len() - 1 => all[index + 1],
+      _ => *self,
+    }
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::ecs::resource::random::seeded_rng::SeededRng;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_get_next() {
+    init();
+    let mut rng = SeededRng::new("goat boy");
+    let mut weather = Weather::Clear;
+    for _ in 0..100 {
+      let next = weather.get_next(&mut rng);
+      let step = (next as i32 - weather as i32).abs();
+      assert!(step <= 1);
+      weather = next;
+    }
+  }
+}
@@ -0,0 +1,5 @@
+/// The version of the snapshot format.
+///
+/// Bump this whenever a component or resource is added to or removed from a
+/// snapshot, or its serialized form changes.
+pub const SNAPSHOT_VERSION: u32 = 9;
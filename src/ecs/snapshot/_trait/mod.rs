@@ -0,0 +1,2 @@
+pub mod remappable;
+pub use remappable::*;
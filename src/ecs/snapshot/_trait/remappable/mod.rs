@@ -0,0 +1,155 @@
+use std::collections::{BTreeSet, HashMap};
+use std::hash::Hash;
+
+use crate::ecs::component::{HasPassages, HasVisitedRooms, IsADoor, IsContainedBy, IsInRoom};
+use crate::ecs::entity::{EntityId, PlayerId, RoomId};
+use crate::ecs::resource::{PlayerResource, SpawnRoomResource, TileMapResource};
+use crate::ecs::snapshot::error::Error;
+use crate::ecs::snapshot::id_map::IdMap;
+use crate::ecs::snapshot::{EntitySnapshot, Snapshot};
+use crate::map::{Door, Passage, PassageDestination, TileMap};
+
+/// The `Remappable` trait.
+///
+/// Anything that holds entity IDs has to have them translated when a snapshot
+/// is restored.
+pub trait Remappable: Sized {
+  /// Translate any entity IDs held by this value.
+  fn remap(self, ids: &IdMap) -> Result<Self, Error>;
+}
+
+impl<T: Remappable> Remappable for Option<T> {
+  fn remap(self, ids: &IdMap) -> Result<Self, Error> {
+    self.map(|value| value.remap(ids)).transpose()
+  }
+}
+
+impl<K: Remappable + Eq + Hash, V> Remappable for HashMap<K, V> {
+  fn remap(self, ids: &IdMap) -> Result<Self, Error> {
+    self
+      .into_iter()
+      .map(|(key, value)| Ok((key.remap(ids)?, value)))
+      .collect()
+  }
+}
+
+impl<T: Remappable + Ord> Remappable for BTreeSet<T> {
+  fn remap(self, ids: &IdMap) -> Result<Self, Error> {
+    self.into_iter().map(|value| value.remap(ids)).collect()
+  }
+}
+
+impl Remappable for EntityId {
+  fn remap(self, ids: &IdMap) -> Result<Self, Error> {
+    Ok(Self(ids.get_index(self.0)?))
+  }
+}
+
+impl Remappable for PlayerId {
+  fn remap(self, ids: &IdMap) -> Result<Self, Error> {
+    Ok(Self(ids.get_index(self.0)?))
+  }
+}
+
+impl Remappable for RoomId {
+  fn remap(self, ids: &IdMap) -> Result<Self, Error> {
+    Ok(Self(ids.get_index(self.0)?))
+  }
+}
+
+impl Remappable for Passage {
+  fn remap(self, ids: &IdMap) -> Result<Self, Error> {
+    let to = match self.to {
+      PassageDestination::Room(room_id) => PassageDestination::Room(room_id.remap(ids)?),
+      other => other,
+    };
+    Ok(Self {
+      direction: self.direction,
+      from: self.from.remap(ids)?,
+      to,
+      door: self.door.map(|door| door.remap(ids)).transpose()?,
+      properties: self.properties,
+    })
+  }
+}
+
+impl Remappable for HasPassages {
+  fn remap(mut self, ids: &IdMap) -> Result<Self, Error> {
+    for direction in self.get_directions() {
+      let passage = self.get_passage_to(&direction).clone().remap(ids)?;
+      self.set_passage(&direction, passage);
+    }
+    Ok(self)
+  }
+}
+
+impl Remappable for HasVisitedRooms {
+  fn remap(self, ids: &IdMap) -> Result<Self, Error> {
+    Ok(Self(self.0.remap(ids)?))
+  }
+}
+
+impl Remappable for IsADoor {
+  fn remap(self, ids: &IdMap) -> Result<Self, Error> {
+    let key_id = self.0.key_id.map(|key_id| key_id.remap(ids)).transpose()?;
+    Ok(Self(Door { key_id, ..self.0 }))
+  }
+}
+
+impl Remappable for IsContainedBy {
+  fn remap(self, ids: &IdMap) -> Result<Self, Error> {
+    Ok(Self(self.0, self.1.remap(ids)?))
+  }
+}
+
+impl Remappable for IsInRoom {
+  fn remap(self, ids: &IdMap) -> Result<Self, Error> {
+    Ok(Self(self.0.remap(ids)?))
+  }
+}
+
+impl Remappable for TileMap {
+  fn remap(self, ids: &IdMap) -> Result<Self, Error> {
+    Ok(Self {
+      rooms: self
+        .rooms
+        .into_iter()
+        .map(|room| room.remap(ids))
+        .collect::<Result<_, _>>()?,
+      room_index_map: self.room_index_map.remap(ids)?,
+      room_coords_map: self.room_coords_map.remap(ids)?,
+      ..self
+    })
+  }
+}
+
+impl Remappable for EntitySnapshot {
+  /// The record keeps the index it was captured with; only what it refers to
+  /// is translated.
+  fn remap(self, ids: &IdMap) -> Result<Self, Error> {
+    Ok(Self {
+      has_passages: self.has_passages.remap(ids)?,
+      has_visited_rooms: self.has_visited_rooms.remap(ids)?,
+      is_a_door: self.is_a_door.remap(ids)?,
+      is_contained_by: self.is_contained_by.remap(ids)?,
+      is_in_room: self.is_in_room.remap(ids)?,
+      ..self
+    })
+  }
+}
+
+impl Remappable for Snapshot {
+  fn remap(self, ids: &IdMap) -> Result<Self, Error> {
+    Ok(Self {
+      player: PlayerResource(self.player.0.remap(ids)?),
+      spawn_room: SpawnRoomResource(self.spawn_room.0.remap(ids)?),
+      tile_map: TileMapResource(self.tile_map.0.remap(ids)?),
+      entities: self
+        .entities
+        .into_iter()
+        .map(|entity| entity.remap(ids))
+        .collect::<Result<_, _>>()?,
+      ..self
+    })
+  }
+}
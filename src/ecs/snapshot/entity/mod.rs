@@ -0,0 +1,47 @@
+use specs::world::Index;
+
+use crate::ecs::component::*;
+
+/// The `Entity` snapshot.
+///
+/// This holds every serializable component attached to a single entity.
+/// Marker components are recorded as flags.
+///
+/// `HasIntent` is not recorded; intents are transient and will be formed
+/// again by the restored world.
+/// Nor is `HasActionInProgress`; anything half done when the snapshot was
+/// taken is simply abandoned.
+/// Nor is `HasCommandQueue`; the rest of a compound command, e.g. the "s" in
+/// "n then save then s", is dropped, and the player picks up from where the
+/// snapshot left them.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Entity {
+  /// The index this entity had when the snapshot was taken.
+  pub id: Index,
+  pub has_adjectives: Option<HasAdjectives>,
+  pub has_ai: Option<HasAi>,
+  pub has_ailments: Option<HasAilments>,
+  pub has_brief_description: Option<HasBriefDescription>,
+  pub has_camera: bool,
+  pub has_capacity: Option<HasCapacity>,
+  pub has_disposition: Option<HasDisposition>,
+  pub has_gender: Option<HasGender>,
+  pub has_initiative: Option<HasInitiative>,
+  pub has_name: Option<HasName>,
+  pub has_needs: Option<HasNeeds>,
+  pub has_nouns: Option<HasNouns>,
+  pub has_passages: Option<HasPassages>,
+  pub has_state: Option<HasState>,
+  pub has_verbosity: Option<HasVerbosity>,
+  pub has_visited_rooms: Option<HasVisitedRooms>,
+  pub has_weight: Option<HasWeight>,
+  pub is_a_door: Option<IsADoor>,
+  pub is_a_player: bool,
+  pub is_a_room: bool,
+  pub is_a_spawn_room: bool,
+  pub is_an_actor: bool,
+  pub is_an_object: bool,
+  pub is_contained_by: Option<IsContainedBy>,
+  pub is_dead: bool,
+  pub is_in_room: Option<IsInRoom>,
+}
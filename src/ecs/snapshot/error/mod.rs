@@ -0,0 +1,20 @@
+use serde_json::Error as JsonError;
+use specs::world::Index;
+use std::io::Error as IoError;
+
+/// Errors encountered saving or restoring a snapshot.
+#[derive(Debug, Error)]
+pub enum Error {
+  /// An I/O error occurred.
+  #[error("an I/O error occurred ({0})")]
+  IoError(#[from] IoError),
+  /// The snapshot could not be serialized or deserialized.
+  #[error("a JSON error occurred ({0})")]
+  JsonError(#[from] JsonError),
+  /// The snapshot was written by an incompatible version of the game.
+  #[error("unsupported snapshot version ({0})")]
+  UnsupportedVersion(u32),
+  /// The snapshot refers to an entity it doesn't contain.
+  #[error("unknown entity ({0})")]
+  UnknownEntity(Index),
+}
@@ -0,0 +1,25 @@
+use specs::prelude::*;
+use specs::world::Index;
+use std::collections::HashMap;
+
+use super::error::Error;
+
+/// The `IdMap` type.
+///
+/// Entities are recreated on restore, so they won't necessarily get the same
+/// indices they had when the snapshot was taken.  This maps old indices to
+/// the new entities.
+#[derive(Clone, Debug, Default)]
+pub struct IdMap(pub HashMap<Index, Entity>);
+
+impl IdMap {
+  /// Get the new entity for an old index.
+  pub fn get_entity(&self, index: Index) -> Result<Entity, Error> {
+    self.0.get(&index).copied().ok_or(Error::UnknownEntity(index))
+  }
+
+  /// Get the new index for an old index.
+  pub fn get_index(&self, index: Index) -> Result<Index, Error> {
+    Ok(self.get_entity(index)?.id())
+  }
+}
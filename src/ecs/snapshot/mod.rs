@@ -0,0 +1,334 @@
+use specs::prelude::*;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+use crate::ecs::component::*;
+use crate::ecs::resource::random::seeded_rng::State as RandomState;
+use crate::ecs::resource::*;
+
+pub mod _constant;
+pub use _constant::*;
+pub mod _trait;
+pub use _trait::*;
+pub mod entity;
+pub use entity::Entity as EntitySnapshot;
+pub mod error;
+use error::Error;
+pub use error::Error as SnapshotError;
+pub mod id_map;
+pub use id_map::IdMap;
+
+/// The `Snapshot` type.
+///
+/// This captures everything needed to rebuild the game world: the entities,
+/// their components, and the resources describing the world.  Event channels
+/// and terminal resources are not captured.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Snapshot {
+  /// The version of the format, so that old files can be rejected.
+  pub version: u32,
+  pub player: PlayerResource,
+  pub random: RandomState,
+  pub spawn_room: SpawnRoomResource,
+  pub tick: TickResource,
+  pub tile_map: TileMapResource,
+  pub time_of_day: TimeOfDayResource,
+  pub weather: WeatherResource,
+  pub entities: Vec<EntitySnapshot>,
+}
+
+#[derive(SystemData)]
+pub struct Data<'a> {
+  pub entities: Entities<'a>,
+  pub player_resource: Write<'a, PlayerResource>,
+  pub random_resource: Write<'a, RandomResource>,
+  pub spawn_room_resource: Write<'a, SpawnRoomResource>,
+  pub tick_resource: Write<'a, TickResource>,
+  pub tile_map_resource: Write<'a, TileMapResource>,
+  pub time_of_day_resource: Write<'a, TimeOfDayResource>,
+  pub weather_resource: Write<'a, WeatherResource>,
+  pub has_adjectives: WriteStorage<'a, HasAdjectives>,
+  pub has_ai: WriteStorage<'a, HasAi>,
+  pub has_ailments: WriteStorage<'a, HasAilments>,
+  pub has_brief_description: WriteStorage<'a, HasBriefDescription>,
+  pub has_camera: WriteStorage<'a, HasCamera>,
+  pub has_capacity: WriteStorage<'a, HasCapacity>,
+  pub has_disposition: WriteStorage<'a, HasDisposition>,
+  pub has_gender: WriteStorage<'a, HasGender>,
+  pub has_initiative: WriteStorage<'a, HasInitiative>,
+  pub has_name: WriteStorage<'a, HasName>,
+  pub has_needs: WriteStorage<'a, HasNeeds>,
+  pub has_nouns: WriteStorage<'a, HasNouns>,
+  pub has_passages: WriteStorage<'a, HasPassages>,
+  pub has_state: WriteStorage<'a, HasState>,
+  pub has_verbosity: WriteStorage<'a, HasVerbosity>,
+  pub has_visited_rooms: WriteStorage<'a, HasVisitedRooms>,
+  pub has_weight: WriteStorage<'a, HasWeight>,
+  pub is_a_door: WriteStorage<'a, IsADoor>,
+  pub is_a_player: WriteStorage<'a, IsAPlayer>,
+  pub is_a_room: WriteStorage<'a, IsARoom>,
+  pub is_a_spawn_room: WriteStorage<'a, IsASpawnRoom>,
+  pub is_an_actor: WriteStorage<'a, IsAnActor>,
+  pub is_an_object: WriteStorage<'a, IsAnObject>,
+  pub is_contained_by: WriteStorage<'a, IsContainedBy>,
+  pub is_dead: WriteStorage<'a, IsDead>,
+  pub is_in_room: WriteStorage<'a, IsInRoom>,
+}
+
+impl Snapshot {
+  /// Capture the current state of the world.
+  pub fn from_world(ecs: &World) -> Self {
+    let data = ecs.system_data::<Data>();
+    let entities = (&data.entities)
+      .join()
+      .map(|entity| EntitySnapshot {
+        id: entity.id(),
+        has_adjectives: data.has_adjectives.get(entity).cloned(),
+        has_ai: data.has_ai.get(entity).cloned(),
+        has_ailments: data.has_ailments.get(entity).cloned(),
+        has_brief_description: data.has_brief_description.get(entity).cloned(),
+        has_camera: data.has_camera.contains(entity),
+        has_capacity: data.has_capacity.get(entity).cloned(),
+        has_disposition: data.has_disposition.get(entity).cloned(),
+        has_gender: data.has_gender.get(entity).cloned(),
+        has_initiative: data.has_initiative.get(entity).cloned(),
+        has_name: data.has_name.get(entity).cloned(),
+        has_needs: data.has_needs.get(entity).cloned(),
+        has_nouns: data.has_nouns.get(entity).cloned(),
+        has_passages: data.has_passages.get(entity).cloned(),
+        has_state: data.has_state.get(entity).cloned(),
+        has_verbosity: data.has_verbosity.get(entity).cloned(),
+        has_visited_rooms: data.has_visited_rooms.get(entity).cloned(),
+        has_weight: data.has_weight.get(entity).cloned(),
+        is_a_door: data.is_a_door.get(entity).cloned(),
+        is_a_player: data.is_a_player.contains(entity),
+        is_a_room: data.is_a_room.contains(entity),
+        is_a_spawn_room: data.is_a_spawn_room.contains(entity),
+        is_an_actor: data.is_an_actor.contains(entity),
+        is_an_object: data.is_an_object.contains(entity),
+        is_contained_by: data.is_contained_by.get(entity).cloned(),
+        is_dead: data.is_dead.contains(entity),
+        is_in_room: data.is_in_room.get(entity).cloned(),
+      })
+      .collect();
+    Self {
+      version: SNAPSHOT_VERSION,
+      player: *data.player_resource,
+      random: data.random_resource.0.get_state(),
+      spawn_room: *data.spawn_room_resource,
+      tick: *data.tick_resource,
+      tile_map: data.tile_map_resource.clone(),
+      time_of_day: *data.time_of_day_resource,
+      weather: *data.weather_resource,
+      entities,
+    }
+  }
+
+  /// Replace the contents of the world with this snapshot.
+  ///
+  /// The snapshot is checked in full before anything is touched, so one that
+  /// refers to an entity it doesn't contain leaves the world as it was.  Then
+  /// every existing entity is deleted.  The recreated entities keep the
+  /// relative order they were captured in, so joins visit them in the same
+  /// order and the restored world plays out the same way.
+  pub fn restore(&self, ecs: &mut World) -> Result<(), Error> {
+    if self.version != SNAPSHOT_VERSION {
+      return Err(Error::UnsupportedVersion(self.version));
+    }
+    // Remapping onto the indices the snapshot already uses changes nothing,
+    // but fails just where remapping onto the new entities would.
+    let staged = {
+      let entities = ecs.entities();
+      let own_ids = IdMap(
+        self
+          .entities
+          .iter()
+          .map(|snapshot| (snapshot.id, entities.entity(snapshot.id)))
+          .collect(),
+      );
+      self.clone().remap(&own_ids)?
+    };
+    ecs.delete_all();
+    ecs.maintain();
+    {
+      let mut data = ecs.system_data::<Data>();
+      // Freed indices are handed out in no particular order, so sort them to
+      // match the order in which the entities were captured.
+      let mut created = staged
+        .entities
+        .iter()
+        .map(|_| data.entities.create())
+        .collect::<Vec<Entity>>();
+      created.sort_by_key(|entity| entity.id());
+      let mut ids = IdMap::default();
+      for (snapshot, entity) in staged.entities.iter().zip(created) {
+        ids.0.insert(snapshot.id, entity);
+      }
+      let restored = staged.remap(&ids)?;
+      for snapshot in restored.entities.into_iter() {
+        let entity = ids.get_entity(snapshot.id)?;
+        // Inserting into a storage can't fail for an entity we just created.
+        if let Some(has_adjectives) = snapshot.has_adjectives {
+          data.has_adjectives.insert(entity, has_adjectives).unwrap();
+        }
+        if let Some(has_ai) = snapshot.has_ai {
+          data.has_ai.insert(entity, has_ai).unwrap();
+        }
+        if let Some(has_ailments) = snapshot.has_ailments {
+          data.has_ailments.insert(entity, has_ailments).unwrap();
+        }
+        if let Some(has_brief_description) = snapshot.has_brief_description {
+          data
+            .has_brief_description
+            .insert(entity, has_brief_description)
+            .unwrap();
+        }
+        if snapshot.has_camera {
+          data.has_camera.insert(entity, HasCamera).unwrap();
+        }
+        if let Some(has_capacity) = snapshot.has_capacity {
+          data.has_capacity.insert(entity, has_capacity).unwrap();
+        }
+        if let Some(has_disposition) = snapshot.has_disposition {
+          data.has_disposition.insert(entity, has_disposition).unwrap();
+        }
+        if let Some(has_gender) = snapshot.has_gender {
+          data.has_gender.insert(entity, has_gender).unwrap();
+        }
+        if let Some(has_initiative) = snapshot.has_initiative {
+          data.has_initiative.insert(entity, has_initiative).unwrap();
+        }
+        if let Some(has_name) = snapshot.has_name {
+          data.has_name.insert(entity, has_name).unwrap();
+        }
+        if let Some(has_needs) = snapshot.has_needs {
+          data.has_needs.insert(entity, has_needs).unwrap();
+        }
+        if let Some(has_nouns) = snapshot.has_nouns {
+          data.has_nouns.insert(entity, has_nouns).unwrap();
+        }
+        if let Some(has_passages) = snapshot.has_passages {
+          data.has_passages.insert(entity, has_passages).unwrap();
+        }
+        if let Some(has_state) = snapshot.has_state {
+          data.has_state.insert(entity, has_state).unwrap();
+        }
+        if let Some(has_verbosity) = snapshot.has_verbosity {
+          data.has_verbosity.insert(entity, has_verbosity).unwrap();
+        }
+        if let Some(has_visited_rooms) = snapshot.has_visited_rooms {
+          data.has_visited_rooms.insert(entity, has_visited_rooms).unwrap();
+        }
+        if let Some(has_weight) = snapshot.has_weight {
+          data.has_weight.insert(entity, has_weight).unwrap();
+        }
+        if let Some(is_a_door) = snapshot.is_a_door {
+          data.is_a_door.insert(entity, is_a_door).unwrap();
+        }
+        if snapshot.is_a_player {
+          data.is_a_player.insert(entity, IsAPlayer).unwrap();
+        }
+        if snapshot.is_a_room {
+          data.is_a_room.insert(entity, IsARoom).unwrap();
+        }
+        if snapshot.is_a_spawn_room {
+          data.is_a_spawn_room.insert(entity, IsASpawnRoom).unwrap();
+        }
+        if snapshot.is_an_actor {
+          data.is_an_actor.insert(entity, IsAnActor).unwrap();
+        }
+        if snapshot.is_an_object {
+          data.is_an_object.insert(entity, IsAnObject).unwrap();
+        }
+        if let Some(is_contained_by) = snapshot.is_contained_by {
+          data.is_contained_by.insert(entity, is_contained_by).unwrap();
+        }
+        if snapshot.is_dead {
+          data.is_dead.insert(entity, IsDead).unwrap();
+        }
+        if let Some(is_in_room) = snapshot.is_in_room {
+          data.is_in_room.insert(entity, is_in_room).unwrap();
+        }
+      }
+      *data.player_resource = restored.player;
+      *data.random_resource = RandomResource(restored.random.into());
+      *data.spawn_room_resource = restored.spawn_room;
+      *data.tick_resource = restored.tick;
+      *data.tile_map_resource = restored.tile_map;
+      *data.time_of_day_resource = restored.time_of_day;
+      *data.weather_resource = restored.weather;
+    }
+    ecs.maintain();
+    Ok(())
+  }
+
+  /// Write this snapshot to a file.
+  pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+    let writer = BufWriter::new(File::create(path)?);
+    serde_json::to_writer_pretty(writer, self)?;
+    Ok(())
+  }
+
+  /// Read a snapshot from a file.
+  pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+    let reader = BufReader::new(File::open(path)?);
+    let result: Self = serde_json::from_reader(reader)?;
+    if result.version != SNAPSHOT_VERSION {
+      return Err(Error::UnsupportedVersion(result.version));
+    }
+    Ok(result)
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::ecs::entity::RoomId;
+  use crate::game::Headless;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_restore() {
+    init();
+    let mut headless = Headless::new("goat boy");
+    headless.run_script(&["n", "s"]);
+    let ecs = &mut headless.game.ecs;
+    let snapshot = Snapshot::from_world(ecs);
+    let json = serde_json::to_string(&snapshot).unwrap();
+    assert_eq!(serde_json::from_str::<Snapshot>(&json).unwrap(), snapshot);
+    snapshot.restore(ecs).unwrap();
+    assert_eq!(Snapshot::from_world(ecs), snapshot);
+  }
+
+  #[test]
+  pub fn test_restore_unsupported_version() {
+    init();
+    let mut headless = Headless::new("goat boy");
+    let ecs = &mut headless.game.ecs;
+    let mut snapshot = Snapshot::from_world(ecs);
+    snapshot.version += 1;
+    assert!(matches!(snapshot.restore(ecs), Err(Error::UnsupportedVersion(_))));
+  }
+
+  #[test]
+  pub fn test_restore_corrupt() {
+    init();
+    let mut headless = Headless::new("goat boy");
+    headless.run_script(&["n", "s"]);
+    let ecs = &mut headless.game.ecs;
+    let before = Snapshot::from_world(ecs);
+    // Something is in a room that isn't there.
+    let mut snapshot = before.clone();
+    let missing = snapshot.entities.iter().map(|entity| entity.id).max().unwrap() + 1;
+    let entity = snapshot
+      .entities
+      .iter_mut()
+      .find(|entity| entity.is_in_room.is_some())
+      .unwrap();
+    entity.is_in_room = Some(IsInRoom(RoomId(missing)));
+    assert!(matches!(snapshot.restore(ecs), Err(Error::UnknownEntity(id)) if id == missing));
+    assert_eq!(Snapshot::from_world(ecs), before);
+  }
+}
@@ -0,0 +1,20 @@
+use specs::prelude::*;
+
+use crate::ecs::system::Cadence;
+
+/// The `Registrable` trait.
+///
+/// A system that can be added to a dispatcher by the `Registry`.  Each system
+/// declares how often it wants to run and what it has to run after; the
+/// registry decides which dispatcher it ends up in.
+pub trait Registrable: for<'a> System<'a> + Send + Sized + 'static {
+  /// The name of the system, as used in dependencies.
+  const NAME: &'static str;
+  /// How often the system runs.
+  const CADENCE: Cadence;
+  /// The names of systems (of the same cadence) that must run first.
+  const DEPENDENCIES: &'static [&'static str] = &[];
+
+  /// Create the system, registering any event readers it needs.
+  fn create(ecs: &mut World) -> Self;
+}
@@ -0,0 +1,56 @@
+/// The `Cadence` enum.
+///
+/// This is how often a system runs.  Each cadence has its own dispatcher, and
+/// a slower cadence is only considered on ticks when the faster ones run.
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Cadence {
+  /// Every tick.
+  Tick,
+  /// Every ten ticks.
+  Deca,
+  /// Every hundred ticks.
+  Hecto,
+  /// Every thousand ticks.
+  Kilo,
+}
+
+impl Cadence {
+  /// Every cadence, from fastest to slowest.
+  pub fn get_all() -> Vec<Self> {
+    use Cadence::*;
+    vec![Tick, Deca, Hecto, Kilo]
+  }
+
+  /// The number of ticks between runs.
+  pub fn get_period(&self) -> u64 {
+    use Cadence::*;
+    match self {
+      Tick => 1,
+      Deca => 10,
+      Hecto => 100,
+      Kilo => 1000,
+    }
+  }
+
+  /// Whether systems with this cadence should run on the specified tick.
+  pub fn is_due(&self, tick: u64) -> bool {
+    tick.is_multiple_of(self.get_period())
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_is_due() {
+    init();
+    assert!(Cadence::Tick.is_due(7));
+    assert!(!Cadence::Deca.is_due(7));
+    assert!(Cadence::Hecto.is_due(300));
+    assert!(!Cadence::Kilo.is_due(300));
+    assert!(Cadence::Kilo.is_due(3000));
+  }
+}
@@ -0,0 +1,2 @@
+pub mod cadence;
+pub use cadence::Cadence;
@@ -1,8 +1,9 @@
 use crate::action::_trait::actionable::Actionable;
 use crate::ecs::event::*;
+use crate::ecs::system::{Cadence, Registrable};
 use crate::ecs::AllData;
 use specs::prelude::*;
-use specs::shrev::ReaderId;
+use specs::shrev::{EventChannel, ReaderId};
 
 pub struct ActionProcessor {
   pub reader_id: ReaderId<ActionEvent>,
@@ -28,10 +29,29 @@ impl<'a> System<'a> for ActionProcessor {
     for event in events.iter() {
       debug!("Processing next action event, {:?}", event);
       let ActionEvent { action } = event;
-      match action.execute(&mut data) {
+      let result = match action.get_duration(&mut data) {
+        0 => action.execute(&mut data),
+        _ => action.begin(&mut data),
+      };
+      match result {
         Ok(()) => {},
-        Err(error) => action_error!(data, action, error),
+        Err(error) => {
+          action_error!(data, action, error);
+          // Whatever the actor meant to do next probably depended on this.
+          let actor = get_entity!(data, action.get_actor_entity_id());
+          cancel_commands!(data, actor);
+        },
       }
     }
   }
 }
+
+impl Registrable for ActionProcessor {
+  const NAME: &'static str = "action_processor";
+  const CADENCE: Cadence = Cadence::Deca;
+
+  fn create(ecs: &mut World) -> Self {
+    let reader_id = ecs.fetch_mut::<EventChannel<ActionEvent>>().register_reader();
+    Self { reader_id }
+  }
+}
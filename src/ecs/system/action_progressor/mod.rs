@@ -0,0 +1,50 @@
+use crate::action::Actionable;
+use crate::ecs::system::{Cadence, Registrable};
+use crate::ecs::AllData;
+use specs::prelude::*;
+
+/// Carry actions in progress forward a tick at a time.
+///
+/// The world may change while an action is in progress, e.g. someone else
+/// takes what the actor was reaching for, so each action is checked again
+/// every tick, and interrupted as soon as it can no longer be carried out.
+pub struct ActionProgressor {}
+
+impl<'a> System<'a> for ActionProgressor {
+  type SystemData = AllData<'a>;
+
+  /// Run the system.
+  fn run(&mut self, mut data: Self::SystemData) {
+    let entities = (&data.entities, &data.has_action_in_progress)
+      .join()
+      .map(|(entity, _has_action_in_progress)| entity)
+      .collect::<Vec<Entity>>();
+    for entity in entities {
+      let action = data.has_action_in_progress.get(entity).unwrap().0.action.clone();
+      if let Err(error) = action.can_execute(&mut data) {
+        interrupt_action!(data, entity);
+        action_error!(data, action, error);
+        // Whatever the actor meant to do next probably depended on this.
+        cancel_commands!(data, entity);
+        continue;
+      }
+      let in_progress = &mut data.has_action_in_progress.get_mut(entity).unwrap().0;
+      let effects = in_progress.advance();
+      if in_progress.is_complete() {
+        data.has_action_in_progress.remove(entity);
+      }
+      for effect in effects {
+        write_effect_event!(data, effect);
+      }
+    }
+  }
+}
+
+impl Registrable for ActionProgressor {
+  const NAME: &'static str = "action_progressor";
+  const CADENCE: Cadence = Cadence::Tick;
+
+  fn create(_ecs: &mut World) -> Self {
+    Self {}
+  }
+}
@@ -1,29 +1,56 @@
 use specs::prelude::*;
-use specs::shrev::EventChannel;
+use specs::shrev::{EventChannel, ReaderId};
 
 use crate::ecs::component::*;
 use crate::ecs::entity::EntityId;
 use crate::ecs::event::*;
 use crate::ecs::resource::*;
+use crate::ecs::system::{Cadence, Registrable};
 
-pub struct AiProcessor {}
+/// The initiative it costs to carry out an order.
+pub const ORDER_INITIATIVE_COST: usize = 250;
 
-impl AiProcessor {}
+pub struct AiProcessor {
+  pub reader_id: ReaderId<PerceptionEvent>,
+  pub order_reader_id: ReaderId<OrderEvent>,
+}
+
+impl AiProcessor {
+  /// Ask an entity's AI what it should do next, and set that in motion.
+  pub fn plan(entity: Entity, has_ai: &HasAi, data: &mut Data) {
+    let ai = &has_ai.0;
+    match ai.get_action(EntityId(entity.id()), data) {
+      Ok(Some(action)) => {
+        info!("Calculated intradigetic action {:?} for ai {:?}...", action, ai);
+        write_action_event!(data, action);
+      },
+      Ok(None) => {
+        info!("Processed extradiegetic ai {:?}...", ai);
+      },
+      Err(error) => write_output_event!(data, format!("encountered an error ({})", error)),
+    }
+  }
+}
 
 #[derive(SystemData)]
 pub struct Data<'a> {
   pub entities: Entities<'a>,
-  pub camera_resource: Read<'a, CameraResource>,
   pub player_resource: Read<'a, PlayerResource>,
   pub random_resource: Write<'a, RandomResource>,
   pub tile_map_resource: Write<'a, TileMapResource>,
   pub action_event_channel: Write<'a, EventChannel<ActionEvent>>,
   pub effect_event_channel: Write<'a, EventChannel<EffectEvent>>,
+  pub order_event_channel: Read<'a, EventChannel<OrderEvent>>,
   pub output_event_channel: Write<'a, EventChannel<OutputEvent>>,
+  pub perception_event_channel: Read<'a, EventChannel<PerceptionEvent>>,
+  pub has_action_in_progress: ReadStorage<'a, HasActionInProgress>,
   pub has_ai: ReadStorage<'a, HasAi>,
   pub has_brief_description: ReadStorage<'a, HasBriefDescription>,
+  pub has_camera: ReadStorage<'a, HasCamera>,
+  pub has_disposition: ReadStorage<'a, HasDisposition>,
   pub has_gender: ReadStorage<'a, HasGender>,
   pub has_initiative: WriteStorage<'a, HasInitiative>,
+  pub has_intent: WriteStorage<'a, HasIntent>,
   pub has_name: ReadStorage<'a, HasName>,
   pub has_passages: ReadStorage<'a, HasPassages>,
   pub has_state: ReadStorage<'a, HasState>,
@@ -39,23 +66,71 @@ impl<'a> System<'a> for AiProcessor {
 
   /// Run the system.
   fn run(&mut self, mut data: Self::SystemData) {
-    let entity_ais = (&data.entities, &data.has_ai)
+    // Let each AI know what its entity has perceived since the last run.
+    let perception_events = data
+      .perception_event_channel
+      .read(&mut self.reader_id)
+      .cloned()
+      .collect::<Vec<PerceptionEvent>>();
+    for PerceptionEvent { perception } in perception_events {
+      let perceiver = get_entity!(data, perception.perceiver_id);
+      if let Some(has_ai) = data.has_ai.get(perceiver).cloned() {
+        has_ai.0.perceive(&perception, &mut data);
+      }
+    }
+    // Let each AI decide whether to do what it's been told.  An order it
+    // goes along with becomes its intent, carried out when it's able.
+    let order_events = data
+      .order_event_channel
+      .read(&mut self.order_reader_id)
+      .cloned()
+      .collect::<Vec<OrderEvent>>();
+    for OrderEvent {
+      giver_id,
+      addressee_id,
+      action,
+    } in order_events
+    {
+      let addressee = get_entity!(data, addressee_id);
+      let has_ai = match data.has_ai.get(addressee).cloned() {
+        Some(has_ai) => has_ai,
+        None => continue,
+      };
+      if has_ai.0.consider_order(addressee_id, giver_id, &mut data) {
+        has_intent!(data, addressee, action, Priority::Moderate, ORDER_INITIATIVE_COST);
+      } else {
+        let name = get_name!(data, addressee).cloned().unwrap_or_default();
+        write_output_event!(data, giver_id, format!("{} ignores you.", name));
+      }
+    }
+    // Anyone with an intent already knows what they're doing next, and anyone
+    // busy has their hands full.
+    let entity_ais = (
+      &data.entities,
+      &data.has_ai,
+      !&data.has_intent,
+      !&data.has_action_in_progress,
+    )
       .join()
-      .map(|(entity, has_ai)| (entity, has_ai.clone()))
+      .map(|(entity, has_ai, _, _)| (entity, has_ai.clone()))
       .collect::<Vec<(Entity, HasAi)>>();
 
     for (entity, has_ai) in entity_ais {
-      let ai = &has_ai.0;
-      match ai.get_action(EntityId(entity.id()), &mut data) {
-        Ok(Some(action)) => {
-          info!("Calculated intradigetic action {:?} for ai {:?}...", action, ai);
-          write_action_event!(data, action);
-        },
-        Ok(None) => {
-          info!("Processed extradiegetic ai {:?}...", ai);
-        },
-        Err(error) => write_output_event!(data, format!("encountered an error ({})", error)),
-      }
+      Self::plan(entity, &has_ai, &mut data);
+    }
+  }
+}
+
+impl Registrable for AiProcessor {
+  const NAME: &'static str = "ai_processor";
+  const CADENCE: Cadence = Cadence::Deca;
+
+  fn create(ecs: &mut World) -> Self {
+    let reader_id = ecs.fetch_mut::<EventChannel<PerceptionEvent>>().register_reader();
+    let order_reader_id = ecs.fetch_mut::<EventChannel<OrderEvent>>().register_reader();
+    Self {
+      reader_id,
+      order_reader_id,
     }
   }
 }
@@ -0,0 +1,67 @@
+use crate::ecs::component::*;
+use crate::ecs::system::{Cadence, Registrable};
+use specs::prelude::*;
+
+pub struct CollectDead {}
+
+impl CollectDead {}
+
+#[derive(SystemData)]
+pub struct Data<'a> {
+  pub entities: Entities<'a>,
+  pub is_a_player: ReadStorage<'a, IsAPlayer>,
+  pub is_dead: ReadStorage<'a, IsDead>,
+}
+
+/// The player's body sticks around; everyone else's is cleaned up.
+impl<'a> System<'a> for CollectDead {
+  type SystemData = Data<'a>;
+
+  /// Run the system.
+  fn run(&mut self, data: Self::SystemData) {
+    let dead = (&data.entities, &data.is_dead, !&data.is_a_player)
+      .join()
+      .map(|(entity, _, _)| entity)
+      .collect::<Vec<Entity>>();
+    for entity in dead {
+      debug!("Collecting dead entity {:?}...", entity);
+      if let Err(error) = data.entities.delete(entity) {
+        error!("Unable to delete dead entity {:?} ({})", entity, error);
+      }
+    }
+  }
+}
+
+impl Registrable for CollectDead {
+  const NAME: &'static str = "collect_dead";
+  const CADENCE: Cadence = Cadence::Kilo;
+
+  fn create(_ecs: &mut World) -> Self {
+    Self {}
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::game::Headless;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_collect_dead() {
+    init();
+    let mut headless = Headless::new("goat boy");
+    let player = get_player(&headless);
+    let corpse = {
+      let ecs = &mut headless.game.ecs;
+      let corpse = ecs.create_entity().with(IsDead).build();
+      ecs.write_storage::<IsDead>().insert(player, IsDead).unwrap();
+      corpse
+    };
+    headless.advance(1000);
+    let ecs = &headless.game.ecs;
+    assert!(!ecs.entities().is_alive(corpse));
+    assert!(ecs.entities().is_alive(player));
+  }
+}
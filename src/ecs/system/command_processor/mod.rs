@@ -1,5 +1,9 @@
 use crate::command::Commandable;
+use crate::ecs::component::*;
 use crate::ecs::event::*;
+use crate::ecs::resource::*;
+use crate::ecs::system::{Cadence, Registrable};
+use crate::game::_constant::TICKS_PER_TURN;
 use specs::prelude::*;
 use specs::shrev::{EventChannel, ReaderId};
 
@@ -12,9 +16,21 @@ impl CommandProcessor {}
 #[derive(SystemData)]
 pub struct Data<'a> {
   pub entities: Entities<'a>,
+  pub journal_resource: Write<'a, JournalResource>,
+  pub multiplayer_resource: Read<'a, MultiplayerResource>,
+  pub tick_resource: Read<'a, TickResource>,
+  pub tile_map_resource: Read<'a, TileMapResource>,
+  pub time_scale_resource: Write<'a, TimeScaleResource>,
   pub action_event_channel: Write<'a, EventChannel<ActionEvent>>,
   pub command_event_channel: Read<'a, EventChannel<CommandEvent>>,
+  pub meta_event_channel: Write<'a, EventChannel<MetaEvent>>,
   pub output_event_channel: Write<'a, EventChannel<OutputEvent>>,
+  pub has_command_queue: WriteStorage<'a, HasCommandQueue>,
+  pub has_name: ReadStorage<'a, HasName>,
+  pub has_verbosity: WriteStorage<'a, HasVerbosity>,
+  pub has_visited_rooms: ReadStorage<'a, HasVisitedRooms>,
+  pub is_a_room: ReadStorage<'a, IsARoom>,
+  pub is_contained_by: ReadStorage<'a, IsContainedBy>,
 }
 
 impl<'a> System<'a> for CommandProcessor {
@@ -34,20 +50,43 @@ impl<'a> System<'a> for CommandProcessor {
     info!("Processing {} command event(s)...", event_count);
     for event in command_events.iter() {
       debug!("Processing next command event {:?}", event);
-      let CommandEvent { command } = event;
+      let CommandEvent { player_id, command } = event;
       match command.get_action(&mut data) {
         Ok(Some(action)) => {
           info!(
             "Calculated intradiegetic action {:?} for command {:?}...",
             action, command
           );
+          // Anything that happens from here on can be undone as a unit.
+          let tick = data.tick_resource.0;
+          data.journal_resource.0.record_turn(tick);
+          // In turn-based mode, the world moves on only for what's done in
+          // it, and for long enough that it can play out.
+          if data.time_scale_resource.turn_based {
+            data.time_scale_resource.pending_ticks += TICKS_PER_TURN;
+          }
           write_action_event!(data, action);
         },
         Ok(None) => {
           info!("Processed extradiegetic command {:?}...", command);
         },
-        Err(error) => write_output_event!(data, format!("encountered an error ({})", error)),
+        Err(error) => {
+          write_output_event!(data, *player_id, format!("encountered an error ({})", error));
+          // Whatever came next probably depended on this.
+          let player = get_entity!(data, *player_id);
+          cancel_commands!(data, player);
+        },
       }
     }
   }
 }
+
+impl Registrable for CommandProcessor {
+  const NAME: &'static str = "command_processor";
+  const CADENCE: Cadence = Cadence::Deca;
+
+  fn create(ecs: &mut World) -> Self {
+    let reader_id = ecs.fetch_mut::<EventChannel<CommandEvent>>().register_reader();
+    Self { reader_id }
+  }
+}
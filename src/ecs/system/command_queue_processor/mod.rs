@@ -0,0 +1,93 @@
+use crate::command::Commandable;
+use crate::ecs::component::*;
+use crate::ecs::entity::PlayerId;
+use crate::ecs::event::*;
+use crate::ecs::system::{Cadence, Registrable};
+use crate::perception::Perspective;
+use specs::prelude::*;
+use specs::shrev::{EventChannel, ReaderId};
+
+pub struct CommandQueueProcessor {
+  pub reader_id: ReaderId<PerceptionEvent>,
+}
+
+impl CommandQueueProcessor {}
+
+#[derive(SystemData)]
+pub struct Data<'a> {
+  pub entities: Entities<'a>,
+  pub command_event_channel: Write<'a, EventChannel<CommandEvent>>,
+  pub output_event_channel: Write<'a, EventChannel<OutputEvent>>,
+  pub perception_event_channel: Read<'a, EventChannel<PerceptionEvent>>,
+  pub has_action_in_progress: ReadStorage<'a, HasActionInProgress>,
+  pub has_command_queue: WriteStorage<'a, HasCommandQueue>,
+  pub has_initiative: ReadStorage<'a, HasInitiative>,
+  pub is_a_player: ReadStorage<'a, IsAPlayer>,
+}
+
+// Release queued commands, one per player per round, once they're free.
+impl<'a> System<'a> for CommandQueueProcessor {
+  type SystemData = Data<'a>;
+
+  /// Run system.
+  fn run(&mut self, mut data: Self::SystemData) {
+    // Having something done to you is enough to make you stop and think.
+    let perception_events = data
+      .perception_event_channel
+      .read(&mut self.reader_id)
+      .cloned()
+      .collect::<Vec<PerceptionEvent>>();
+    for PerceptionEvent { perception } in perception_events {
+      if perception.perspective != Perspective::SecondPerson {
+        continue;
+      }
+      let perceiver = get_entity!(data, perception.perceiver_id);
+      if cancel_commands!(data, perceiver) > 0 {
+        write_output_event!(data, perception.perceiver_id, "You stop what you were doing.");
+      }
+    }
+    let players = (&data.entities, &data.is_a_player, &data.has_initiative)
+      .join()
+      .map(|(entity, _is_a_player, has_initiative)| (entity, has_initiative.0.current > 0))
+      .collect::<Vec<(Entity, bool)>>();
+    for (player, has_initiative) in players {
+      // Commands wait for the player to be ready and free, unless urgent.
+      let is_urgent = peek_command!(data, player).map_or(false, |command| command.is_urgent());
+      if !is_urgent && (!has_initiative || is_busy!(data, player)) {
+        continue;
+      }
+      if let Some(command) = dequeue_command!(data, player) {
+        write_command_event!(data, PlayerId(player.id()), command);
+      }
+    }
+  }
+}
+
+impl Registrable for CommandQueueProcessor {
+  const NAME: &'static str = "command_queue_processor";
+  const CADENCE: Cadence = Cadence::Deca;
+
+  fn create(ecs: &mut World) -> Self {
+    let reader_id = ecs.fetch_mut::<EventChannel<PerceptionEvent>>().register_reader();
+    Self { reader_id }
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::test::*;
+
+  #[test]
+  pub fn test_command_queue() {
+    init();
+    let mut headless = compass_rose_headless();
+    headless.input("n then e");
+    assert_eq!(get_player_room_name(&headless), "Northeast Room");
+    assert!(!headless.game.has_queued_commands());
+    // There's no way north from here, so going west never happens.
+    headless.input("n, w");
+    assert_eq!(get_player_room_name(&headless), "Northeast Room");
+    assert!(!headless.game.has_queued_commands());
+  }
+}
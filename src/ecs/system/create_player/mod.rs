@@ -10,13 +10,16 @@ pub struct CreatePlayer {}
 pub struct CreatePlayerData<'a> {
   pub entities: Entities<'a>,
   pub player_resource: Write<'a, PlayerResource>,
-  pub camera_resource: Write<'a, CameraResource>,
   pub has_brief_description: WriteStorage<'a, HasBriefDescription>,
+  pub has_camera: WriteStorage<'a, HasCamera>,
+  pub has_capacity: WriteStorage<'a, HasCapacity>,
   pub has_gender: WriteStorage<'a, HasGender>,
   pub has_initiative: WriteStorage<'a, HasInitiative>,
   pub has_name: WriteStorage<'a, HasName>,
+  pub has_needs: WriteStorage<'a, HasNeeds>,
   pub is_a_player: WriteStorage<'a, IsAPlayer>,
   pub is_an_actor: WriteStorage<'a, IsAnActor>,
+  pub is_in_room: WriteStorage<'a, IsInRoom>,
 }
 
 // This system should normally only be run at startup.
@@ -28,7 +31,7 @@ impl<'a> System<'a> for CreatePlayer {
     if data.player_resource.0.is_none() {
       let player = create_player!(data, Gender::Male);
       data.player_resource.0 = Some(PlayerId(player.id()));
-      data.camera_resource.0 = Some(EntityId(player.id()));
+      has_camera!(data, player);
     }
   }
 }
@@ -0,0 +1,60 @@
+use crate::ecs::component::*;
+use crate::ecs::system::{Cadence, Registrable};
+use crate::need::MAX_NEED_LEVEL;
+use specs::prelude::*;
+
+pub struct DecayNeeds {}
+
+impl DecayNeeds {}
+
+#[derive(SystemData)]
+pub struct Data<'a> {
+  pub entities: Entities<'a>,
+  pub has_needs: WriteStorage<'a, HasNeeds>,
+  pub is_dead: ReadStorage<'a, IsDead>,
+}
+
+impl<'a> System<'a> for DecayNeeds {
+  type SystemData = Data<'a>;
+
+  /// Run the system.
+  fn run(&mut self, mut data: Self::SystemData) {
+    for (_entity, has_needs, _) in (&data.entities, &mut data.has_needs, !&data.is_dead).join() {
+      for level in has_needs.0.values_mut() {
+        *level = level.saturating_add(1).min(MAX_NEED_LEVEL);
+      }
+    }
+  }
+}
+
+impl Registrable for DecayNeeds {
+  const NAME: &'static str = "decay_needs";
+  const CADENCE: Cadence = Cadence::Hecto;
+
+  fn create(_ecs: &mut World) -> Self {
+    Self {}
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::game::Headless;
+  use crate::need::Need;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_decay_needs() {
+    init();
+    let mut headless = Headless::new("goat boy");
+    let get_hunger = |headless: &Headless| {
+      let ecs = &headless.game.ecs;
+      let player = get_player(headless);
+      ecs.read_storage::<HasNeeds>().get(player).unwrap().0[&Need::Food]
+    };
+    let before = get_hunger(&headless);
+    headless.advance(300);
+    assert_eq!(get_hunger(&headless), before + 3);
+  }
+}
@@ -1,7 +1,9 @@
 use crate::ecs::component::*;
 use crate::ecs::event::*;
 use crate::ecs::resource::*;
-use crate::effect::Effectable;
+use crate::ecs::system::{Cadence, Registrable};
+use crate::effect::{Effect, Effectable};
+use anyhow::Error;
 use specs::prelude::*;
 use specs::shrev::{EventChannel, ReaderId};
 
@@ -11,23 +13,50 @@ pub struct EffectProcessor {
 
 impl EffectProcessor {}
 
+/// Process a single effect and record it in the journal.
+///
+/// The inverse is calculated first, while the state it depends upon is still
+/// intact.
+pub fn process_effect(effect: &Effect, data: &mut Data) -> Result<(), Error> {
+  let inverse = effect.get_inverse(data)?;
+  effect.process(data)?;
+  // Being attacked, or worse, puts a stop to whatever the victim was doing.
+  if let Some(target_entity_id) = effect.get_hostile_target_id() {
+    let target_entity = get_entity!(data, target_entity_id);
+    interrupt_action!(data, target_entity);
+  }
+  let tick = data.tick_resource.0;
+  data.journal_resource.0.record_effect(tick, effect, inverse);
+  Ok(())
+}
+
 #[derive(SystemData)]
 pub struct Data<'a> {
   pub entities: Entities<'a>,
   pub player_resource: Read<'a, PlayerResource>,
-  pub camera_resource: Read<'a, CameraResource>,
+  pub journal_resource: Write<'a, JournalResource>,
+  pub tick_resource: Read<'a, TickResource>,
   pub tile_map_resource: Write<'a, TileMapResource>,
   pub effect_event_channel: Write<'a, EventChannel<EffectEvent>>,
   pub output_event_channel: Write<'a, EventChannel<OutputEvent>>,
+  pub perception_event_channel: Write<'a, EventChannel<PerceptionEvent>>,
+  pub has_action_in_progress: WriteStorage<'a, HasActionInProgress>,
   pub has_ai: ReadStorage<'a, HasAi>,
+  pub has_ailments: WriteStorage<'a, HasAilments>,
   pub has_brief_description: ReadStorage<'a, HasBriefDescription>,
+  pub has_camera: ReadStorage<'a, HasCamera>,
+  pub has_capacity: ReadStorage<'a, HasCapacity>,
   pub has_initiative: WriteStorage<'a, HasInitiative>,
   pub has_name: ReadStorage<'a, HasName>,
-  pub has_passages: ReadStorage<'a, HasPassages>,
+  pub has_passages: WriteStorage<'a, HasPassages>,
   pub has_state: WriteStorage<'a, HasState>,
+  pub has_verbosity: ReadStorage<'a, HasVerbosity>,
+  pub has_visited_rooms: WriteStorage<'a, HasVisitedRooms>,
+  pub is_a_door: WriteStorage<'a, IsADoor>,
   pub is_a_player: ReadStorage<'a, IsAPlayer>,
   pub is_an_actor: ReadStorage<'a, IsAnActor>,
   pub is_an_object: ReadStorage<'a, IsAnObject>,
+  pub is_contained_by: WriteStorage<'a, IsContainedBy>,
   pub is_in_room: WriteStorage<'a, IsInRoom>,
 }
 
@@ -49,10 +78,20 @@ impl<'a> System<'a> for EffectProcessor {
     for event in events.iter() {
       debug!("Processing next effect event, {:?}", event);
       let EffectEvent { effect } = event;
-      match effect.process(&mut data) {
+      match process_effect(effect, &mut data) {
         Ok(()) => {},
         Err(error) => write_output_event!(data, format!("encountered an error ({})", error)),
       }
     }
   }
 }
+
+impl Registrable for EffectProcessor {
+  const NAME: &'static str = "effect_processor";
+  const CADENCE: Cadence = Cadence::Deca;
+
+  fn create(ecs: &mut World) -> Self {
+    let reader_id = ecs.fetch_mut::<EventChannel<EffectEvent>>().register_reader();
+    Self { reader_id }
+  }
+}
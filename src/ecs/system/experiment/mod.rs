@@ -1,6 +1,7 @@
 use crate::ecs::component::*;
 use crate::ecs::event::*;
 use crate::ecs::resource::*;
+use crate::ecs::system::{Cadence, Registrable};
 use specs::prelude::*;
 use specs::shrev::EventChannel;
 
@@ -53,3 +54,12 @@ impl<'a> System<'a> for Experiment {
     }
   }
 }
+
+impl Registrable for Experiment {
+  const NAME: &'static str = "experiment";
+  const CADENCE: Cadence = Cadence::Deca;
+
+  fn create(_ecs: &mut World) -> Self {
+    Self {}
+  }
+}
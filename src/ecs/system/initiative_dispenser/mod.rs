@@ -1,4 +1,5 @@
 use crate::ecs::component::*;
+use crate::ecs::system::{Cadence, Registrable};
 use crate::initiative::MAX_INITIATIVE;
 use specs::prelude::*;
 
@@ -25,3 +26,12 @@ impl<'a> System<'a> for InitiativeDispenser {
     }
   }
 }
+
+impl Registrable for InitiativeDispenser {
+  const NAME: &'static str = "initiative_dispenser";
+  const CADENCE: Cadence = Cadence::Deca;
+
+  fn create(_ecs: &mut World) -> Self {
+    Self {}
+  }
+}
@@ -2,13 +2,18 @@ use crate::ecs::component::*;
 use crate::ecs::entity::*;
 use crate::ecs::event::*;
 use crate::ecs::resource::*;
-use crate::input::{Input, ParserData};
+use crate::ecs::system::{Cadence, Registrable};
+use crate::input::{Input, InputError, ParserContext, ParserData, Referent};
+use crate::map::Direction;
 use anyhow::Error as AnyError;
 use specs::prelude::*;
 use specs::shrev::{EventChannel, ReaderId};
+use std::collections::HashMap;
 
 pub struct InputProcessor {
   pub reader_id: ReaderId<InputEvent>,
+  /// What each player's parser remembers between lines of input.
+  pub contexts: HashMap<PlayerId, ParserContext>,
 }
 
 impl InputProcessor {}
@@ -18,51 +23,83 @@ pub struct Data<'a> {
   pub entities: Entities<'a>,
   pub player_resource: Read<'a, PlayerResource>,
   pub output_resource: Write<'a, OutputResource>,
-  pub command_event_channel: Write<'a, EventChannel<CommandEvent>>,
   pub input_event_channel: Read<'a, EventChannel<InputEvent>>,
   pub output_event_channel: Write<'a, EventChannel<OutputEvent>>,
+  pub has_adjectives: ReadStorage<'a, HasAdjectives>,
   pub has_brief_description: ReadStorage<'a, HasBriefDescription>,
+  pub has_command_queue: WriteStorage<'a, HasCommandQueue>,
   pub has_name: ReadStorage<'a, HasName>,
+  pub has_nouns: ReadStorage<'a, HasNouns>,
   pub has_passages: ReadStorage<'a, HasPassages>,
+  pub is_a_door: ReadStorage<'a, IsADoor>,
   pub is_a_player: ReadStorage<'a, IsAPlayer>,
+  pub is_an_actor: ReadStorage<'a, IsAnActor>,
   pub is_an_object: ReadStorage<'a, IsAnObject>,
+  pub is_contained_by: ReadStorage<'a, IsContainedBy>,
   pub is_in_room: ReadStorage<'a, IsInRoom>,
 }
 
-impl<'a> ParserData for Data<'a> {
+/// The world as a single player's parser sees it.
+pub struct PlayerData<'a, 'b> {
+  pub data: &'b Data<'a>,
+  pub player_id: PlayerId,
+}
+
+impl<'a, 'b> ParserData for PlayerData<'a, 'b> {
   /// Retrieve the player ID.
   fn get_player_id(&self) -> Result<PlayerId, AnyError> {
-    Ok(get_player_id!(self))
-  }
-  /// Retrieve a list of nouns.
-  fn get_nouns(&self) -> Result<Vec<(String, EntityId)>, AnyError> {
-    let player_id = get_player_id!(self);
-    let player = get_entity!(self, player_id);
-    if let Some(current_room) = get_current_room_id!(self, player) {
-      let result = (
-        &self.entities,
-        &self.is_in_room,
-        &self.has_name,
-        &self.has_brief_description,
-        !&self.is_a_player,
-      )
-        .join()
-        .filter(|(_entity, is_in_room, _has_name, _has_brief_description, _)| is_in_room.0 == current_room)
-        .map(|(entity, _is_in_room, has_name, _has_brief_description, _)| {
-          (has_name.0.to_lowercase(), EntityId(entity.id()))
-        })
-        .collect::<Vec<(String, EntityId)>>();
-      return Ok(result);
-    }
-    Err(anyhow!("Not found"))
+    Ok(self.player_id)
   }
-  /// Retrieve a list of genitives.
-  fn get_genitives(&self) -> Result<Vec<String>, AnyError> {
-    Ok(vec![])
+  /// Retrieve everything the player might refer to.
+  ///
+  /// That's everything else in the room with a noun to call it by, and
+  /// everything in or on those, or carried by anyone there.
+  fn get_referents(&self) -> Result<Vec<Referent>, AnyError> {
+    let data = self.data;
+    let player = get_entity!(data, self.player_id);
+    let current_room = match get_current_room_id!(data, player) {
+      Some(current_room) => current_room,
+      None => return Ok(vec![]),
+    };
+    // Doors belong to no room, but can be seen from either side.
+    let doors = get_room_doors!(data, get_entity!(data, current_room))
+      .into_iter()
+      .map(|(_direction, door)| door)
+      .collect::<Vec<Entity>>();
+    let result = (
+      &data.entities,
+      &data.has_nouns,
+      data.has_adjectives.maybe(),
+      data.has_name.maybe(),
+    )
+      .join()
+      .filter(|(entity, _has_nouns, _has_adjectives, _has_name)| {
+        *entity != player && (get_outermost_room_id!(data, *entity) == Some(current_room) || doors.contains(entity))
+      })
+      .map(|(entity, has_nouns, has_adjectives, has_name)| Referent {
+        entity_id: EntityId(entity.id()),
+        name: has_name.map(|has_name| has_name.0.clone()).unwrap_or_default(),
+        nouns: has_nouns.0.clone(),
+        adjectives: has_adjectives
+          .map(|has_adjectives| has_adjectives.0.clone())
+          .unwrap_or_default(),
+        owner_id: get_holder!(data, entity).map(|holder| EntityId(holder.id())),
+        container_id: get_container!(data, entity).map(|(_containment, container_id)| container_id),
+        is_an_object: data.is_an_object.get(entity).is_some(),
+      })
+      .collect();
+    Ok(result)
   }
-  /// Retrieve a list of adjectives.
-  fn get_adjectives(&self) -> Result<Vec<String>, AnyError> {
-    Ok(vec![])
+
+  /// Retrieve the directions in which the player can leave the room.
+  fn get_exit_directions(&self) -> Result<Vec<Direction>, AnyError> {
+    let data = self.data;
+    let player = get_entity!(data, self.player_id);
+    let result = get_current_room_id!(data, player)
+      .and_then(|room_id| get_passages!(data, get_entity!(data, room_id)))
+      .map(|passages| passages.get_visible_directions())
+      .unwrap_or_default();
+    Ok(result)
   }
 }
 
@@ -83,16 +120,47 @@ impl<'a> System<'a> for InputProcessor {
     info!("Processing {} input event(s)...", event_count);
     let input_manager = Input::default();
     for event in input_events.iter() {
+      // Input without a player is from whoever is at the terminal.
+      let player_id = match event.player_id.or(data.player_resource.0) {
+        Some(player_id) => player_id,
+        None => {
+          warn!("Discarding input {:?} with no player to act on it...", event);
+          continue;
+        },
+      };
       let input_string = &event.input;
-      match input_manager.interpret(input_string, &data) {
-        Ok((command, string_opt)) => {
-          write_command_event!(data, command);
+      let player_data = PlayerData { data: &data, player_id };
+      let context = self.contexts.entry(player_id).or_default();
+      match input_manager.interpret(input_string, &player_data, context) {
+        Ok((commands, string_opt)) => {
+          // New orders replace any that haven't been carried out yet.
+          if !commands.is_empty() {
+            let player = get_entity!(data, player_id);
+            queue_commands!(data, player, commands);
+          }
           if let Some(string) = string_opt {
-            write_output_event!(data, string);
+            write_output_event!(data, player_id, string);
           }
         },
-        Err(error) => write_output_event!(data, format!("Something was screwed up about {:?}: {}", event, error)),
+        // Some errors are questions for the player, e.g. which goblin they meant.
+        Err(error) if error.is::<InputError>() => write_output_event!(data, player_id, error.to_string()),
+        Err(error) => write_output_event!(
+          data,
+          player_id,
+          format!("Something was screwed up about {:?}: {}", event.input, error)
+        ),
       }
     }
   }
 }
+
+impl Registrable for InputProcessor {
+  const NAME: &'static str = "input_processor";
+  const CADENCE: Cadence = Cadence::Deca;
+
+  fn create(ecs: &mut World) -> Self {
+    let reader_id = ecs.fetch_mut::<EventChannel<InputEvent>>().register_reader();
+    let contexts = HashMap::new();
+    Self { reader_id, contexts }
+  }
+}
@@ -1,5 +1,6 @@
 use crate::ecs::component::*;
 use crate::ecs::event::*;
+use crate::ecs::system::{Cadence, Registrable};
 use specs::prelude::*;
 use specs::shrev::EventChannel;
 
@@ -9,6 +10,7 @@ pub struct IntentProcessor {}
 pub struct Data<'a> {
   pub entities: Entities<'a>,
   pub action_event_channel: Write<'a, EventChannel<ActionEvent>>,
+  pub has_action_in_progress: ReadStorage<'a, HasActionInProgress>,
   pub has_initiative: WriteStorage<'a, HasInitiative>,
   pub has_intent: ReadStorage<'a, HasIntent>,
   pub lazy_updater: Read<'a, LazyUpdate>,
@@ -20,7 +22,14 @@ impl<'a> System<'a> for IntentProcessor {
 
   /// Run system.
   fn run(&mut self, mut data: Self::SystemData) {
-    for (entity, mut has_initiative, has_intent) in (&data.entities, &mut data.has_initiative, &data.has_intent).join()
+    // Intents keep until whatever's in progress is over.
+    for (entity, mut has_initiative, has_intent, _) in (
+      &data.entities,
+      &mut data.has_initiative,
+      &data.has_intent,
+      !&data.has_action_in_progress,
+    )
+      .join()
     {
       if has_initiative.0.current > has_intent.0.initiative_cost {
         has_initiative.0.current -= has_intent.0.initiative_cost;
@@ -30,3 +39,12 @@ impl<'a> System<'a> for IntentProcessor {
     }
   }
 }
+
+impl Registrable for IntentProcessor {
+  const NAME: &'static str = "intent_processor";
+  const CADENCE: Cadence = Cadence::Deca;
+
+  fn create(_ecs: &mut World) -> Self {
+    Self {}
+  }
+}
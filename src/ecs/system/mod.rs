@@ -1,18 +1,27 @@
 use specs::prelude::*;
-use specs::shrev::EventChannel;
-
-use crate::ecs::event::*;
 
+pub mod _trait;
+pub use _trait::*;
+pub mod _type;
+pub use _type::*;
 pub mod action_processor;
 pub use action_processor::ActionProcessor as ActionProcessorSystem;
+pub mod action_progressor;
+pub use action_progressor::ActionProgressor as ActionProgressorSystem;
 pub mod ai_processor;
 pub use ai_processor::AiProcessor as AiProcessorSystem;
+pub mod collect_dead;
+pub use collect_dead::CollectDead as CollectDeadSystem;
 pub mod command_processor;
 pub use command_processor::CommandProcessor as CommandProcessorSystem;
+pub mod command_queue_processor;
+pub use command_queue_processor::CommandQueueProcessor as CommandQueueProcessorSystem;
 pub mod create_map;
 pub use create_map::CreateMap as CreateMapSystem;
 pub mod create_player;
 pub use create_player::CreatePlayer as CreatePlayerSystem;
+pub mod decay_needs;
+pub use decay_needs::DecayNeeds as DecayNeedsSystem;
 pub mod effect_processor;
 pub use effect_processor::EffectProcessor as EffectProcessorSystem;
 pub mod experiment;
@@ -25,68 +34,70 @@ pub mod intent_processor;
 pub use intent_processor::IntentProcessor as IntentProcessorSystem;
 pub mod output_processor;
 pub use output_processor::OutputProcessor as OutputProcessorSystem;
+pub mod registry;
+pub use registry::Registry;
+pub mod replan_npcs;
+pub use replan_npcs::ReplanNpcs as ReplanNpcsSystem;
 pub mod tick;
 pub use tick::Tick as TickSystem;
+pub mod update_time_of_day;
+pub use update_time_of_day::UpdateTimeOfDay as UpdateTimeOfDaySystem;
+pub mod update_weather;
+pub use update_weather::UpdateWeather as UpdateWeatherSystem;
 
 pub fn run_initial_systems(ecs: &mut World) {
   (CreatePlayerSystem {}).run_now(ecs);
   (CreateMapSystem {}).run_now(ecs);
 }
 
-pub fn get_tick_dispatcher(_ecs: &mut World) -> Dispatcher<'static, 'static> {
-  let tick_system = TickSystem {};
-  let dispatcher = DispatcherBuilder::new().with(tick_system, "tick", &[]).build();
-  dispatcher
+/// Offer every scheduled system to the registry.
+///
+/// Each system declares its own cadence, so this is just an inventory.  Within
+/// a cadence, systems that conflict run in the order listed here.
+pub fn register_systems(registry: &mut Registry, ecs: &mut World) {
+  registry
+    .register::<TickSystem>(ecs)
+    .register::<ExperimentSystem>(ecs)
+    .register::<InitiativeDispenserSystem>(ecs)
+    .register::<InputProcessorSystem>(ecs)
+    .register::<AiProcessorSystem>(ecs)
+    .register::<CommandQueueProcessorSystem>(ecs)
+    .register::<CommandProcessorSystem>(ecs)
+    .register::<IntentProcessorSystem>(ecs)
+    .register::<ActionProcessorSystem>(ecs)
+    .register::<ActionProgressorSystem>(ecs)
+    .register::<EffectProcessorSystem>(ecs)
+    .register::<OutputProcessorSystem>(ecs)
+    .register::<DecayNeedsSystem>(ecs)
+    .register::<UpdateTimeOfDaySystem>(ecs)
+    .register::<ReplanNpcsSystem>(ecs)
+    .register::<UpdateWeatherSystem>(ecs)
+    .register::<CollectDeadSystem>(ecs);
+}
+
+/// Build the dispatcher for the systems with the specified cadence.
+pub fn get_dispatcher(ecs: &mut World, cadence: Cadence) -> Dispatcher<'static, 'static> {
+  let mut registry = Registry::new(cadence);
+  register_systems(&mut registry, ecs);
+  registry.build()
+}
+
+/// Every tick.
+pub fn get_tick_dispatcher(ecs: &mut World) -> Dispatcher<'static, 'static> {
+  get_dispatcher(ecs, Cadence::Tick)
 }
 
 /// Every ten ticks.
 pub fn get_deca_tick_dispatcher(ecs: &mut World) -> Dispatcher<'static, 'static> {
-  let output_processor_system = {
-    let reader_id = ecs.fetch_mut::<EventChannel<OutputEvent>>().register_reader();
-    OutputProcessorSystem { reader_id }
-  };
-  let input_processor_system = {
-    let reader_id = ecs.fetch_mut::<EventChannel<InputEvent>>().register_reader();
-    InputProcessorSystem { reader_id }
-  };
-  let command_processor_system = {
-    let reader_id = ecs.fetch_mut::<EventChannel<CommandEvent>>().register_reader();
-    CommandProcessorSystem { reader_id }
-  };
-  let action_processor_system = {
-    let reader_id = ecs.fetch_mut::<EventChannel<ActionEvent>>().register_reader();
-    ActionProcessorSystem { reader_id }
-  };
-  let effect_processor_system = {
-    let reader_id = ecs.fetch_mut::<EventChannel<EffectEvent>>().register_reader();
-    EffectProcessorSystem { reader_id }
-  };
-  let ai_processor_system = AiProcessorSystem {};
-  let intent_processor_system = IntentProcessorSystem {};
-  let experiment_system = ExperimentSystem {};
-  let initiative_dispenser_system = InitiativeDispenserSystem {};
-  let dispatcher = DispatcherBuilder::new()
-    .with(experiment_system, "experiment", &[])
-    .with(initiative_dispenser_system, "initiative_dispenser", &[])
-    .with(input_processor_system, "input_processor", &[])
-    .with(ai_processor_system, "ai_processor", &[])
-    .with(command_processor_system, "command_processor", &[])
-    .with(intent_processor_system, "intent_processor", &[])
-    .with(action_processor_system, "action_processor", &[])
-    .with(effect_processor_system, "effect_processor", &[])
-    .with(output_processor_system, "output_processor", &[])
-    .build();
-  dispatcher
+  get_dispatcher(ecs, Cadence::Deca)
 }
 
 /// Every hundred ticks.
-pub fn get_hecto_tick_dispatcher(_ecs: &mut World) -> Dispatcher<'static, 'static> {
-  let dispatcher = DispatcherBuilder::new().build();
-  dispatcher
+pub fn get_hecto_tick_dispatcher(ecs: &mut World) -> Dispatcher<'static, 'static> {
+  get_dispatcher(ecs, Cadence::Hecto)
 }
 
 /// Every thousand ticks.
-pub fn get_kilo_tick_dispatcher(_ecs: &mut World) -> Dispatcher<'static, 'static> {
-  let dispatcher = DispatcherBuilder::new().build();
-  dispatcher
+pub fn get_kilo_tick_dispatcher(ecs: &mut World) -> Dispatcher<'static, 'static> {
+  get_dispatcher(ecs, Cadence::Kilo)
 }
@@ -1,12 +1,15 @@
+use crate::ecs::entity::EntityId;
 use crate::ecs::event::OutputEvent;
 use crate::ecs::resource::*;
-use crate::formatting::format_string;
+use crate::ecs::system::{Cadence, Registrable};
+use crate::output::{AnsiRenderer, Layout, Renderer};
 use specs::prelude::*;
 use specs::shrev::{EventChannel, ReaderId};
 use std::io::Write as _;
 
 pub struct OutputProcessor {
   pub reader_id: ReaderId<OutputEvent>,
+  pub renderer: Box<dyn Renderer>,
 }
 
 impl OutputProcessor {}
@@ -15,6 +18,7 @@ impl OutputProcessor {}
 pub struct Data<'a> {
   pub entities: Entities<'a>,
   pub output_resource: Write<'a, OutputResource>,
+  pub player_resource: Read<'a, PlayerResource>,
   pub output_event_channel: Read<'a, EventChannel<OutputEvent>>,
 }
 
@@ -31,11 +35,33 @@ impl<'a> System<'a> for OutputProcessor {
     if event_count == 0 {
       return;
     }
+    // Without a terminal attached, someone else is listening to the channel.
+    if data.output_resource.0.is_none() {
+      return;
+    }
     let mut output = clone_output!(data);
     info!("Processing {} output event(s)...", event_count);
+    // Only output for everyone, or for the player at the terminal, is shown.
+    let player_id = data.player_resource.0.map(EntityId::from);
+    // The terminal may have been resized since the last time we looked.
+    let layout = Layout::from_terminal();
     for event in output_events.iter() {
-      let string = format_string(event.string.trim());
-      writeln!(output, "{}\n", string).unwrap();
+      if event.recipient.is_some() && event.recipient != player_id {
+        continue;
+      }
+      let string = self.renderer.render(&layout.apply(&event.document));
+      writeln!(output, "{}\n", string.trim()).unwrap();
     }
   }
 }
+
+impl Registrable for OutputProcessor {
+  const NAME: &'static str = "output_processor";
+  const CADENCE: Cadence = Cadence::Deca;
+
+  fn create(ecs: &mut World) -> Self {
+    let reader_id = ecs.fetch_mut::<EventChannel<OutputEvent>>().register_reader();
+    let renderer = Box::new(AnsiRenderer);
+    Self { reader_id, renderer }
+  }
+}
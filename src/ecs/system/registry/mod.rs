@@ -0,0 +1,39 @@
+use specs::prelude::*;
+
+use crate::ecs::system::{Cadence, Registrable};
+
+/// The `Registry` type.
+///
+/// This collects the systems for a single cadence into a dispatcher.  Every
+/// system is offered to every registry; systems with a different cadence are
+/// ignored and never created.
+pub struct Registry {
+  /// The cadence of the systems being collected.
+  pub cadence: Cadence,
+  /// The dispatcher being built.
+  builder: DispatcherBuilder<'static, 'static>,
+}
+
+impl Registry {
+  /// Constructor.
+  pub fn new(cadence: Cadence) -> Self {
+    let builder = DispatcherBuilder::new();
+    Self { cadence, builder }
+  }
+
+  /// Add a system, if it runs at this cadence.
+  ///
+  /// Systems that conflict with one another run in the order they were
+  /// registered.
+  pub fn register<S: Registrable>(&mut self, ecs: &mut World) -> &mut Self {
+    if S::CADENCE == self.cadence {
+      self.builder.add(S::create(ecs), S::NAME, S::DEPENDENCIES);
+    }
+    self
+  }
+
+  /// Build the dispatcher.
+  pub fn build(self) -> Dispatcher<'static, 'static> {
+    self.builder.build()
+  }
+}
@@ -0,0 +1,78 @@
+use crate::ecs::component::*;
+use crate::ecs::system::ai_processor::{AiProcessor, Data};
+use crate::ecs::system::{Cadence, Registrable};
+use specs::prelude::*;
+
+pub struct ReplanNpcs {}
+
+impl ReplanNpcs {}
+
+/// NPCs plan from moment to moment, but their circumstances can change out
+/// from under them.  Every so often, this throws out whatever an idle NPC had
+/// resolved to do and has its AI plan again from where it stands now.
+impl<'a> System<'a> for ReplanNpcs {
+  type SystemData = Data<'a>;
+
+  /// Run the system.
+  fn run(&mut self, mut data: Self::SystemData) {
+    let stale = (
+      &data.entities,
+      &data.has_ai,
+      &data.has_intent,
+      !&data.has_action_in_progress,
+      !&data.is_a_player,
+    )
+      .join()
+      .map(|(entity, has_ai, _, _, _)| (entity, has_ai.clone()))
+      .collect::<Vec<(Entity, HasAi)>>();
+    for (entity, has_ai) in stale {
+      debug!("Replanning for entity {:?}...", entity);
+      data.has_intent.remove(entity);
+      AiProcessor::plan(entity, &has_ai, &mut data);
+    }
+  }
+}
+
+impl Registrable for ReplanNpcs {
+  const NAME: &'static str = "replan_npcs";
+  const CADENCE: Cadence = Cadence::Hecto;
+
+  fn create(_ecs: &mut World) -> Self {
+    Self {}
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::action::LookAroundAction;
+  use crate::ecs::entity::EntityId;
+  use crate::intent::Intent;
+  use crate::priority::Priority;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_replan_npcs() {
+    init();
+    let mut headless = compass_rose_headless();
+    let goblin = get_named_entity(&headless, "Goblin");
+    // An intent the goblin will never have the initiative to carry out.
+    let intent = Intent {
+      action: create_action!(LookAroundAction {
+        entity_id: EntityId(goblin.id())
+      }),
+      priority: Priority::Low,
+      initiative_cost: usize::MAX,
+    };
+    {
+      let ecs = &mut headless.game.ecs;
+      ecs
+        .write_storage::<HasIntent>()
+        .insert(goblin, HasIntent(intent))
+        .unwrap();
+    }
+    headless.advance(100);
+    assert!(!headless.game.ecs.read_storage::<HasIntent>().contains(goblin));
+  }
+}
@@ -1,6 +1,7 @@
 use specs::prelude::*;
 
 use crate::ecs::resource::*;
+use crate::ecs::system::{Cadence, Registrable};
 
 pub struct Tick {}
 
@@ -18,3 +19,12 @@ impl<'a> System<'a> for Tick {
     data.tick_resource.0 = data.tick_resource.0.wrapping_add(1);
   }
 }
+
+impl Registrable for Tick {
+  const NAME: &'static str = "tick";
+  const CADENCE: Cadence = Cadence::Tick;
+
+  fn create(_ecs: &mut World) -> Self {
+    Self {}
+  }
+}
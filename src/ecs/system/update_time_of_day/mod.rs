@@ -0,0 +1,36 @@
+use crate::ecs::resource::*;
+use crate::ecs::system::{Cadence, Registrable};
+use specs::prelude::*;
+
+pub struct UpdateTimeOfDay {}
+
+impl UpdateTimeOfDay {}
+
+#[derive(SystemData)]
+pub struct Data<'a> {
+  pub entities: Entities<'a>,
+  pub tick_resource: Read<'a, TickResource>,
+  pub time_of_day_resource: Write<'a, TimeOfDayResource>,
+}
+
+impl<'a> System<'a> for UpdateTimeOfDay {
+  type SystemData = Data<'a>;
+
+  /// Run the system.
+  fn run(&mut self, mut data: Self::SystemData) {
+    let time_of_day = TimeOfDayResource::from_tick(data.tick_resource.0);
+    if time_of_day != *data.time_of_day_resource {
+      info!("The time of day is now {}.", time_of_day);
+      *data.time_of_day_resource = time_of_day;
+    }
+  }
+}
+
+impl Registrable for UpdateTimeOfDay {
+  const NAME: &'static str = "update_time_of_day";
+  const CADENCE: Cadence = Cadence::Hecto;
+
+  fn create(_ecs: &mut World) -> Self {
+    Self {}
+  }
+}
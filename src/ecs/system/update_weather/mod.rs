@@ -0,0 +1,36 @@
+use crate::ecs::resource::*;
+use crate::ecs::system::{Cadence, Registrable};
+use specs::prelude::*;
+
+pub struct UpdateWeather {}
+
+impl UpdateWeather {}
+
+#[derive(SystemData)]
+pub struct Data<'a> {
+  pub entities: Entities<'a>,
+  pub random_resource: Write<'a, RandomResource>,
+  pub weather_resource: Write<'a, WeatherResource>,
+}
+
+impl<'a> System<'a> for UpdateWeather {
+  type SystemData = Data<'a>;
+
+  /// Run the system.
+  fn run(&mut self, mut data: Self::SystemData) {
+    let weather = data.weather_resource.get_next(&mut data.random_resource.0);
+    if weather != *data.weather_resource {
+      info!("The weather is now {}.", weather);
+      *data.weather_resource = weather;
+    }
+  }
+}
+
+impl Registrable for UpdateWeather {
+  const NAME: &'static str = "update_weather";
+  const CADENCE: Cadence = Cadence::Kilo;
+
+  fn create(_ecs: &mut World) -> Self {
+    Self {}
+  }
+}
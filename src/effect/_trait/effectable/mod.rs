@@ -1,4 +1,6 @@
+use crate::ecs::entity::EntityId;
 use crate::ecs::system::effect_processor::Data;
+use crate::effect::{Effect, EffectRecord};
 use anyhow::Error;
 use std::fmt::Debug;
 
@@ -8,4 +10,21 @@ use std::fmt::Debug;
 pub trait Effectable: Debug + Send + Sync {
   /// Process the effect's changes on the world.
   fn process(&self, data: &mut Data) -> Result<(), Error>;
+
+  /// Calculate the effects that would undo this one.
+  ///
+  /// This is called just before the effect is processed, so it can capture
+  /// whatever state the effect is about to change.  The effects returned are
+  /// processed in order, and should be silent.
+  fn get_inverse(&self, data: &mut Data) -> Result<Vec<Effect>, Error>;
+
+  /// The entity this effect is hostile to, if any.
+  ///
+  /// Whatever that entity is in the middle of doing is interrupted.
+  fn get_hostile_target_id(&self) -> Option<EntityId> {
+    None
+  }
+
+  /// Get a serializable record of this effect.
+  fn get_record(&self) -> EffectRecord;
 }
@@ -0,0 +1,47 @@
+use crate::ecs::entity::EntityId;
+use crate::ecs::entity::RoomId;
+use crate::ecs::system::effect_processor::Data as EffectProcessorData;
+use crate::effect::{Effect, EffectRecord, Effectable};
+use anyhow::Error;
+
+/// `EntityDropsEntity`.
+///
+/// The target is put down in the room.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Drops {
+  /// The entity performing the action.
+  pub entity_id: EntityId,
+  /// The entity being dropped.
+  pub target_entity_id: EntityId,
+  /// The room the target is dropped in.
+  pub room_id: RoomId,
+}
+
+impl Effectable for Drops {
+  fn process(&self, data: &mut EffectProcessorData) -> Result<(), Error> {
+    let entity = get_entity!(data, self.entity_id);
+    let target_entity = get_entity!(data, self.target_entity_id);
+    let name = get_name!(data, entity).unwrap().clone();
+    let lc_name = get_lc_name!(data, target_entity).unwrap();
+    narrate!(
+      data,
+      entity,
+      format!("You drop the {}.", lc_name),
+      format!("{} drops the {}.", name, lc_name)
+    );
+    remove_is_contained_by!(data, target_entity);
+    is_in_room!(data, target_entity, self.room_id);
+    Ok(())
+  }
+
+  fn get_inverse(&self, data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    Ok(vec![
+      get_set_container_inverse!(data, self.target_entity_id),
+      get_set_room_inverse!(data, self.target_entity_id),
+    ])
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntityDropsEntity(*self)
+  }
+}
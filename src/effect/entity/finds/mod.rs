@@ -0,0 +1,4 @@
+pub mod nothing;
+pub use nothing::Nothing as EntityFindsNothing;
+pub mod passage;
+pub use passage::Passage as EntityFindsPassage;
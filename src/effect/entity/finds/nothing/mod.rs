@@ -0,0 +1,36 @@
+use crate::ecs::entity::EntityId;
+use crate::ecs::system::effect_processor::Data as EffectProcessorData;
+use crate::effect::{Effect, EffectRecord, Effectable};
+use anyhow::Error;
+
+/// `EntityFindsNothing`.
+///
+/// A search turned up nothing.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Nothing {
+  /// The entity performing the action.
+  pub entity_id: EntityId,
+}
+
+impl Effectable for Nothing {
+  fn process(&self, data: &mut EffectProcessorData) -> Result<(), Error> {
+    let entity = get_entity!(data, self.entity_id);
+    let name = get_name!(data, entity).unwrap().clone();
+    narrate!(
+      data,
+      entity,
+      "You find nothing out of the ordinary.",
+      format!("{} finds nothing out of the ordinary.", name)
+    );
+    Ok(())
+  }
+
+  fn get_inverse(&self, _data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    // Finding nothing doesn't change anything.
+    Ok(vec![])
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntityFindsNothing(*self)
+  }
+}
@@ -0,0 +1,47 @@
+use crate::ecs::entity::{EntityId, RoomId};
+use crate::ecs::system::effect_processor::Data as EffectProcessorData;
+use crate::effect::{Effect, EffectRecord, Effectable};
+use crate::map::Direction;
+use anyhow::Error;
+
+/// `EntityFindsPassage`.
+///
+/// A hidden passage is found, and is hidden no longer, for anyone.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Passage {
+  /// The entity performing the action.
+  pub entity_id: EntityId,
+  /// The room the passage leads out of.
+  pub room_id: RoomId,
+  /// The direction in which the passage leads.
+  pub direction: Direction,
+}
+
+impl Effectable for Passage {
+  fn process(&self, data: &mut EffectProcessorData) -> Result<(), Error> {
+    let entity = get_entity!(data, self.entity_id);
+    let name = get_name!(data, entity).unwrap().clone();
+    let lc_direction = self.direction.get_lowercase();
+    narrate!(
+      data,
+      entity,
+      format!("You find a way {} that was hidden!", lc_direction),
+      format!("{} finds a way {} that was hidden!", name, lc_direction)
+    );
+    let room = get_entity!(data, self.room_id);
+    set_passage_hidden!(data, room, &self.direction, false);
+    Ok(())
+  }
+
+  fn get_inverse(&self, _data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    Ok(vec![create_effect!(crate::effect::EntitySetPassageHidden {
+      entity_id: self.room_id.into(),
+      direction: self.direction,
+      is_hidden: true,
+    })])
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntityFindsPassage(*self)
+  }
+}
@@ -0,0 +1,42 @@
+use crate::ecs::entity::EntityId;
+use crate::ecs::system::effect_processor::Data as EffectProcessorData;
+use crate::effect::{Effect, EffectRecord, Effectable};
+use anyhow::Error;
+
+/// `EntityGivesOrder`.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GivesOrder {
+  /// The entity giving the order.
+  pub entity_id: EntityId,
+  /// The entity being told what to do.
+  pub target_entity_id: EntityId,
+  /// What they're being told to do, as it was said.
+  pub order: String,
+}
+
+impl Effectable for GivesOrder {
+  fn process(&self, data: &mut EffectProcessorData) -> Result<(), Error> {
+    let entity = get_entity!(data, self.entity_id);
+    let target_entity = get_entity!(data, self.target_entity_id);
+    let actor_name = get_name!(data, entity).unwrap().clone();
+    let lc_name = get_lc_name!(data, target_entity).unwrap();
+    narrate!(
+      data,
+      entity,
+      target_entity,
+      format!("You tell {} to {}.", lc_name, self.order),
+      format!("{} tells you to {}.", actor_name, self.order),
+      format!("{} tells {} to {}.", actor_name, lc_name, self.order)
+    );
+    Ok(())
+  }
+
+  fn get_inverse(&self, _data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    // Words can't be unsaid, but they don't change anything by themselves.
+    Ok(vec![])
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntityGivesOrder(self.clone())
+  }
+}
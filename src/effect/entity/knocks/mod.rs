@@ -0,0 +1,45 @@
+use crate::ecs::entity::EntityId;
+use crate::ecs::system::effect_processor::Data as EffectProcessorData;
+use crate::effect::{Effect, EffectRecord, Effectable};
+use anyhow::Error;
+
+/// `EntityKnocksOnDoor`.
+///
+/// Anyone on the other side hears it, though they can't see who it is.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Knocks {
+  /// The entity performing the action.
+  pub entity_id: EntityId,
+  /// The door.
+  pub target_entity_id: EntityId,
+}
+
+impl Effectable for Knocks {
+  fn process(&self, data: &mut EffectProcessorData) -> Result<(), Error> {
+    let entity = get_entity!(data, self.entity_id);
+    let target_entity = get_entity!(data, self.target_entity_id);
+    let name = get_name!(data, entity).unwrap().clone();
+    let lc_name = get_lc_name!(data, target_entity).unwrap();
+    narrate!(
+      data,
+      entity,
+      format!("You knock on the {}.", lc_name),
+      format!("{} knocks on the {}.", name, lc_name)
+    );
+    narrate_beyond_door!(
+      data,
+      target_entity,
+      get_current_room_id!(data, entity),
+      |direction| format!("Someone knocks on the {} to the {}.", lc_name, direction)
+    );
+    Ok(())
+  }
+
+  fn get_inverse(&self, _data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    Ok(vec![])
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntityKnocksOnDoor(*self)
+  }
+}
@@ -1,6 +1,7 @@
 use crate::ecs::entity::EntityId;
 use crate::ecs::system::effect_processor::Data;
-use crate::effect::Effectable;
+use crate::effect::{Effect, EffectRecord, Effectable};
+use crate::output::Verbosity;
 use anyhow::Error;
 
 /// `EntityLooksAround`.
@@ -8,6 +9,9 @@ use anyhow::Error;
 pub struct LooksAround {
   /// The entity performing the action.
   pub entity_id: EntityId,
+  /// Whether to describe the room in full, however brief the entity would
+  /// like its descriptions, e.g. because it looked around on purpose.
+  pub verbose: bool,
 }
 
 impl Effectable for LooksAround {
@@ -17,9 +21,65 @@ impl Effectable for LooksAround {
     let room = get_entity!(data, room_id);
     let name = get_name!(data, entity).unwrap();
     they!(data, entity, format!("{} looks around.", name));
-    show!(data, entity, format_room!(data, room));
+    let brief =
+      !self.verbose && get_verbosity!(data, entity) == Verbosity::Brief && has_visited_room!(data, entity, room_id);
+    // Visits aren't undone, any more than the fog lifted from the map is.
+    visit_room!(data, entity, room_id);
+    show!(data, entity, format_room!(data, room, brief));
     reset_state!(data, entity, 1);
     reset_state!(data, entity, 0);
     Ok(())
   }
+
+  fn get_inverse(&self, data: &mut Data) -> Result<Vec<Effect>, Error> {
+    Ok(get_set_state_inverse!(data, self.entity_id).into_iter().collect())
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntityLooksAround(*self)
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::ecs::component::HasVerbosity;
+  use crate::game::Headless;
+  use crate::test::*;
+  use specs::prelude::*;
+
+  #[test]
+  pub fn test_brief_descriptions() {
+    init();
+    let mut headless = compass_rose_headless();
+    {
+      let player = get_player(&headless);
+      let ecs = &mut headless.game.ecs;
+      ecs
+        .write_storage::<HasVerbosity>()
+        .insert(player, HasVerbosity(Verbosity::Brief))
+        .unwrap();
+    }
+    let count = |headless: &Headless| {
+      headless
+        .transcript
+        .iter()
+        .filter(|line| line.contains("Dark olive trees"))
+        .count()
+    };
+    let first_visit = count(&headless);
+    assert_eq!(first_visit, 1);
+    // Coming back to the spawn room shouldn't describe it again...
+    headless.input("n");
+    headless.input("s");
+    assert_eq!(count(&headless), first_visit);
+    let returned = headless.transcript.iter().rposition(|line| line == "> s").unwrap();
+    assert!(headless.transcript[returned..]
+      .iter()
+      .any(|line| line.starts_with("Spawn Room")));
+    // ...unless we ask.
+    headless.input("look");
+    assert_eq!(count(&headless), first_visit + 1);
+  }
 }
@@ -1,6 +1,8 @@
+use crate::containment::Containment;
 use crate::ecs::entity::EntityId;
 use crate::ecs::system::effect_processor::Data as EffectProcessorData;
-use crate::effect::Effectable;
+use crate::effect::{Effect, EffectRecord, Effectable};
+use crate::input::Preposition;
 use anyhow::Error;
 
 /// `EntityLooksAtEntity`.
@@ -10,18 +12,138 @@ pub struct LooksAtEntity {
   pub entity_id: EntityId,
   /// The target entity.
   pub target_entity_id: EntityId,
+  /// Where the entity is looking, relative to the target, e.g. "under".
+  pub preposition: Preposition,
 }
 
 impl Effectable for LooksAtEntity {
   fn process(&self, data: &mut EffectProcessorData) -> Result<(), Error> {
     let entity = get_entity!(data, self.entity_id);
     let target_entity = get_entity!(data, self.target_entity_id);
-    let actor_name = get_lc_name!(data, entity).unwrap();
+    let actor_name = get_name!(data, entity).unwrap().clone();
     let lc_name = get_lc_name!(data, target_entity).unwrap();
-    you!(data, entity, format!("look at {}...", lc_name));
-    they!(data, entity, format!("{} looks at {}.", actor_name, lc_name));
-    let brief = get_brief_description!(data, target_entity).unwrap().0.clone();
-    show!(data, entity, brief);
+    let preposition = self.preposition;
+    narrate!(
+      data,
+      entity,
+      target_entity,
+      format!("You look {} {}...", preposition, lc_name),
+      format!("{} looks {} you.", actor_name, preposition),
+      format!("{} looks {} {}.", actor_name, preposition, lc_name)
+    );
+    let nothing = format!("There's nothing {} {}.", preposition, lc_name);
+    match (preposition, Containment::from_preposition(preposition)) {
+      (Preposition::At, _) => {
+        let brief = get_brief_description!(data, target_entity).unwrap().0.clone();
+        show!(data, entity, brief);
+        if let Some(door) = get_door!(data, target_entity) {
+          show!(data, entity, format!("It is {}.", door.get_lowercase()));
+        }
+        for sentence in describe_contents!(data, target_entity) {
+          show!(data, entity, sentence);
+        }
+      },
+      (_, Some(containment)) => {
+        let content_names = get_contents!(data, target_entity, containment)
+          .into_iter()
+          .filter_map(|content| get_lc_name!(data, content))
+          .collect::<Vec<String>>();
+        match content_names.is_empty() {
+          true => show!(data, entity, nothing),
+          false => show!(data, entity, containment.describe_contents(&lc_name, &content_names)),
+        }
+      },
+      // Nothing can be behind or under anything yet.
+      (_, None) => show!(data, entity, nothing),
+    }
     Ok(())
   }
+
+  fn get_inverse(&self, _data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    // Looking at something doesn't change anything.
+    Ok(vec![])
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntityLooksAtEntity(*self)
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::ecs::component::*;
+  use crate::ecs::event::{OutputEvent, PerceptionEvent};
+  use crate::ecs::resource::*;
+  use crate::ecs::system::effect_processor::process_effect;
+  use crate::ecs::system::run_initial_systems;
+  use crate::game::{Game, GameSettings};
+  use crate::map::MapBuilderKind;
+  use crate::perception::Perspective;
+  use crate::test::*;
+  use specs::prelude::*;
+  use specs::shrev::EventChannel;
+
+  #[test]
+  pub fn test_looks_at_entity() {
+    init();
+    let settings = GameSettings {
+      map_builder: MapBuilderKind::CompassRose,
+      ..GameSettings::default()
+    };
+    let mut game = Game::headless_from_settings(&settings);
+    let ecs = &mut game.ecs;
+    let mut output_reader_id = ecs.fetch_mut::<EventChannel<OutputEvent>>().register_reader();
+    let mut perception_reader_id = ecs.fetch_mut::<EventChannel<PerceptionEvent>>().register_reader();
+    run_initial_systems(ecs);
+    let player_id = EntityId::from(ecs.read_resource::<PlayerResource>().0.unwrap());
+    let goblin_id = (&ecs.entities(), &ecs.read_storage::<HasAi>())
+      .join()
+      .map(|(entity, _)| EntityId(entity.id()))
+      .next()
+      .unwrap();
+    let effect = create_effect!(LooksAtEntity {
+      entity_id: player_id,
+      target_entity_id: goblin_id,
+      preposition: Preposition::At,
+    });
+    process_effect(&effect, &mut ecs.system_data()).unwrap();
+    // The player is shown their own side of it.
+    let output = ecs
+      .read_resource::<EventChannel<OutputEvent>>()
+      .read(&mut output_reader_id)
+      .filter(|event| event.recipient == Some(player_id))
+      .map(|event| event.document.to_string())
+      .collect::<Vec<String>>();
+    assert!(output.contains(&"You look at goblin...".to_string()));
+    // The goblin can't see output, but perceives being looked at.
+    let perceptions = ecs
+      .read_resource::<EventChannel<PerceptionEvent>>()
+      .read(&mut perception_reader_id)
+      .map(|event| event.perception.clone())
+      .collect::<Vec<_>>();
+    let goblin_perception = perceptions
+      .iter()
+      .find(|perception| perception.perceiver_id == goblin_id)
+      .unwrap();
+    assert_eq!(goblin_perception.perspective, Perspective::SecondPerson);
+    assert_eq!(goblin_perception.string, "Player looks at you.");
+    let effect = create_effect!(LooksAtEntity {
+      entity_id: player_id,
+      target_entity_id: goblin_id,
+      preposition: Preposition::Under,
+    });
+    process_effect(&effect, &mut ecs.system_data()).unwrap();
+    let output = ecs
+      .read_resource::<EventChannel<OutputEvent>>()
+      .read(&mut output_reader_id)
+      .filter(|event| event.recipient == Some(player_id))
+      .map(|event| event.document.to_string())
+      .collect::<Vec<String>>();
+    assert_eq!(
+      output,
+      vec!["You look under goblin...", "There's nothing under goblin."]
+    );
+  }
 }
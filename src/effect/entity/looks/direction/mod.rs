@@ -1,6 +1,6 @@
 use crate::ecs::entity::EntityId;
 use crate::ecs::system::effect_processor::Data as EffectProcessorData;
-use crate::effect::Effectable;
+use crate::effect::{Effect, EffectRecord, Effectable};
 use crate::map::{Direction, PassageDestination};
 use anyhow::Error;
 
@@ -25,16 +25,13 @@ impl Effectable for LooksDirection {
         Room(destination_id) => {
           let lc_direction = self.direction.get_lowercase();
           let destination_room = get_entity!(data, destination_id);
-          you!(
+          narrate!(
             data,
             entity,
-            format!(
-              "look to the {}...\n{}",
-              lc_direction,
-              format_room!(data, destination_room)
-            )
+            format!("You look to the {}...", lc_direction),
+            format!("{} looks {}.", name, lc_direction)
           );
-          they!(data, entity, format!("{} looks {}.", name, lc_direction));
+          show!(data, entity, format_room!(data, destination_room));
           set_state!(data, entity, 0);
           reset_state!(data, entity, 1);
         },
@@ -43,4 +40,12 @@ impl Effectable for LooksDirection {
     }
     Ok(())
   }
+
+  fn get_inverse(&self, data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    Ok(get_set_state_inverse!(data, self.entity_id).into_iter().collect())
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntityLooksDirection(*self)
+  }
 }
@@ -1,6 +1,40 @@
+pub mod closes;
+pub use closes::Closes as EntityClosesDoor;
+pub mod drops;
+pub use drops::Drops as EntityDropsEntity;
+pub mod finds;
+pub use finds::*;
+pub mod gives_order;
+pub use gives_order::GivesOrder as EntityGivesOrder;
+pub mod knocks;
+pub use knocks::Knocks as EntityKnocksOnDoor;
+pub mod locks;
+pub use locks::Locks as EntityLocksDoor;
 pub mod looks;
 pub use looks::*;
+pub mod opens;
+pub use opens::Opens as EntityOpensDoor;
+pub mod puts;
+pub use puts::Puts as EntityPutsEntity;
+pub mod set_ailment;
+pub use set_ailment::SetAilment as EntitySetAilment;
+pub mod set_container;
+pub use set_container::SetContainer as EntitySetContainer;
+pub mod set_door;
+pub use set_door::SetDoor as EntitySetDoor;
 pub mod set_initiative;
 pub use set_initiative::SetInitiative as EntitySetInitiative;
+pub mod set_passage_hidden;
+pub use set_passage_hidden::SetPassageHidden as EntitySetPassageHidden;
+pub mod set_room;
+pub use set_room::SetRoom as EntitySetRoom;
+pub mod set_state;
+pub use set_state::SetState as EntitySetState;
+pub mod suffers;
+pub use suffers::Suffers as EntitySuffersAilment;
+pub mod takes;
+pub use takes::Takes as EntityTakesEntity;
+pub mod unlocks;
+pub use unlocks::Unlocks as EntityUnlocksDoor;
 pub mod walks;
 pub use walks::*;
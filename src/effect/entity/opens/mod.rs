@@ -0,0 +1,48 @@
+use crate::ecs::entity::EntityId;
+use crate::ecs::system::effect_processor::Data as EffectProcessorData;
+use crate::effect::{Effect, EffectRecord, Effectable};
+use anyhow::Error;
+
+/// `EntityOpensDoor`.
+///
+/// The door swings open, from both sides.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Opens {
+  /// The entity performing the action.
+  pub entity_id: EntityId,
+  /// The door.
+  pub target_entity_id: EntityId,
+}
+
+impl Effectable for Opens {
+  fn process(&self, data: &mut EffectProcessorData) -> Result<(), Error> {
+    let entity = get_entity!(data, self.entity_id);
+    let target_entity = get_entity!(data, self.target_entity_id);
+    let name = get_name!(data, entity).unwrap().clone();
+    let lc_name = get_lc_name!(data, target_entity).unwrap();
+    narrate!(
+      data,
+      entity,
+      format!("You open the {}.", lc_name),
+      format!("{} opens the {}.", name, lc_name)
+    );
+    narrate_beyond_door!(
+      data,
+      target_entity,
+      get_current_room_id!(data, entity),
+      |direction| format!("The {} to the {} opens.", lc_name, direction)
+    );
+    if let Some(is_a_door) = data.is_a_door.get_mut(target_entity) {
+      is_a_door.0.is_open = true;
+    }
+    Ok(())
+  }
+
+  fn get_inverse(&self, data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    Ok(get_set_door_inverse!(data, self.target_entity_id).into_iter().collect())
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntityOpensDoor(*self)
+  }
+}
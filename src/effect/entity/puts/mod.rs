@@ -0,0 +1,57 @@
+use crate::containment::Containment;
+use crate::ecs::entity::EntityId;
+use crate::ecs::system::effect_processor::Data as EffectProcessorData;
+use crate::effect::{Effect, EffectRecord, Effectable};
+use anyhow::Error;
+
+/// `EntityPutsEntity`.
+///
+/// The target is put in or on a container, from wherever it was.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Puts {
+  /// The entity performing the action.
+  pub entity_id: EntityId,
+  /// The entity being put somewhere.
+  pub target_entity_id: EntityId,
+  /// The entity it's put in or on.
+  pub container_entity_id: EntityId,
+  /// Whether it's put in or on the container.
+  pub containment: Containment,
+}
+
+impl Effectable for Puts {
+  fn process(&self, data: &mut EffectProcessorData) -> Result<(), Error> {
+    let entity = get_entity!(data, self.entity_id);
+    let target_entity = get_entity!(data, self.target_entity_id);
+    let container_entity = get_entity!(data, self.container_entity_id);
+    let name = get_name!(data, entity).unwrap().clone();
+    let lc_name = get_lc_name!(data, target_entity).unwrap();
+    let container_lc_name = get_lc_name!(data, container_entity).unwrap();
+    narrate!(
+      data,
+      entity,
+      format!(
+        "You put the {} {} the {}.",
+        lc_name, self.containment, container_lc_name
+      ),
+      format!(
+        "{} puts the {} {} the {}.",
+        name, lc_name, self.containment, container_lc_name
+      )
+    );
+    data.is_in_room.remove(target_entity);
+    is_contained_by!(data, target_entity, self.containment, self.container_entity_id);
+    Ok(())
+  }
+
+  fn get_inverse(&self, data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    Ok(vec![
+      get_set_room_inverse!(data, self.target_entity_id),
+      get_set_container_inverse!(data, self.target_entity_id),
+    ])
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntityPutsEntity(*self)
+  }
+}
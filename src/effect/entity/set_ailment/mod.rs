@@ -0,0 +1,38 @@
+use crate::ailment::Ailment;
+use crate::ecs::entity::EntityId;
+use crate::ecs::system::effect_processor::Data as EffectProcessorData;
+use crate::effect::{Effect, EffectRecord, Effectable};
+use anyhow::Error;
+
+/// `EntitySetAilment`.
+///
+/// This silently afflicts an entity with an ailment, or cures it of one.  It
+/// exists mostly so that other effects can be undone.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct SetAilment {
+  /// The entity whose ailments are being set.
+  pub entity_id: EntityId,
+  /// The ailment.
+  pub ailment: Ailment,
+  /// Whether the entity suffers from it.
+  pub value: bool,
+}
+
+impl Effectable for SetAilment {
+  fn process(&self, data: &mut EffectProcessorData) -> Result<(), Error> {
+    let entity = get_entity!(data, self.entity_id);
+    match self.value {
+      true => has_ailment!(data, entity, self.ailment),
+      false => remove_ailment!(data, entity, self.ailment),
+    }
+    Ok(())
+  }
+
+  fn get_inverse(&self, data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    Ok(vec![get_set_ailment_inverse!(data, self.entity_id, self.ailment)])
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntitySetAilment(*self)
+  }
+}
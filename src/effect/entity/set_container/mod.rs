@@ -0,0 +1,37 @@
+use crate::containment::Containment;
+use crate::ecs::entity::EntityId;
+use crate::ecs::system::effect_processor::Data as EffectProcessorData;
+use crate::effect::{Effect, EffectRecord, Effectable};
+use anyhow::Error;
+
+/// `EntitySetContainer`.
+///
+/// This silently puts an entity in, on, or in the hands of another, or takes
+/// it out of whatever holds it.  Like `EntitySetRoom`, it exists mostly so
+/// that other effects can be undone.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct SetContainer {
+  /// The entity being moved.
+  pub entity_id: EntityId,
+  /// How, and by what, the entity should be held, if at all.
+  pub container: Option<(Containment, EntityId)>,
+}
+
+impl Effectable for SetContainer {
+  fn process(&self, data: &mut EffectProcessorData) -> Result<(), Error> {
+    let entity = get_entity!(data, self.entity_id);
+    match self.container {
+      Some((containment, container_id)) => is_contained_by!(data, entity, containment, container_id),
+      None => remove_is_contained_by!(data, entity),
+    }
+    Ok(())
+  }
+
+  fn get_inverse(&self, data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    Ok(vec![get_set_container_inverse!(data, self.entity_id)])
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntitySetContainer(*self)
+  }
+}
@@ -0,0 +1,35 @@
+use crate::ecs::entity::EntityId;
+use crate::ecs::system::effect_processor::Data as EffectProcessorData;
+use crate::effect::{Effect, EffectRecord, Effectable};
+use crate::map::Door;
+use anyhow::Error;
+
+/// `EntitySetDoor`.
+///
+/// This silently replaces the state of a door.  It exists mostly so that
+/// other effects can be undone.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct SetDoor {
+  /// The door.
+  pub entity_id: EntityId,
+  /// The new state.
+  pub door: Door,
+}
+
+impl Effectable for SetDoor {
+  fn process(&self, data: &mut EffectProcessorData) -> Result<(), Error> {
+    let entity = get_entity!(data, self.entity_id);
+    if let Some(is_a_door) = data.is_a_door.get_mut(entity) {
+      is_a_door.0 = self.door;
+    }
+    Ok(())
+  }
+
+  fn get_inverse(&self, data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    Ok(get_set_door_inverse!(data, self.entity_id).into_iter().collect())
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntitySetDoor(*self)
+  }
+}
@@ -1,6 +1,6 @@
 use crate::ecs::entity::EntityId;
 use crate::ecs::system::effect_processor::Data as EffectProcessorData;
-use crate::effect::Effectable;
+use crate::effect::{Effect, EffectRecord, Effectable};
 use anyhow::Error;
 
 /// `EntitySetInitiative`.
@@ -19,4 +19,17 @@ impl Effectable for SetInitiative {
     has_initiative!(data, entity, self.value, initiative.increment);
     Ok(())
   }
+
+  fn get_inverse(&self, data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    let entity = get_entity!(data, self.entity_id);
+    let initiative = get_has_initiative!(data, entity).unwrap();
+    Ok(vec![create_effect!(SetInitiative {
+      entity_id: self.entity_id,
+      value: initiative.current,
+    })])
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntitySetInitiative(*self)
+  }
 }
@@ -0,0 +1,46 @@
+use crate::ecs::entity::EntityId;
+use crate::ecs::system::effect_processor::Data as EffectProcessorData;
+use crate::effect::{Effect, EffectRecord, Effectable};
+use crate::map::Direction;
+use anyhow::Error;
+
+/// `EntitySetPassageHidden`.
+///
+/// This silently hides or reveals a passage out of a room.  It exists mostly
+/// so that other effects can be undone.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct SetPassageHidden {
+  /// The room the passage leads out of.
+  pub entity_id: EntityId,
+  /// The direction in which the passage leads.
+  pub direction: Direction,
+  /// Whether the passage should be hidden.
+  pub is_hidden: bool,
+}
+
+impl Effectable for SetPassageHidden {
+  fn process(&self, data: &mut EffectProcessorData) -> Result<(), Error> {
+    let room = get_entity!(data, self.entity_id);
+    set_passage_hidden!(data, room, &self.direction, self.is_hidden);
+    Ok(())
+  }
+
+  fn get_inverse(&self, data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    let room = get_entity!(data, self.entity_id);
+    Ok(
+      get_passage_to!(data, room, &self.direction)
+        .map(|passage| {
+          create_effect!(SetPassageHidden {
+            is_hidden: passage.properties.is_hidden,
+            ..*self
+          })
+        })
+        .into_iter()
+        .collect(),
+    )
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntitySetPassageHidden(*self)
+  }
+}
@@ -0,0 +1,38 @@
+use crate::ecs::entity::EntityId;
+use crate::ecs::entity::RoomId;
+use crate::ecs::system::effect_processor::Data as EffectProcessorData;
+use crate::effect::{Effect, EffectRecord, Effectable};
+use anyhow::Error;
+
+/// `EntitySetRoom`.
+///
+/// This silently puts an entity in a room, or takes it out of any room.  It
+/// exists mostly so that other effects can be undone.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct SetRoom {
+  /// The entity being moved.
+  pub entity_id: EntityId,
+  /// The room the entity should be in, if any.
+  pub room_id: Option<RoomId>,
+}
+
+impl Effectable for SetRoom {
+  fn process(&self, data: &mut EffectProcessorData) -> Result<(), Error> {
+    let entity = get_entity!(data, self.entity_id);
+    match self.room_id {
+      Some(room_id) => is_in_room!(data, entity, room_id),
+      None => {
+        data.is_in_room.remove(entity);
+      },
+    }
+    Ok(())
+  }
+
+  fn get_inverse(&self, data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    Ok(vec![get_set_room_inverse!(data, self.entity_id)])
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntitySetRoom(*self)
+  }
+}
@@ -0,0 +1,35 @@
+use crate::ecs::entity::EntityId;
+use crate::ecs::system::effect_processor::Data as EffectProcessorData;
+use crate::effect::{Effect, EffectRecord, Effectable};
+use crate::goap::State;
+use anyhow::Error;
+
+/// `EntitySetState`.
+///
+/// This silently replaces an entity's internal state.  It exists mostly so
+/// that other effects can be undone.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct SetState {
+  /// The entity whose state is being set.
+  pub entity_id: EntityId,
+  /// The new state.
+  pub state: State,
+}
+
+impl Effectable for SetState {
+  fn process(&self, data: &mut EffectProcessorData) -> Result<(), Error> {
+    let entity = get_entity!(data, self.entity_id);
+    if let Some(has_state) = data.has_state.get_mut(entity) {
+      has_state.0 = self.state;
+    }
+    Ok(())
+  }
+
+  fn get_inverse(&self, data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    Ok(get_set_state_inverse!(data, self.entity_id).into_iter().collect())
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntitySetState(*self)
+  }
+}
@@ -0,0 +1,74 @@
+use crate::ailment::Ailment;
+use crate::ecs::entity::EntityId;
+use crate::ecs::system::effect_processor::Data as EffectProcessorData;
+use crate::effect::{Effect, EffectRecord, Effectable};
+use anyhow::Error;
+use inflector::Inflector;
+
+/// `EntitySuffersAilment`.
+///
+/// Something has befallen the entity, e.g. its legs have been broken.  This
+/// is hostile, so whatever the entity was in the middle of is interrupted.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Suffers {
+  /// The entity that is suffering.
+  pub entity_id: EntityId,
+  /// What it's suffering from.
+  pub ailment: Ailment,
+}
+
+impl Effectable for Suffers {
+  fn process(&self, data: &mut EffectProcessorData) -> Result<(), Error> {
+    let entity = get_entity!(data, self.entity_id);
+    has_ailment!(data, entity, self.ailment);
+    show!(data, entity, format!("{}.", self.ailment.describe().to_sentence_case()));
+    Ok(())
+  }
+
+  fn get_inverse(&self, data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    Ok(vec![get_set_ailment_inverse!(data, self.entity_id, self.ailment)])
+  }
+
+  fn get_hostile_target_id(&self) -> Option<EntityId> {
+    Some(self.entity_id)
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntitySuffersAilment(*self)
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::ecs::event::EffectEvent;
+  use crate::test::*;
+  use specs::prelude::*;
+  use specs::shrev::EventChannel;
+
+  #[test]
+  pub fn test_suffering_interrupts() {
+    init();
+    let mut headless = compass_rose_headless();
+    headless.game.write_input("search");
+    headless.advance(10);
+    assert!(headless.game.has_actions_in_progress());
+    let entity_id = EntityId(get_player(&headless).id());
+    headless
+      .game
+      .ecs
+      .write_resource::<EventChannel<EffectEvent>>()
+      .single_write(EffectEvent {
+        effect: create_effect!(Suffers {
+          entity_id,
+          ailment: Ailment::HandsBroken,
+        }),
+      });
+    headless.input("wait 10");
+    assert_response_contains(&headless, "Your hands are broken.");
+    assert_response_contains(&headless, "You stop trying to search the area.");
+    assert_response_lacks(&headless, "You find");
+    assert!(!headless.game.has_actions_in_progress());
+  }
+}
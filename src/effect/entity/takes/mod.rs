@@ -0,0 +1,46 @@
+use crate::containment::Containment;
+use crate::ecs::entity::EntityId;
+use crate::ecs::system::effect_processor::Data as EffectProcessorData;
+use crate::effect::{Effect, EffectRecord, Effectable};
+use anyhow::Error;
+
+/// `EntityTakesEntity`.
+///
+/// The target is picked up from wherever it was, whether in the room or in or
+/// on something else, and carried.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Takes {
+  /// The entity performing the action.
+  pub entity_id: EntityId,
+  /// The entity being taken.
+  pub target_entity_id: EntityId,
+}
+
+impl Effectable for Takes {
+  fn process(&self, data: &mut EffectProcessorData) -> Result<(), Error> {
+    let entity = get_entity!(data, self.entity_id);
+    let target_entity = get_entity!(data, self.target_entity_id);
+    let name = get_name!(data, entity).unwrap().clone();
+    let lc_name = get_lc_name!(data, target_entity).unwrap();
+    narrate!(
+      data,
+      entity,
+      format!("You take the {}.", lc_name),
+      format!("{} takes the {}.", name, lc_name)
+    );
+    data.is_in_room.remove(target_entity);
+    is_contained_by!(data, target_entity, Containment::CarriedBy, self.entity_id);
+    Ok(())
+  }
+
+  fn get_inverse(&self, data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    Ok(vec![
+      get_set_room_inverse!(data, self.target_entity_id),
+      get_set_container_inverse!(data, self.target_entity_id),
+    ])
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntityTakesEntity(*self)
+  }
+}
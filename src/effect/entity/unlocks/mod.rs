@@ -0,0 +1,52 @@
+use crate::ecs::entity::EntityId;
+use crate::ecs::system::effect_processor::Data as EffectProcessorData;
+use crate::effect::{Effect, EffectRecord, Effectable};
+use anyhow::Error;
+
+/// `EntityUnlocksDoor`.
+///
+/// The door is unlocked with its key.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Unlocks {
+  /// The entity performing the action.
+  pub entity_id: EntityId,
+  /// The door.
+  pub target_entity_id: EntityId,
+  /// The key that fits its lock.
+  pub key_entity_id: EntityId,
+}
+
+impl Effectable for Unlocks {
+  fn process(&self, data: &mut EffectProcessorData) -> Result<(), Error> {
+    let entity = get_entity!(data, self.entity_id);
+    let target_entity = get_entity!(data, self.target_entity_id);
+    let key_entity = get_entity!(data, self.key_entity_id);
+    let name = get_name!(data, entity).unwrap().clone();
+    let lc_name = get_lc_name!(data, target_entity).unwrap();
+    let key_lc_name = get_lc_name!(data, key_entity).unwrap();
+    narrate!(
+      data,
+      entity,
+      format!("You unlock the {} with the {}.", lc_name, key_lc_name),
+      format!("{} unlocks the {} with the {}.", name, lc_name, key_lc_name)
+    );
+    narrate_beyond_door!(
+      data,
+      target_entity,
+      get_current_room_id!(data, entity),
+      |direction| format!("There is a click from the {} to the {}.", lc_name, direction)
+    );
+    if let Some(is_a_door) = data.is_a_door.get_mut(target_entity) {
+      is_a_door.0.is_locked = false;
+    }
+    Ok(())
+  }
+
+  fn get_inverse(&self, data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    Ok(get_set_door_inverse!(data, self.target_entity_id).into_iter().collect())
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntityUnlocksDoor(*self)
+  }
+}
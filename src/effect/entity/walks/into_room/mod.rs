@@ -1,7 +1,7 @@
 use crate::ecs::entity::EntityId;
 use crate::ecs::entity::RoomId;
 use crate::ecs::system::effect_processor::Data as EffectProcessorData;
-use crate::effect::Effectable;
+use crate::effect::{Effect, EffectRecord, Effectable};
 use crate::map::Direction;
 use anyhow::Error;
 
@@ -30,4 +30,14 @@ impl Effectable for IntoRoom {
     set_state!(data, entity, 1);
     Ok(())
   }
+
+  fn get_inverse(&self, data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    let mut result = vec![get_set_room_inverse!(data, self.entity_id)];
+    result.extend(get_set_state_inverse!(data, self.entity_id));
+    Ok(result)
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntityWalksIntoRoom(*self)
+  }
 }
@@ -1,7 +1,7 @@
 use crate::ecs::entity::EntityId;
 use crate::ecs::entity::RoomId;
 use crate::ecs::system::effect_processor::Data as EffectProcessorData;
-use crate::effect::Effectable;
+use crate::effect::{Effect, EffectRecord, Effectable};
 use crate::map::Direction;
 use anyhow::Error;
 
@@ -25,7 +25,20 @@ impl Effectable for OutOfRoom {
       entity,
       format!("{} walks out to the {}.", name, self.direction.get_lowercase())
     );
+    // Some ways are worth remarking on to whoever takes them.
+    let room = get_entity!(data, self.room_id);
+    if let Some(message) = get_passage_to!(data, room, &self.direction).and_then(|passage| passage.properties.message) {
+      show!(data, entity, message);
+    }
     remove_is_in_room!(data, entity);
     Ok(())
   }
+
+  fn get_inverse(&self, data: &mut EffectProcessorData) -> Result<Vec<Effect>, Error> {
+    Ok(vec![get_set_room_inverse!(data, self.entity_id)])
+  }
+
+  fn get_record(&self) -> EffectRecord {
+    EffectRecord::EntityWalksOutOfRoom(*self)
+  }
 }
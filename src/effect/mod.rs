@@ -1,3 +1,4 @@
+use crate::ecs::entity::EntityId;
 use crate::ecs::system::effect_processor::Data;
 use anyhow::Error;
 use std::sync::Arc;
@@ -6,6 +7,8 @@ pub mod _trait;
 pub use _trait::*;
 pub mod entity;
 pub use entity::*;
+pub mod record;
+pub use record::Record as EffectRecord;
 
 /// The `Effect` enum.
 ///
@@ -56,4 +59,19 @@ impl Effectable for Effect {
   fn process(&self, data: &mut Data) -> Result<(), Error> {
     (*self.0).process(data)
   }
+
+  /// Calculate the effects that would undo this one.
+  fn get_inverse(&self, data: &mut Data) -> Result<Vec<Effect>, Error> {
+    (*self.0).get_inverse(data)
+  }
+
+  /// The entity this effect is hostile to, if any.
+  fn get_hostile_target_id(&self) -> Option<EntityId> {
+    (*self.0).get_hostile_target_id()
+  }
+
+  /// Get a serializable record of this effect.
+  fn get_record(&self) -> EffectRecord {
+    (*self.0).get_record()
+  }
 }
@@ -0,0 +1,67 @@
+use super::*;
+
+/// The `Record` enum.
+///
+/// `Effect` wraps a trait object, which can't be serialized.  This holds the
+/// same information in a form that can be written to a journal and turned
+/// back into an `Effect` later.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum Record {
+  EntityClosesDoor(EntityClosesDoor),
+  EntityDropsEntity(EntityDropsEntity),
+  EntityFindsNothing(EntityFindsNothing),
+  EntityFindsPassage(EntityFindsPassage),
+  EntityGivesOrder(EntityGivesOrder),
+  EntityKnocksOnDoor(EntityKnocksOnDoor),
+  EntityLocksDoor(EntityLocksDoor),
+  EntityLooksAround(EntityLooksAround),
+  EntityLooksAtEntity(EntityLooksAtEntity),
+  EntityLooksDirection(EntityLooksDirection),
+  EntityOpensDoor(EntityOpensDoor),
+  EntityPutsEntity(EntityPutsEntity),
+  EntitySetAilment(EntitySetAilment),
+  EntitySetContainer(EntitySetContainer),
+  EntitySetDoor(EntitySetDoor),
+  EntitySetPassageHidden(EntitySetPassageHidden),
+  EntitySetInitiative(EntitySetInitiative),
+  EntitySetRoom(EntitySetRoom),
+  EntitySetState(EntitySetState),
+  EntitySuffersAilment(EntitySuffersAilment),
+  EntityTakesEntity(EntityTakesEntity),
+  EntityUnlocksDoor(EntityUnlocksDoor),
+  EntityWalksIntoRoom(EntityWalksIntoRoom),
+  EntityWalksOutOfRoom(EntityWalksOutOfRoom),
+}
+
+impl Record {
+  /// Turn this record back into an effect.
+  pub fn get_effect(&self) -> Effect {
+    use Record::*;
+    match self.clone() {
+      EntityClosesDoor(effect) => create_effect!(effect),
+      EntityDropsEntity(effect) => create_effect!(effect),
+      EntityFindsNothing(effect) => create_effect!(effect),
+      EntityFindsPassage(effect) => create_effect!(effect),
+      EntityGivesOrder(effect) => create_effect!(effect),
+      EntityKnocksOnDoor(effect) => create_effect!(effect),
+      EntityLocksDoor(effect) => create_effect!(effect),
+      EntityLooksAround(effect) => create_effect!(effect),
+      EntityLooksAtEntity(effect) => create_effect!(effect),
+      EntityLooksDirection(effect) => create_effect!(effect),
+      EntityOpensDoor(effect) => create_effect!(effect),
+      EntityPutsEntity(effect) => create_effect!(effect),
+      EntitySetAilment(effect) => create_effect!(effect),
+      EntitySetContainer(effect) => create_effect!(effect),
+      EntitySetDoor(effect) => create_effect!(effect),
+      EntitySetPassageHidden(effect) => create_effect!(effect),
+      EntitySetInitiative(effect) => create_effect!(effect),
+      EntitySetRoom(effect) => create_effect!(effect),
+      EntitySetState(effect) => create_effect!(effect),
+      EntitySuffersAilment(effect) => create_effect!(effect),
+      EntityTakesEntity(effect) => create_effect!(effect),
+      EntityUnlocksDoor(effect) => create_effect!(effect),
+      EntityWalksIntoRoom(effect) => create_effect!(effect),
+      EntityWalksOutOfRoom(effect) => create_effect!(effect),
+    }
+  }
+}
@@ -1,3 +1,14 @@
 /// The game should tick every few milliseconds.
 /// 16.67 = 60FPS, not that we really operate on FPS.
 pub const TICK_INTERVAL: u64 = 16;
+
+/// How many ticks a headless game advances after each line of input.
+/// Input, commands, actions, and effects are all processed every ten ticks.
+pub const HEADLESS_TICKS_PER_INPUT: u64 = 10;
+
+/// How many ticks a turn lasts in turn-based mode.
+pub const TICKS_PER_TURN: u64 = 100;
+
+/// How many rounds of queued commands and actions in progress a single line
+/// of input can set off in a headless game before it's given up on.
+pub const HEADLESS_MAX_ROUNDS_PER_INPUT: usize = 1_000;
@@ -0,0 +1,97 @@
+use super::Game;
+use crate::ecs::component::{HasActionInProgress, HasCommandQueue, IsAPlayer};
+use crate::ecs::resource::TimeScaleResource;
+use specs::prelude::*;
+
+impl Game {
+  /// Advance the world as the time scale dictates.
+  ///
+  /// This is called each time the timer fires.
+  pub fn advance_clock(&mut self) {
+    let pending_ticks = self.take_pending_ticks();
+    for _ in 0..pending_ticks {
+      self.tick();
+    }
+    let time_scale = (*self.ecs.read_resource::<TimeScaleResource>()).clone();
+    if time_scale.is_running() {
+      // Speeds below 1x only run a tick every so often.
+      self.tick_budget += time_scale.speed;
+      while self.tick_budget >= 1.0 {
+        self.tick_budget -= 1.0;
+        self.tick();
+      }
+    } else if self.has_queued_commands() || self.has_actions_in_progress() {
+      // Carry out the rest of what the player asked for, one round at a time.
+      self.tick();
+    }
+  }
+
+  /// Whether any player has commands still waiting to be carried out.
+  pub fn has_queued_commands(&self) -> bool {
+    self
+      .ecs
+      .read_storage::<HasCommandQueue>()
+      .join()
+      .any(|has_command_queue| !has_command_queue.0.is_empty())
+  }
+
+  /// Whether any player is in the middle of doing something.
+  pub fn has_actions_in_progress(&self) -> bool {
+    (
+      &self.ecs.read_storage::<HasActionInProgress>(),
+      &self.ecs.read_storage::<IsAPlayer>(),
+    )
+      .join()
+      .next()
+      .is_some()
+  }
+
+  /// Make sure a line of input will be processed, even if the clock is not
+  /// running.
+  ///
+  /// Input is processed every ten ticks, so the world advances at least that
+  /// far.  In turn-based mode, anything the player does in the world asks for
+  /// a turn's worth of ticks beyond that when it's carried out; asking for
+  /// help, say, doesn't.
+  pub fn advance_for_input(&mut self) {
+    let mut time_scale = self.ecs.write_resource::<TimeScaleResource>();
+    if time_scale.is_running() {
+      return;
+    }
+    time_scale.pending_ticks += 10 - self.tick % 10;
+  }
+
+  /// Remove and return any ticks that have been requested, e.g. with `wait`.
+  pub fn take_pending_ticks(&mut self) -> u64 {
+    std::mem::take(&mut self.ecs.write_resource::<TimeScaleResource>().pending_ticks)
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::game::_constant::TICKS_PER_TURN;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_turn_based() {
+    init();
+    let mut headless = compass_rose_headless();
+    headless.input("speed turns");
+    let game = &mut headless.game;
+    let enter = |game: &mut Game, line: &str| {
+      let start = game.tick;
+      game.write_input(line);
+      game.advance_for_input();
+      for _ in 0..10 {
+        game.advance_clock();
+      }
+      game.tick - start
+    };
+    // Nothing happens in the world, so the world doesn't move on.
+    assert!(enter(game, "help") <= 10);
+    assert!(enter(game, "inventory") <= 10);
+    assert!(enter(game, "look") >= TICKS_PER_TURN);
+  }
+}
@@ -1,7 +1,10 @@
+use anyhow::Error as AnyError;
 use rustyline_async::ReadlineError;
 use std::error::Error as StdError;
 use std::io::Error as IoError;
 
+use crate::ecs::snapshot::SnapshotError;
+use crate::journal::JournalError;
 use crate::map::DirectionError;
 
 /// Errors encountered in parsing.
@@ -19,4 +22,16 @@ pub enum Error {
   /// A Readline error occurred.
   #[error("a Readline error occurred ({0})")]
   ReadlineError(#[from] ReadlineError),
+  /// A snapshot error occurred.
+  #[error("a snapshot error occurred ({0})")]
+  SnapshotError(#[from] SnapshotError),
+  /// A journal error occurred.
+  #[error("a journal error occurred ({0})")]
+  JournalError(#[from] JournalError),
+  /// A line of input kept the world busy for too long.
+  #[error("the input {0:?} did not settle within {1} rounds")]
+  InputDidNotSettle(String, usize),
+  /// An effect could not be processed.
+  #[error("an effect error occurred ({0})")]
+  EffectError(#[from] AnyError),
 }
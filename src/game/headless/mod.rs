@@ -0,0 +1,306 @@
+use specs::prelude::*;
+use specs::shrev::{EventChannel, ReaderId};
+use std::path::Path;
+
+use crate::ecs::event::OutputEvent;
+use crate::ecs::system::run_initial_systems;
+use crate::journal::Journal;
+
+use super::_constant::{HEADLESS_MAX_ROUNDS_PER_INPUT, HEADLESS_TICKS_PER_INPUT};
+use super::error::Error;
+use super::Game;
+use super::Settings;
+
+/// The `Headless` driver.
+///
+/// This plays a game without a terminal or a wall clock.  Input lines are fed
+/// in from a script, a fixed number of ticks elapse between them, and every
+/// `OutputEvent` is collected into a transcript as plain text rather than
+/// printed.
+///
+/// Given the same seed and the same script, the transcript is the same.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Headless {
+  /// The game being driven.
+  pub game: Game,
+  /// How many ticks elapse after each line of input.
+  pub ticks_per_input: u64,
+  /// Our own reader for the output channel.
+  #[derivative(Debug = "ignore")]
+  pub reader_id: ReaderId<OutputEvent>,
+  /// Everything that has happened so far, input and output alike.
+  pub transcript: Vec<String>,
+}
+
+impl Headless {
+  /// Constructor.
+  pub fn new(seed: &str) -> Self {
+    Self::with_ticks_per_input(seed, HEADLESS_TICKS_PER_INPUT)
+  }
+
+  /// Constructor specifying the number of ticks between lines of input.
+  pub fn with_ticks_per_input(seed: &str, ticks_per_input: u64) -> Self {
+    let game = Game::new_headless(seed);
+    Self::from_game(game, ticks_per_input)
+  }
+
+  /// Constructor specifying the settings for the world.
+  pub fn from_settings(settings: &Settings) -> Self {
+    let game = Game::headless_from_settings(settings);
+    Self::from_game(game, HEADLESS_TICKS_PER_INPUT)
+  }
+
+  /// Drive an existing headless game.
+  ///
+  /// The initial systems are run here, so the game should be fresh.
+  pub fn from_game(mut game: Game, ticks_per_input: u64) -> Self {
+    // Register before anything is written, so we don't miss the first room.
+    let reader_id = game.ecs.fetch_mut::<EventChannel<OutputEvent>>().register_reader();
+    run_initial_systems(&mut game.ecs);
+    let mut result = Self {
+      game,
+      ticks_per_input,
+      reader_id,
+      transcript: Vec::new(),
+    };
+    result.advance(ticks_per_input);
+    result
+  }
+
+  /// Rebuild a session from a journal file.
+  ///
+  /// The world is created from the settings in the journal's header, and the
+  /// recorded effects are then applied in order; no systems are run, so the
+  /// transcript holds exactly what the effects themselves produced.
+  pub fn replay<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+    let (header, entries) = Journal::read_from_file(path)?;
+    let mut game = Game::headless_from_settings(&header.settings);
+    let reader_id = game.ecs.fetch_mut::<EventChannel<OutputEvent>>().register_reader();
+    run_initial_systems(&mut game.ecs);
+    let mut result = Self {
+      game,
+      ticks_per_input: HEADLESS_TICKS_PER_INPUT,
+      reader_id,
+      transcript: Vec::new(),
+    };
+    for entry in entries.iter() {
+      result.game.replay_entry(entry)?;
+      result.collect_output();
+    }
+    Ok(result)
+  }
+
+  /// Advance the game by some number of ticks, collecting any output.
+  pub fn advance(&mut self, ticks: u64) {
+    for _ in 0..ticks {
+      self.game.tick();
+      self.collect_output();
+    }
+  }
+
+  /// Submit a single line of input and let the world respond to it.
+  ///
+  /// This panics if the world never settles; see `try_input()`.
+  pub fn input(&mut self, line: &str) {
+    if let Err(error) = self.try_input(line) {
+      panic!("{}", error);
+    }
+  }
+
+  /// Submit a single line of input and let the world respond to it.
+  ///
+  /// Input that keeps the world busy, e.g. an action that never ends or a
+  /// command that keeps queueing more, is given up on after a while rather
+  /// than left to run forever.
+  pub fn try_input(&mut self, line: &str) -> Result<(), Error> {
+    let line = line.trim();
+    self.transcript.push(format!("> {}", line));
+    self.game.write_input(line);
+    self.advance(self.ticks_per_input);
+    let mut rounds = 0;
+    loop {
+      if rounds >= HEADLESS_MAX_ROUNDS_PER_INPUT {
+        return Err(Error::InputDidNotSettle(line.to_string(), rounds));
+      }
+      rounds += 1;
+      // Carry out the rest of a compound command, e.g. "n then look", and let
+      // anything the player has begun run its course.
+      if self.game.has_queued_commands() || self.game.has_actions_in_progress() {
+        self.advance(self.ticks_per_input.max(1));
+        continue;
+      }
+      // Honor any explicit requests for time to pass, e.g. `wait`.
+      let pending_ticks = self.game.take_pending_ticks();
+      if pending_ticks == 0 {
+        break;
+      }
+      self.advance(pending_ticks);
+    }
+    Ok(())
+  }
+
+  /// Play through a list of input lines and return the full transcript.
+  pub fn run_script(&mut self, lines: &[&str]) -> Vec<String> {
+    for line in lines {
+      self.input(line);
+    }
+    self.transcript.clone()
+  }
+
+  /// Move any pending output into the transcript.
+  fn collect_output(&mut self) {
+    let channel = self.game.ecs.read_resource::<EventChannel<OutputEvent>>();
+    for event in channel.read(&mut self.reader_id) {
+      self.transcript.push(event.document.to_string().trim().to_string());
+    }
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::ecs::component::{HasActionInProgress, IsInRoom};
+  use crate::ecs::entity::RoomId;
+  use crate::ecs::resource::JournalResource;
+  use crate::map::MapBuilderKind;
+  use crate::test::*;
+  use specs::world::Index;
+
+  /// Where everything is, for comparing worlds.
+  pub fn get_rooms(headless: &Headless) -> Vec<(Index, RoomId)> {
+    let ecs = &headless.game.ecs;
+    (&ecs.entities(), &ecs.read_storage::<IsInRoom>())
+      .join()
+      .map(|(entity, is_in_room)| (entity.id(), is_in_room.0))
+      .collect()
+  }
+
+  #[test]
+  pub fn test_headless_is_deterministic() {
+    init();
+    let script = ["look", "n", "s", "look"];
+    let first = Headless::new("goat boy").run_script(&script);
+    let second = Headless::new("goat boy").run_script(&script);
+    assert_eq!(first, second);
+  }
+
+  #[test]
+  pub fn test_headless_records_input() {
+    init();
+    let transcript = Headless::new("goat boy").run_script(&["look"]);
+    assert!(transcript.iter().any(|line| line == "> look"));
+    // The spawn room is described before any input and again after `look`.
+    let first_input = transcript.iter().position(|line| line == "> look").unwrap();
+    assert!(first_input > 0);
+    assert!(transcript.len() > first_input + 1);
+  }
+
+  /// Play a script through and compare the transcript to a golden copy.
+  pub fn assert_transcript(map_builder: MapBuilderKind, script: &[&str], expected: &str) {
+    let settings = Settings {
+      map_builder,
+      ..Settings::default()
+    };
+    let transcript = Headless::from_settings(&settings).run_script(script).join("\n");
+    // Drawn maps are padded out with spaces, which aren't worth keeping.
+    let actual = transcript.lines().map(str::trim_end).collect::<Vec<_>>();
+    assert_eq!(actual, expected.lines().collect::<Vec<_>>());
+  }
+
+  #[test]
+  pub fn test_headless_compass_rose() {
+    init();
+    let script = [
+      "look",
+      "n",
+      "s",
+      "take the mushroom",
+      "inventory",
+      "put the mushroom in the basket",
+    ];
+    let expected = include_str!("../../_data/transcript/compass_rose.txt");
+    assert_transcript(MapBuilderKind::CompassRose, &script, expected);
+  }
+
+  #[test]
+  pub fn test_headless_trivial_maze() {
+    init();
+    let script = ["look", "n", "s", "look"];
+    let expected = include_str!("../../_data/transcript/trivial_maze.txt");
+    assert_transcript(MapBuilderKind::TrivialMaze, &script, expected);
+  }
+
+  #[test]
+  pub fn test_headless_gives_up() {
+    init();
+    let mut headless = compass_rose_headless();
+    // Begin searching, and then keep at it forever.
+    headless.game.write_input("search");
+    headless.advance(headless.ticks_per_input);
+    let player = get_player(&headless);
+    let mut has_action_in_progress = headless.game.ecs.write_storage::<HasActionInProgress>();
+    has_action_in_progress.get_mut(player).unwrap().0.duration = usize::MAX;
+    drop(has_action_in_progress);
+    assert!(matches!(
+      headless.try_input("look"),
+      Err(Error::InputDidNotSettle(_, HEADLESS_MAX_ROUNDS_PER_INPUT))
+    ));
+  }
+
+  #[test]
+  pub fn test_headless_undo() {
+    init();
+    let settings = Settings {
+      map_builder: MapBuilderKind::CompassRose,
+      ..Settings::default()
+    };
+    let mut headless = Headless::from_settings(&settings);
+    let before = get_rooms(&headless);
+    headless.input("n");
+    assert_ne!(get_rooms(&headless), before);
+    headless.input("undo");
+    assert_eq!(get_rooms(&headless), before);
+    headless.input("undo");
+    assert!(headless
+      .transcript
+      .iter()
+      .any(|line| line == "There is nothing to undo."));
+  }
+
+  #[test]
+  pub fn test_headless_replay() {
+    init();
+    let path = std::env::temp_dir().join("hornvale-test-headless-replay.journal");
+    let settings = Settings {
+      map_builder: MapBuilderKind::CompassRose,
+      ..Settings::default()
+    };
+    let mut original = Headless::from_settings(&settings);
+    original.game.record_journal(&path).unwrap();
+    original.run_script(&["n", "s", "e", "undo", "look"]);
+    // What's been written out isn't kept in memory as well.
+    assert!(original
+      .game
+      .ecs
+      .read_resource::<JournalResource>()
+      .0
+      .entries
+      .is_empty());
+    let replayed = Headless::replay(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(get_rooms(&replayed), get_rooms(&original));
+    assert!(replayed.transcript.iter().any(|line| line.contains("Spawn Room")));
+  }
+
+  #[test]
+  pub fn test_headless_wait() {
+    init();
+    let mut headless = Headless::new("goat boy");
+    let before = headless.game.tick;
+    headless.input("wait 50");
+    assert!(headless.game.tick >= before + HEADLESS_TICKS_PER_INPUT + 50);
+    assert!(headless.transcript.iter().any(|line| line == "Time passes..."));
+  }
+}
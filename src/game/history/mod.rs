@@ -0,0 +1,65 @@
+use specs::prelude::*;
+use std::path::Path;
+
+use super::error::Error;
+use super::Game;
+use crate::ecs::resource::InputResource;
+use crate::ecs::system::input_processor::{Data as InputProcessorData, PlayerData};
+use crate::input::{get_completions, InputHistory};
+
+impl Game {
+  /// Start keeping the input history in a file.
+  ///
+  /// Whatever was entered in earlier sessions can be recalled at the prompt
+  /// right away; after that, each line is appended as it's entered.
+  pub fn record_history<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Error> {
+    let history = InputHistory::open(path)?;
+    if let Some(readline) = self.ecs.write_resource::<InputResource>().0.as_mut() {
+      for entry in history.entries.iter() {
+        readline.add_history_entry(entry.clone());
+      }
+    }
+    self.history = Some(history);
+    Ok(())
+  }
+
+  /// Append a line to the history file, if there is one.
+  pub fn write_history(&mut self, line: &str) {
+    if let Some(history) = self.history.as_mut() {
+      if let Err(error) = history.add(line) {
+        error!("Unable to write to the history ({})", error);
+      }
+    }
+  }
+
+  /// Get the ways the player at the terminal might finish a partial line of
+  /// input, e.g. "look at goblin" for "look at gob".
+  ///
+  /// This draws on the same vocabulary as the parser, so it only offers what
+  /// the player could refer to right now.
+  pub fn complete(&self, line: &str) -> Vec<String> {
+    let data = self.ecs.system_data::<InputProcessorData>();
+    let player_id = match data.player_resource.0 {
+      Some(player_id) => player_id,
+      None => return vec![],
+    };
+    let player_data = PlayerData { data: &data, player_id };
+    get_completions(line, &player_data).unwrap_or_default()
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::test::*;
+
+  #[test]
+  pub fn test_complete() {
+    init();
+    let headless = compass_rose_headless();
+    let game = &headless.game;
+    assert_eq!(game.complete("go northe"), vec!["go northeast"]);
+    assert_eq!(game.complete("look at gob"), vec!["look at goblin"]);
+    assert!(game.complete("look at dragon").is_empty());
+  }
+}
@@ -0,0 +1,82 @@
+use specs::prelude::*;
+use std::path::Path;
+
+use super::error::Error;
+use super::Game;
+use crate::ecs::resource::{JournalResource, TickResource};
+use crate::ecs::system::effect_processor::{process_effect, Data as EffectProcessorData};
+use crate::effect::Effectable;
+use crate::journal::{JournalEntry, JournalWriter};
+
+impl Game {
+  /// Start writing the journal to a file.
+  ///
+  /// Anything already in the journal is written immediately; after that, new
+  /// entries are appended after every tick.
+  pub fn record_journal<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Error> {
+    let mut writer = JournalWriter::new(path, &self.settings)?;
+    writer.write_new_entries(&mut self.ecs.write_resource::<JournalResource>().0)?;
+    self.journal_writer = Some(writer);
+    Ok(())
+  }
+
+  /// Append any new entries to the journal file, if there is one.
+  pub fn write_journal(&mut self) {
+    if let Some(writer) = self.journal_writer.as_mut() {
+      let mut journal = self.ecs.write_resource::<JournalResource>();
+      if let Err(error) = writer.write_new_entries(&mut journal.0) {
+        error!("Unable to write to the journal ({})", error);
+      }
+    }
+  }
+
+  /// Undo the most recent player turn.
+  ///
+  /// Returns false if there was nothing to undo.
+  pub fn undo(&mut self) -> bool {
+    let tick = self.ecs.read_resource::<TickResource>().0;
+    let inverses = self.ecs.write_resource::<JournalResource>().0.undo(tick);
+    match inverses {
+      Some(effects) => {
+        let mut data = self.ecs.system_data::<EffectProcessorData>();
+        for effect in effects.iter() {
+          if let Err(error) = effect.process(&mut data) {
+            error!("Unable to undo {:?} ({})", effect, error);
+          }
+        }
+        true
+      },
+      None => false,
+    }
+  }
+
+  /// Apply an entry from a journal, as though it were happening now.
+  ///
+  /// The entry is recorded in this game's own journal, too, so undo entries
+  /// are replayed just as they happened originally.
+  pub fn replay_entry(&mut self, entry: &JournalEntry) -> Result<(), Error> {
+    use JournalEntry::*;
+    match entry {
+      Turn { tick } => {
+        self.set_tick(*tick);
+        self.ecs.write_resource::<JournalResource>().0.record_turn(*tick);
+      },
+      Effect { tick, effect } => {
+        self.set_tick(*tick);
+        let mut data = self.ecs.system_data::<EffectProcessorData>();
+        process_effect(&effect.get_effect(), &mut data)?;
+      },
+      Undo { tick } => {
+        self.set_tick(*tick);
+        self.undo();
+      },
+    }
+    Ok(())
+  }
+
+  /// Set the tick, in both the local counter and the world.
+  fn set_tick(&mut self, tick: u64) {
+    self.tick = tick;
+    self.ecs.write_resource::<TickResource>().0 = tick;
+  }
+}
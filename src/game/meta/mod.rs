@@ -0,0 +1,61 @@
+use specs::prelude::*;
+use specs::shrev::EventChannel;
+use std::path::Path;
+
+use super::error::Error;
+use super::Game;
+use crate::ecs::event::MetaEvent;
+use crate::ecs::resource::JournalResource;
+use crate::ecs::snapshot::Snapshot;
+
+impl Game {
+  /// Handle any requests to do something to the game itself.
+  pub fn process_meta_events(&mut self) {
+    let meta_events = self
+      .ecs
+      .read_resource::<EventChannel<MetaEvent>>()
+      .read(&mut self.meta_reader_id)
+      .cloned()
+      .collect::<Vec<MetaEvent>>();
+    for event in meta_events {
+      let string = match event {
+        MetaEvent::Save(path) => match self.save(&path) {
+          Ok(()) => format!("Saved to {}.", path),
+          Err(error) => format!("Unable to save to {} ({}).", path, error),
+        },
+        MetaEvent::Restore(path) => match self.restore(&path) {
+          Ok(()) => format!("Restored from {}.", path),
+          Err(error) => format!("Unable to restore from {} ({}).", path, error),
+        },
+        MetaEvent::Undo => match self.undo() {
+          true => "Undone.".to_string(),
+          false => "There is nothing to undo.".to_string(),
+        },
+        // The server hangs up on the player instead.
+        MetaEvent::Quit(_) => {
+          self.quitting = true;
+          continue;
+        },
+      };
+      self.write_output(&string);
+    }
+  }
+
+  /// Save the world to a file.
+  pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+    Snapshot::from_world(&self.ecs).save_to_file(path)?;
+    Ok(())
+  }
+
+  /// Replace the world with one previously saved to a file.
+  ///
+  /// If the file can't be read, the world is left as it was.
+  pub fn restore<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Error> {
+    let snapshot = Snapshot::load_from_file(path)?;
+    snapshot.restore(&mut self.ecs)?;
+    self.tick = snapshot.tick.0;
+    // The recorded turns refer to a world that no longer exists.
+    self.ecs.write_resource::<JournalResource>().0.forget_turns();
+    Ok(())
+  }
+}
@@ -2,21 +2,35 @@ use async_std::stream;
 use futures::prelude::*;
 use rustyline_async::SharedWriter;
 use specs::prelude::*;
-use specs::shrev::EventChannel;
+use specs::shrev::{EventChannel, ReaderId};
 use std::io::Write;
 use std::time::Duration;
 
 use crate::ecs::component::register_components;
+use crate::ecs::entity::PlayerId;
 use crate::ecs::event::insert_event_channels;
-use crate::ecs::event::InputEvent;
+use crate::ecs::event::{InputEvent, MetaEvent, OutputEvent};
 use crate::ecs::resource::*;
 use crate::ecs::system::*;
+use crate::input::InputHistory;
+use crate::journal::JournalWriter;
 
 pub mod _constant;
 use _constant::*;
+pub mod clock;
 pub mod error;
 use error::Error;
 pub use error::Error as GameError;
+pub mod headless;
+pub use headless::Headless;
+pub mod history;
+pub mod journal;
+pub mod meta;
+pub mod server;
+pub use server::Server;
+pub mod settings;
+pub use settings::Settings as GameSettings;
+use settings::Settings;
 
 /// The `Game` struct.
 #[derive(Derivative)]
@@ -37,26 +51,67 @@ pub struct Game {
   /// Every thousandth tick (roughly).
   #[derivative(Debug = "ignore")]
   pub kilo_tick_dispatcher: Dispatcher<'static, 'static>,
-  /// Raw output channel.
+  /// Raw output channel, if a terminal is attached.
   #[derivative(Debug = "ignore")]
-  pub output: SharedWriter,
+  pub output: Option<SharedWriter>,
+  /// A local tick counter; just for performing less frequent operations.
+  pub tick: u64,
+  /// Fractional ticks owed by the clock, when running slower than 1x.
+  pub tick_budget: f64,
+  /// Our reader for requests to save, restore, etc.
+  #[derivative(Debug = "ignore")]
+  pub meta_reader_id: ReaderId<MetaEvent>,
+  /// The settings with which the world was created.
+  pub settings: Settings,
+  /// Where the journal is being written, if anywhere.
+  #[derivative(Debug = "ignore")]
+  pub journal_writer: Option<JournalWriter>,
+  /// Where the input history is being kept, if anywhere.
+  #[derivative(Debug = "ignore")]
+  pub history: Option<InputHistory>,
+  /// Whether the player has asked to quit.
+  pub quitting: bool,
 }
 
 impl Game {
   /// Initialize ECS.
   pub fn new(seed: &str) -> Self {
+    Self::from_settings(&Settings::new(seed))
+  }
+
+  /// Initialize ECS with the specified settings.
+  pub fn from_settings(settings: &Settings) -> Self {
     let mut ecs = World::new();
-    insert_resources(&mut ecs, seed);
+    insert_resources(&mut ecs, &settings.seed);
+    Self::from_world(ecs, settings)
+  }
+
+  /// Initialize ECS without a terminal.
+  ///
+  /// Input has to be written with `write_input()` and time has to be advanced
+  /// with `tick()`; nothing happens on its own.
+  pub fn new_headless(seed: &str) -> Self {
+    Self::headless_from_settings(&Settings::new(seed))
+  }
+
+  /// Initialize ECS without a terminal, with the specified settings.
+  pub fn headless_from_settings(settings: &Settings) -> Self {
+    let mut ecs = World::new();
+    insert_headless_resources(&mut ecs, &settings.seed);
+    Self::from_world(ecs, settings)
+  }
+
+  /// Finish initializing ECS once the resources are in place.
+  fn from_world(mut ecs: World, settings: &Settings) -> Self {
+    ecs.insert(MapBuilderResource(settings.map_builder));
     insert_event_channels(&mut ecs);
     register_components(&mut ecs);
     let tick_dispatcher = get_tick_dispatcher(&mut ecs);
     let deca_tick_dispatcher = get_deca_tick_dispatcher(&mut ecs);
     let hecto_tick_dispatcher = get_hecto_tick_dispatcher(&mut ecs);
     let kilo_tick_dispatcher = get_kilo_tick_dispatcher(&mut ecs);
-    let output = {
-      let output_resource = ecs.read_resource::<OutputResource>();
-      output_resource.0.as_ref().unwrap().clone()
-    };
+    let output = ecs.read_resource::<OutputResource>().0.clone();
+    let meta_reader_id = ecs.fetch_mut::<EventChannel<MetaEvent>>().register_reader();
     Self {
       ecs,
       tick_dispatcher,
@@ -64,46 +119,94 @@ impl Game {
       hecto_tick_dispatcher,
       kilo_tick_dispatcher,
       output,
+      tick: 0,
+      tick_budget: 0.0,
+      meta_reader_id,
+      settings: settings.clone(),
+      journal_writer: None,
+      history: None,
+      quitting: false,
+    }
+  }
+
+  /// Advance the world by a single tick.
+  pub fn tick(&mut self) {
+    // Each tick, run all of the systems.  We could have multiple
+    // dispatchers, each running a subset of the systems, and scheduled
+    // differently.
+    self.tick_dispatcher.dispatch(&self.ecs);
+    self.tick += 1;
+    if self.tick % 10 == 0 {
+      self.deca_tick_dispatcher.dispatch(&self.ecs);
+      if self.tick % 100 == 0 {
+        self.hecto_tick_dispatcher.dispatch(&self.ecs);
+        if self.tick % 1000 == 0 {
+          self.kilo_tick_dispatcher.dispatch(&self.ecs);
+        }
+      }
     }
+    // Maintain after every tick.  This enables the use of the lazy systems,
+    // which should make it easier to have simple, concise systems.
+    self.ecs.maintain();
+    self.process_meta_events();
+    self.write_journal();
+  }
+
+  /// Write a line of output, as though some system had produced it.
+  pub fn write_output(&mut self, string: &str) {
+    self
+      .ecs
+      .write_resource::<EventChannel<OutputEvent>>()
+      .single_write(OutputEvent {
+        recipient: None,
+        document: string.into(),
+      });
+  }
+
+  /// Submit a line of input, as though the player had typed it.
+  pub fn write_input(&mut self, line: &str) {
+    // We could write "input" in other places.  This might be a way
+    // (however unsophisticated) of building macros into the UI.
+    self
+      .ecs
+      .write_resource::<EventChannel<InputEvent>>()
+      .single_write(InputEvent {
+        player_id: None,
+        input: line.to_owned(),
+      });
+  }
+
+  /// Submit a line of input on behalf of a specific player.
+  pub fn write_player_input(&mut self, player_id: PlayerId, line: &str) {
+    self
+      .ecs
+      .write_resource::<EventChannel<InputEvent>>()
+      .single_write(InputEvent {
+        player_id: Some(player_id),
+        input: line.to_owned(),
+      });
   }
 
   /// Run.
   pub async fn run(&mut self) -> Result<(), Error> {
     run_initial_systems(&mut self.ecs);
     // If we need to print without sending it through the whole thing.
-    let mut stdout = self.output.clone();
-    // It'd be interesting to store this in a resource and possibly modify it
-    // on the fly.  Very FRP.  Much signal.
+    let mut stdout = self.output.clone().expect("a headless game can't be run interactively");
+    // This is how we read input.  Probably move to a prompt system?  Or not?
+    let mut stdin = self
+      .ecs
+      .write_resource::<InputResource>()
+      .0
+      .take()
+      .expect("a headless game can't be run interactively");
+    // The timer runs at a fixed rate; the `TimeScale` resource decides how
+    // many ticks each firing is worth.
     let mut tick_timer = stream::interval(Duration::from_millis(TICK_INTERVAL));
-    // A local tick counter; just for performing less frequent operations.
-    let mut tick: u64 = 0;
     // Main game loop, such as it is.
     loop {
-      // Maintain after every tick.  This enables the use of the lazy systems,
-      // which should make it easier to have simple, concise systems.
-      self.ecs.maintain();
-      // This is how we read input.
-      let mut input_resource = self.ecs.write_resource::<InputResource>();
-      // Probably move to a prompt system?  Or not?  IDK.
-      let stdin = input_resource.0.as_mut().unwrap();
       // Select the next future to complete.
       futures::select! {
-        _ = tick_timer.next().fuse() => {
-          // Each tick, run all of the systems.  We could have multiple
-          // dispatchers, each running a subset of the systems, and scheduled
-          // differently.
-          self.tick_dispatcher.dispatch(&self.ecs);
-          tick += 1;
-          if tick % 10 == 0 {
-            self.deca_tick_dispatcher.dispatch(&self.ecs);
-            if tick % 100 == 0 {
-              self.hecto_tick_dispatcher.dispatch(&self.ecs);
-              if tick % 1000 == 0 {
-                self.kilo_tick_dispatcher.dispatch(&self.ecs);
-              }
-            }
-          }
-        }
+        _ = tick_timer.next().fuse() => self.advance_clock(),
         command = stdin.readline().fuse() => match command {
           Ok(line) => {
             // We could conceivably be parsing some commands (like Quit, etc)
@@ -112,13 +215,12 @@ impl Game {
             let line = line.trim();
             writeln!(stdout, "> {}\n", line)?;
             stdin.add_history_entry(line.to_owned());
-            // We could write "input" in other places.  This might be a way
-            // (however unsophisticated) of building macros into the UI.
-            self.ecs
-              .write_resource::<EventChannel<InputEvent>>()
-              .single_write(InputEvent {
-                input: line.to_owned(),
-              });
+            self.write_history(line);
+            self.write_input(line);
+            self.advance_for_input();
+            if self.quitting {
+              return Ok(());
+            }
           },
           Err(error) => return Err(error.into()),
         },
@@ -0,0 +1,99 @@
+use std::collections::VecDeque;
+use std::io::{ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpStream};
+
+use crate::ecs::entity::PlayerId;
+
+/// The `Connection` type.
+///
+/// This is a single client of the server, and the player it controls.  The
+/// socket is non-blocking; input is queued a line at a time and output is
+/// buffered until the socket will take it.
+#[derive(Debug)]
+pub struct Connection {
+  /// The player this connection controls.
+  pub player_id: PlayerId,
+  /// Where the connection is coming from.
+  pub address: SocketAddr,
+  /// Complete lines of input that have yet to be submitted.
+  pub input_queue: VecDeque<String>,
+  /// Whether the connection has been closed, by either side.
+  pub closed: bool,
+  /// The socket itself.
+  stream: TcpStream,
+  /// Input received since the last complete line.
+  incoming: Vec<u8>,
+  /// Output that the socket hasn't accepted yet.
+  outgoing: Vec<u8>,
+}
+
+impl Connection {
+  /// Constructor.
+  pub fn new(stream: TcpStream, address: SocketAddr, player_id: PlayerId) -> Result<Self, std::io::Error> {
+    stream.set_nonblocking(true)?;
+    stream.set_nodelay(true)?;
+    Ok(Self {
+      player_id,
+      address,
+      input_queue: VecDeque::new(),
+      closed: false,
+      stream,
+      incoming: Vec::new(),
+      outgoing: Vec::new(),
+    })
+  }
+
+  /// Read whatever has arrived, queueing any complete lines.
+  pub fn receive(&mut self) {
+    let mut buffer = [0; 1024];
+    loop {
+      match self.stream.read(&mut buffer) {
+        Ok(0) => {
+          self.closed = true;
+          break;
+        },
+        Ok(count) => self.incoming.extend_from_slice(&buffer[..count]),
+        Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+        Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+        Err(error) => {
+          debug!("Closing connection from {} ({})", self.address, error);
+          self.closed = true;
+          break;
+        },
+      }
+    }
+    while let Some(position) = self.incoming.iter().position(|byte| *byte == b'\n') {
+      let line = self.incoming.drain(..=position).collect::<Vec<u8>>();
+      let line = String::from_utf8_lossy(&line).trim().to_string();
+      self.input_queue.push_back(line);
+    }
+  }
+
+  /// Queue a string to be sent, followed by a blank line.
+  pub fn send(&mut self, string: &str) {
+    self.outgoing.extend_from_slice(string.as_bytes());
+    self.outgoing.extend_from_slice(b"\r\n\r\n");
+  }
+
+  /// Write as much pending output as the socket will take.
+  pub fn flush(&mut self) {
+    while !self.outgoing.is_empty() {
+      match self.stream.write(&self.outgoing) {
+        Ok(0) => {
+          self.closed = true;
+          break;
+        },
+        Ok(count) => {
+          self.outgoing.drain(..count);
+        },
+        Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+        Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+        Err(error) => {
+          debug!("Closing connection from {} ({})", self.address, error);
+          self.closed = true;
+          break;
+        },
+      }
+    }
+  }
+}
@@ -0,0 +1,408 @@
+use specs::prelude::*;
+use specs::shrev::{EventChannel, ReaderId};
+use std::io::ErrorKind;
+use std::net::{SocketAddr, TcpListener, ToSocketAddrs};
+use std::thread::sleep;
+use std::time::Duration;
+
+use crate::ecs::component::*;
+use crate::ecs::entity::{EntityId, PlayerId};
+use crate::ecs::event::{EffectEvent, MetaEvent, OutputEvent};
+use crate::ecs::resource::*;
+use crate::ecs::system::create_player::CreatePlayerData;
+use crate::ecs::system::CreateMapSystem;
+use crate::effect::EntityLooksAround;
+use crate::output::{AnsiRenderer, Block, Document, Layout, Renderer, Span, Style};
+
+use super::_constant::TICK_INTERVAL;
+use super::error::Error;
+use super::Game;
+use super::Settings;
+
+pub mod connection;
+pub use connection::Connection;
+
+/// The `Server` driver.
+///
+/// This plays a single game with any number of players connected over TCP,
+/// e.g. with `telnet`.  Each connection gets its own player, with its own
+/// camera and queue of input, and output is sent only to the connections
+/// whose players should see it.
+///
+/// There is no player at the terminal; the world is empty until someone
+/// connects.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Server {
+  /// The game being served.
+  pub game: Game,
+  /// Everyone who's connected.
+  pub connections: Vec<Connection>,
+  /// The socket on which we accept connections.
+  #[derivative(Debug = "ignore")]
+  listener: TcpListener,
+  /// Our own reader for the output channel.
+  #[derivative(Debug = "ignore")]
+  reader_id: ReaderId<OutputEvent>,
+  /// Our own reader for requests to quit.
+  #[derivative(Debug = "ignore")]
+  meta_reader_id: ReaderId<MetaEvent>,
+  /// How many players have connected so far, for naming them.
+  player_count: usize,
+  /// How output is laid out, if at all; we can't see the players' screens,
+  /// so this assumes they're all the same width.
+  pub layout: Option<Layout>,
+  /// How output is turned into text for the connections.
+  #[derivative(Debug = "ignore")]
+  pub renderer: Box<dyn Renderer>,
+}
+
+impl Server {
+  /// Create a world and listen for connections to it.
+  ///
+  /// Bind to port 0 to have the system pick a free port.
+  pub fn bind<A: ToSocketAddrs>(address: A, settings: &Settings) -> Result<Self, Error> {
+    let listener = TcpListener::bind(address)?;
+    listener.set_nonblocking(true)?;
+    let mut game = Game::headless_from_settings(settings);
+    let reader_id = game.ecs.fetch_mut::<EventChannel<OutputEvent>>().register_reader();
+    let meta_reader_id = game.ecs.fetch_mut::<EventChannel<MetaEvent>>().register_reader();
+    // Nobody gets to save, restore, etc the world out from under the others.
+    game.ecs.insert(MultiplayerResource(true));
+    // Only the map; players are created as they connect.
+    (CreateMapSystem {}).run_now(&game.ecs);
+    game.ecs.maintain();
+    Ok(Self {
+      game,
+      connections: Vec::new(),
+      listener,
+      reader_id,
+      meta_reader_id,
+      player_count: 0,
+      layout: Some(Layout::default()),
+      renderer: Box::new(AnsiRenderer),
+    })
+  }
+
+  /// The address on which we're listening.
+  pub fn local_addr(&self) -> Result<SocketAddr, Error> {
+    Ok(self.listener.local_addr()?)
+  }
+
+  /// Serve forever.
+  pub fn run(&mut self) -> Result<(), Error> {
+    loop {
+      self.accept()?;
+      if self.receive() {
+        self.game.advance_for_input();
+      }
+      self.game.advance_clock();
+      self.send();
+      sleep(Duration::from_millis(TICK_INTERVAL));
+    }
+  }
+
+  /// Handle everything that's happened since the last step, then advance the
+  /// world a single tick.
+  pub fn step(&mut self) -> Result<(), Error> {
+    self.accept()?;
+    self.receive();
+    self.game.tick();
+    self.send();
+    Ok(())
+  }
+
+  /// Accept any pending connections, giving each its own player.
+  pub fn accept(&mut self) -> Result<(), Error> {
+    loop {
+      match self.listener.accept() {
+        Ok((stream, address)) => {
+          let player_id = self.spawn_player();
+          let mut connection = Connection::new(stream, address, player_id)?;
+          info!("Accepted connection from {} as player {:?}...", address, player_id);
+          let welcome = Document {
+            blocks: vec![Block::Paragraph(vec![
+              Span::new("Welcome to "),
+              Span::styled("Hornvale", Style::bold()),
+              Span::new("."),
+            ])],
+          };
+          connection.send(&self.render(&welcome));
+          self.connections.push(connection);
+        },
+        Err(error) if error.kind() == ErrorKind::WouldBlock => return Ok(()),
+        Err(error) => return Err(error.into()),
+      }
+    }
+  }
+
+  /// Lay out and render a document for the connections.
+  pub fn render(&self, document: &Document) -> String {
+    match &self.layout {
+      Some(layout) => self.renderer.render(&layout.apply(document)),
+      None => self.renderer.render(document),
+    }
+  }
+
+  /// Submit the next line of input from each connection.
+  ///
+  /// Only one line per connection is taken each time, so that no player can
+  /// flood the world with commands.  Returns whether any input was submitted.
+  pub fn receive(&mut self) -> bool {
+    let mut result = false;
+    for connection in self.connections.iter_mut() {
+      connection.receive();
+      if let Some(line) = connection.input_queue.pop_front() {
+        self.game.write_player_input(connection.player_id, &line);
+        result = true;
+      }
+    }
+    result
+  }
+
+  /// Deliver output to whichever connections should see it, and hang up on
+  /// any that have closed or whose players have quit.
+  pub fn send(&mut self) {
+    let events = self
+      .game
+      .ecs
+      .read_resource::<EventChannel<OutputEvent>>()
+      .read(&mut self.reader_id)
+      .cloned()
+      .collect::<Vec<OutputEvent>>();
+    for event in events.iter() {
+      let string = self.render(&event.document);
+      let string = string.trim();
+      for connection in self.connections.iter_mut() {
+        if event.recipient.is_none() || event.recipient == Some(EntityId::from(connection.player_id)) {
+          connection.send(string);
+        }
+      }
+    }
+    let quitters = self
+      .game
+      .ecs
+      .read_resource::<EventChannel<MetaEvent>>()
+      .read(&mut self.meta_reader_id)
+      .filter_map(|event| match event {
+        MetaEvent::Quit(player_id) => Some(*player_id),
+        _ => None,
+      })
+      .collect::<Vec<PlayerId>>();
+    for connection in self.connections.iter_mut() {
+      if quitters.contains(&connection.player_id) {
+        connection.closed = true;
+      }
+      connection.flush();
+    }
+    let (closed, open): (Vec<Connection>, Vec<Connection>) = std::mem::take(&mut self.connections)
+      .into_iter()
+      .partition(|connection| connection.closed);
+    self.connections = open;
+    for connection in closed.iter() {
+      info!("Connection from {} closed.", connection.address);
+      self.despawn_player(connection.player_id);
+    }
+  }
+
+  /// Create a player for a new connection, in the spawn room.
+  fn spawn_player(&mut self) -> PlayerId {
+    self.player_count += 1;
+    let ecs = &mut self.game.ecs;
+    let spawn_room_id = ecs.read_resource::<SpawnRoomResource>().0;
+    let player = {
+      let mut data = ecs.system_data::<CreatePlayerData>();
+      let player = match spawn_room_id {
+        Some(room_id) => create_player!(data, Gender::Male, room_id),
+        None => create_player!(data, Gender::Male),
+      };
+      has_name!(data, player, format!("Player {}", self.player_count));
+      has_camera!(data, player);
+      player
+    };
+    let entity_id = EntityId(player.id());
+    ecs
+      .write_resource::<EventChannel<EffectEvent>>()
+      .single_write(EffectEvent {
+        effect: create_effect!(EntityLooksAround {
+          entity_id,
+          verbose: false,
+        }),
+      });
+    PlayerId(player.id())
+  }
+
+  /// Remove a departed player from the world.
+  ///
+  /// The body is marked dead and left for the garbage collector, so nothing
+  /// that still refers to it will find it gone out from under it.
+  fn despawn_player(&mut self, player_id: PlayerId) {
+    let ecs = &mut self.game.ecs;
+    let player = ecs.entities().entity(player_id.0);
+    // Whatever they were carrying is left behind where they stood.
+    if let Some(room_id) = ecs.read_storage::<IsInRoom>().get(player).cloned() {
+      let carried = (&ecs.entities(), &ecs.read_storage::<IsContainedBy>())
+        .join()
+        .filter(|(_entity, is_contained_by)| is_contained_by.1 == EntityId::from(player_id))
+        .map(|(entity, _is_contained_by)| entity)
+        .collect::<Vec<Entity>>();
+      for entity in carried {
+        ecs.write_storage::<IsContainedBy>().remove(entity);
+        if let Err(error) = ecs.write_storage::<IsInRoom>().insert(entity, room_id.clone()) {
+          error!("Unable to leave {:?} behind ({})", entity, error);
+        }
+      }
+    }
+    ecs.write_storage::<HasCamera>().remove(player);
+    ecs.write_storage::<IsInRoom>().remove(player);
+    if let Err(error) = ecs.write_storage::<IsDead>().insert(player, IsDead) {
+      error!("Unable to mark departed player {:?} as dead ({})", player_id, error);
+    }
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::map::MapBuilderKind;
+  use crate::test::*;
+  use std::io::{BufRead, BufReader, Write};
+  use std::net::TcpStream;
+  use std::time::Instant;
+
+  /// Step the server until a client has received a line matching `predicate`.
+  pub fn wait_for(server: &mut Server, client: &mut BufReader<TcpStream>, predicate: impl Fn(&str) -> bool) {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    let mut line = String::new();
+    while Instant::now() < deadline {
+      server.step().unwrap();
+      line.clear();
+      match client.read_line(&mut line) {
+        Ok(_) if predicate(&line) => return,
+        Ok(_) => {},
+        Err(error) if error.kind() == ErrorKind::WouldBlock => {},
+        Err(error) => panic!("{}", error),
+      }
+    }
+    panic!("timed out waiting for output");
+  }
+
+  pub fn connect(server: &Server) -> BufReader<TcpStream> {
+    let stream = TcpStream::connect(server.local_addr().unwrap()).unwrap();
+    stream.set_nonblocking(true).unwrap();
+    BufReader::new(stream)
+  }
+
+  #[test]
+  pub fn test_server() {
+    init();
+    let settings = Settings {
+      map_builder: MapBuilderKind::CompassRose,
+      ..Settings::default()
+    };
+    let mut server = Server::bind("127.0.0.1:0", &settings).unwrap();
+    let mut first = connect(&server);
+    wait_for(&mut server, &mut first, |line| line.contains("Spawn Room"));
+    let mut second = connect(&server);
+    wait_for(&mut server, &mut second, |line| line.contains("Spawn Room"));
+    assert_eq!(server.connections.len(), 2);
+    // Each player's input is their own, and so is the response to it.
+    first.get_mut().write_all(b"echo only for the first\n").unwrap();
+    wait_for(&mut server, &mut first, |line| line.contains("only for the first"));
+    // The second player sees the first player leave.
+    first.get_mut().write_all(b"n\n").unwrap();
+    wait_for(&mut server, &mut second, |line| line.contains("Player 1 walks out"));
+    let mut line = String::new();
+    while second.read_line(&mut line).is_ok() {
+      assert!(!line.contains("only for the first"));
+      line.clear();
+    }
+    // Hanging up removes the player from the world.
+    drop(first);
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while server.connections.len() > 1 && Instant::now() < deadline {
+      server.step().unwrap();
+    }
+    assert_eq!(server.connections.len(), 1);
+  }
+
+  #[test]
+  pub fn test_server_global_commands() {
+    init();
+    let settings = Settings {
+      map_builder: MapBuilderKind::CompassRose,
+      ..Settings::default()
+    };
+    let mut server = Server::bind("127.0.0.1:0", &settings).unwrap();
+    let mut first = connect(&server);
+    wait_for(&mut server, &mut first, |line| line.contains("Spawn Room"));
+    let mut second = connect(&server);
+    wait_for(&mut server, &mut second, |line| line.contains("Spawn Room"));
+    // Nobody can change the world out from under everyone else.
+    for (input, refusal) in [
+      ("save /tmp/never", "You can't save while others are playing."),
+      ("restore /tmp/never", "You can't restore while others are playing."),
+      ("undo", "You can't undo while others are playing."),
+      ("pause", "You can't pause while others are playing."),
+      (
+        "speed 2x",
+        "You can't change the speed of time while others are playing.",
+      ),
+    ] {
+      first.get_mut().write_all(format!("{}\n", input).as_bytes()).unwrap();
+      wait_for(&mut server, &mut first, |line| line.contains(refusal));
+    }
+    {
+      let time_scale = server.game.ecs.read_resource::<TimeScaleResource>();
+      assert!(!time_scale.paused);
+      assert_eq!(time_scale.speed, 1.0);
+    }
+    let mut line = String::new();
+    while second.read_line(&mut line).is_ok() {
+      assert!(!line.contains("You can't"));
+      line.clear();
+    }
+    // Quitting hangs up on that player alone.
+    first.get_mut().write_all(b"quit\n").unwrap();
+    wait_for(&mut server, &mut first, |line| line.contains("Farewell."));
+    assert_eq!(server.connections.len(), 1);
+    second.get_mut().write_all(b"echo still here\n").unwrap();
+    wait_for(&mut server, &mut second, |line| line.contains("still here"));
+  }
+
+  #[test]
+  pub fn test_server_private_commands() {
+    init();
+    let settings = Settings {
+      map_builder: MapBuilderKind::CompassRose,
+      ..Settings::default()
+    };
+    let mut server = Server::bind("127.0.0.1:0", &settings).unwrap();
+    let mut first = connect(&server);
+    wait_for(&mut server, &mut first, |line| line.contains("Spawn Room"));
+    let mut second = connect(&server);
+    wait_for(&mut server, &mut second, |line| line.contains("Spawn Room"));
+    // What one player asks about themselves, or about the game, is nobody
+    // else's business.
+    let responses = [
+      ("inventory", "You aren't carrying anything."),
+      ("map", "There's no map of this world."),
+      ("help examine", "look at <something>"),
+      ("time", "(Tick "),
+      ("score", "You have explored"),
+      ("brief", "described briefly"),
+    ];
+    for (input, response) in responses {
+      first.get_mut().write_all(format!("{}\n", input).as_bytes()).unwrap();
+      wait_for(&mut server, &mut first, |line| line.contains(response));
+    }
+    let mut line = String::new();
+    while second.read_line(&mut line).is_ok() {
+      for (_input, response) in responses {
+        assert!(!line.contains(response));
+      }
+      line.clear();
+    }
+  }
+}
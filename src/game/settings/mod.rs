@@ -0,0 +1,37 @@
+use uuid::Uuid;
+
+use crate::map::MapBuilderKind;
+
+/// The `Settings` type.
+///
+/// This is everything needed to create a particular world.  Given the same
+/// settings and the same input, the game should play out the same way, so
+/// these are what a bug report needs in order to reproduce a problem.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Settings {
+  /// The seed for the random number generator.
+  pub seed: String,
+  /// The builder used to create the map.
+  pub map_builder: MapBuilderKind,
+}
+
+impl Settings {
+  /// Constructor.
+  pub fn new(seed: &str) -> Self {
+    Self {
+      seed: seed.to_owned(),
+      map_builder: MapBuilderKind::default(),
+    }
+  }
+
+  /// Constructor with a fresh, unpredictable seed.
+  pub fn with_random_seed() -> Self {
+    Self::new(&Uuid::new_v4().to_string())
+  }
+}
+
+impl Default for Settings {
+  fn default() -> Self {
+    Self::new("goat boy")
+  }
+}
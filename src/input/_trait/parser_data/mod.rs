@@ -1,14 +1,16 @@
-use crate::ecs::entity::{EntityId, PlayerId};
+use crate::ecs::entity::PlayerId;
+use crate::input::Referent;
+use crate::map::Direction;
 use anyhow::Error as AnyError;
 
 /// The `ParserData` trait.
 pub trait ParserData {
   /// Retrieve the player ID.
   fn get_player_id(&self) -> Result<PlayerId, AnyError>;
-  /// Retrieve a list of nouns.
-  fn get_nouns(&self) -> Result<Vec<(String, EntityId)>, AnyError>;
-  /// Retrieve a list of genitives.
-  fn get_genitives(&self) -> Result<Vec<String>, AnyError>;
-  /// Retrieve a list of adjectives.
-  fn get_adjectives(&self) -> Result<Vec<String>, AnyError>;
+  /// Retrieve everything the player might refer to.
+  fn get_referents(&self) -> Result<Vec<Referent>, AnyError>;
+  /// Retrieve the directions in which the player can leave the room.
+  fn get_exit_directions(&self) -> Result<Vec<Direction>, AnyError> {
+    Ok(vec![])
+  }
 }
@@ -0,0 +1,86 @@
+use super::grammar::{GrammarElement, GRAMMAR};
+use super::ParserData;
+use anyhow::Error as AnyError;
+
+/// Get the ways the last word of a partial line of input might be finished,
+/// e.g. "look at goblin" for "look at gob", as whole lines.
+///
+/// The first word of a command might be a verb or a direction; any later
+/// word might be something the player can refer to, a way out of the room,
+/// or one of the words the verb's sentences are made with, e.g. "at".
+pub fn get_completions(line: &str, data: &impl ParserData) -> Result<Vec<String>, AnyError> {
+  let (prefix, partial) = match line.rfind(char::is_whitespace) {
+    Some(index) => line.split_at(index + 1),
+    None => ("", line),
+  };
+  if partial.is_empty() && prefix.trim().is_empty() {
+    return Ok(vec![]);
+  }
+  let partial_lowercase = partial.to_lowercase();
+  let exits = data
+    .get_exit_directions()?
+    .iter()
+    .map(|direction| direction.get_name().to_lowercase())
+    .collect::<Vec<String>>();
+  let mut candidates = exits;
+  let mut words = prefix.split_whitespace();
+  match words.next() {
+    None => candidates.extend(GRAMMAR.verbs.iter().flat_map(|verb| verb.synonyms.iter().cloned())),
+    Some(first) => {
+      if let Some(verb) = GRAMMAR.get_verb(&first.to_lowercase()) {
+        candidates.extend(verb.syntaxes.iter().flat_map(|syntax| {
+          syntax.pattern.0.iter().filter_map(|element| match element {
+            GrammarElement::Word(word) => Some(word.clone()),
+            _ => None,
+          })
+        }));
+      }
+      for referent in data.get_referents()? {
+        candidates.extend(
+          referent
+            .nouns
+            .iter()
+            .chain(referent.adjectives.iter())
+            .flat_map(|vocabulary| vocabulary.split_whitespace())
+            .map(String::from),
+        );
+      }
+    },
+  }
+  candidates.retain(|candidate| candidate.len() > partial.len() && candidate.starts_with(&partial_lowercase));
+  candidates.sort();
+  candidates.dedup();
+  Ok(
+    candidates
+      .into_iter()
+      .map(|candidate| format!("{}{}", prefix, candidate))
+      .collect(),
+  )
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::input::parser::test::TestData;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_completions() {
+    init();
+    let data = TestData {};
+    assert_eq!(
+      get_completions("go nor", &data).unwrap(),
+      vec!["go north", "go northeast"]
+    );
+    assert_eq!(get_completions("look at gob", &data).unwrap(), vec!["look at goblin"]);
+    assert_eq!(get_completions("look a", &data).unwrap(), vec!["look at"]);
+    assert_eq!(get_completions("exa", &data).unwrap(), vec!["examine"]);
+    assert_eq!(
+      get_completions("look at the melting ic", &data).unwrap(),
+      vec!["look at the melting ice"]
+    );
+    assert!(get_completions("look at xyz", &data).unwrap().is_empty());
+    assert!(get_completions("", &data).unwrap().is_empty());
+  }
+}
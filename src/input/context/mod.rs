@@ -0,0 +1,167 @@
+use super::error::Error as InputError;
+use super::token::TokenType;
+use crate::ecs::entity::EntityId;
+use anyhow::Error as AnyError;
+use std::str::FromStr;
+
+/// The `Context` type.
+///
+/// What a player's parser remembers from one line of input to the next, so
+/// that they can say "again", "look at it", or "oops goblin" and be
+/// understood.  Each player has their own.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Context {
+  /// The last input that made it through the parser.
+  pub last_input: Option<String>,
+  /// The things referred to most recently, e.g. by "it" or "them".
+  pub referent_ids: Vec<EntityId>,
+  /// The last input that the parser choked on, and the word that choked it.
+  pub last_failure: Option<(String, String)>,
+}
+
+impl Context {
+  /// Expand "again" and "oops" into the input they stand for.
+  ///
+  /// "Again" stands for the whole of the last input, and anything after it is
+  /// kept, e.g. "again then n".  Anything else is passed through as it is.
+  pub fn expand(&self, input: &str) -> Result<String, InputError> {
+    let input = input.trim();
+    let mut words = input.split_whitespace();
+    let first_word = words.next().unwrap_or_default();
+    let first = first_word.to_lowercase();
+    match TokenType::from_str(&first) {
+      Ok(TokenType::Again) => {
+        let last_input = self.last_input.as_ref().ok_or(InputError::NothingToRepeat)?;
+        Ok(format!("{}{}", last_input, &input[first_word.len()..]))
+      },
+      Ok(TokenType::Oops) => {
+        let correction = words.collect::<Vec<&str>>().join(" ");
+        match &self.last_failure {
+          Some((failed_input, unknown_word)) if !correction.is_empty() => {
+            Ok(replace_word(failed_input, unknown_word, &correction))
+          },
+          _ => Err(InputError::NothingToCorrect),
+        }
+      },
+      _ => Ok(input.to_string()),
+    }
+  }
+
+  /// Remember an input that was parsed successfully.
+  ///
+  /// The things it referred to last become "it" and "them".
+  pub fn record_success(&mut self, input: &str, referent_ids: &[EntityId]) {
+    self.last_input = Some(input.to_string());
+    self.last_failure = None;
+    self.referent_ids = referent_ids.to_vec();
+  }
+
+  /// Remember an input that the parser couldn't handle.
+  ///
+  /// Only an unknown word can be corrected with "oops"; anything else just
+  /// clears the slate.
+  pub fn record_failure(&mut self, input: &str, error: &AnyError) {
+    self.last_failure = match error.downcast_ref::<InputError>() {
+      Some(InputError::UnknownWord(word, _)) => Some((input.to_string(), word.clone())),
+      _ => None,
+    };
+  }
+}
+
+/// Replace the first occurrence of a word in the input, ignoring case.
+fn replace_word(input: &str, word: &str, replacement: &str) -> String {
+  let mut replaced = false;
+  input
+    .split_whitespace()
+    .map(|candidate| match !replaced && candidate.eq_ignore_ascii_case(word) {
+      true => {
+        replaced = true;
+        replacement
+      },
+      false => candidate,
+    })
+    .collect::<Vec<&str>>()
+    .join(" ")
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::input::parser::test::TestData;
+  use crate::input::Input;
+  use crate::test::*;
+
+  /// Interpret the input and get the entity its noun phrase refers to.
+  pub fn get_target(input: &str, context: &mut Context) -> Result<Option<EntityId>, AnyError> {
+    Input::default().interpret(input, &TestData {}, context)?;
+    Ok(context.referent_ids.first().copied())
+  }
+
+  #[test]
+  pub fn test_again() {
+    init();
+    let mut context = Context::default();
+    assert_eq!(context.expand("again").unwrap_err(), InputError::NothingToRepeat);
+    assert_eq!(
+      get_target("look at the green goblin", &mut context).unwrap(),
+      Some(EntityId(1))
+    );
+    assert_eq!(context.expand("again").unwrap(), "look at the green goblin");
+    assert_eq!(context.expand("g").unwrap(), "look at the green goblin");
+    assert_eq!(context.expand("look").unwrap(), "look");
+    // A line of several commands is repeated in full, and "again" partway
+    // through a line repeats the command before it.
+    let looks_at_goblin = |input: &str, context: &mut Context| {
+      let (commands, _) = Input::default().interpret(input, &TestData {}, context).unwrap();
+      commands
+        .iter()
+        .map(|command| format!("{:?}", command))
+        .map(|debug| debug.contains("target_entity_id: Id(1)"))
+        .collect::<Vec<bool>>()
+    };
+    let line = "look at the green goblin then look at the speckled mushroom";
+    assert_eq!(looks_at_goblin(line, &mut context), vec![true, false]);
+    assert_eq!(context.expand("again").unwrap(), line);
+    assert_eq!(looks_at_goblin("g", &mut context), vec![true, false]);
+    assert_eq!(
+      looks_at_goblin("again then look at the mushroom", &mut context),
+      vec![true, false, false]
+    );
+    assert_eq!(
+      looks_at_goblin("look at the green goblin. again", &mut context),
+      vec![true, true]
+    );
+    assert_eq!(looks_at_goblin("again", &mut context), vec![true, true]);
+  }
+
+  #[test]
+  pub fn test_pronouns() {
+    init();
+    let mut context = Context::default();
+    let error = get_target("look at it", &mut context).unwrap_err();
+    assert_eq!(error.to_string(), "I'm not sure what \"it\" refers to.");
+    get_target("look at the speckled mushroom", &mut context).unwrap();
+    // Looking at nothing in particular doesn't change what "it" is.
+    get_target("look", &mut context).unwrap();
+    assert_eq!(get_target("look at it", &mut context).unwrap(), Some(EntityId(3)));
+    assert_eq!(get_target("look at them", &mut context).unwrap(), Some(EntityId(3)));
+  }
+
+  #[test]
+  pub fn test_oops() {
+    init();
+    let mut context = Context::default();
+    assert_eq!(context.expand("oops goblin").unwrap_err(), InputError::NothingToCorrect);
+    let error = get_target("look at the pale hobgoblin", &mut context).unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      "I don't know the word \"hobgoblin\". Did you mean \"goblin\"?"
+    );
+    assert_eq!(context.expand("oops").unwrap_err(), InputError::NothingToCorrect);
+    assert_eq!(context.expand("oops goblin").unwrap(), "look at the pale goblin");
+    assert_eq!(get_target("oops goblin", &mut context).unwrap(), Some(EntityId(2)));
+    assert_eq!(context.last_input.as_deref(), Some("look at the pale goblin"));
+    assert_eq!(context.expand("oops goblin").unwrap_err(), InputError::NothingToCorrect);
+  }
+}
@@ -0,0 +1,94 @@
+use crate::input::Preposition;
+
+/// Errors encountered in interpreting input.
+///
+/// These are meant to be shown to the player as they are, so they should read
+/// like the game talking rather than like a stack trace.
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum Error {
+  /// A noun phrase could refer to more than one thing.
+  #[error("Which do you mean, {}?", join_alternatives(.0))]
+  AmbiguousNounPhrase(Vec<String>),
+  /// An order to do something that isn't done within the game world.
+  #[error("You can't tell {0} to do that.")]
+  CannotOrder(String),
+  /// Putting one thing somewhere relative to another that nothing can be.
+  #[error("You can't put anything {0} anything else.")]
+  CannotPut(Preposition),
+  /// "All" with nothing around for it to mean.
+  #[error("There isn't anything here to do that to.")]
+  NothingForAll,
+  /// "Oops" with nothing to correct, or nothing to correct it with.
+  #[error("There's nothing to correct.")]
+  NothingToCorrect,
+  /// "Again" before anything has been done.
+  #[error("There's nothing to repeat.")]
+  NothingToRepeat,
+  /// A noun phrase that's understood, but that nothing around fits.
+  #[error("You can't see any such thing.")]
+  NotHere,
+  /// Input that doesn't begin with anything a command can begin with.
+  #[error("I didn't understand that.")]
+  NotUnderstood,
+  /// A verb followed by something none of its syntaxes fit.
+  #[error("I only understood you as far as wanting to {0}.")]
+  PartlyUnderstood(String),
+  /// A pronoun with nothing around for it to stand for.
+  #[error("I'm not sure what \"{0}\" refers to.")]
+  UnknownPronoun(String),
+  /// A word that isn't in anything's vocabulary, and any it might have been
+  /// a misspelling of.
+  #[error("I don't know the word \"{0}\".{}", join_suggestions(.1))]
+  UnknownWord(String, Vec<String>),
+}
+
+/// Ask about suggestions as in " Did you mean \"a\" or \"b\"?", if any.
+fn join_suggestions(suggestions: &[String]) -> String {
+  if suggestions.is_empty() {
+    return String::new();
+  }
+  let quoted = suggestions
+    .iter()
+    .map(|suggestion| format!("\"{}\"", suggestion))
+    .collect::<Vec<String>>();
+  format!(" Did you mean {}?", join_alternatives(&quoted))
+}
+
+/// Join alternatives as in "a, b, or c".
+fn join_alternatives(alternatives: &[String]) -> String {
+  match alternatives {
+    [] => String::new(),
+    [only] => only.clone(),
+    [first, second] => format!("{} or {}", first, second),
+    [others @ .., last] => format!("{}, or {}", others.join(", "), last),
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_ambiguous_noun_phrase() {
+    init();
+    let error = Error::AmbiguousNounPhrase(vec!["the green goblin".into(), "the pale goblin".into()]);
+    assert_eq!(
+      error.to_string(),
+      "Which do you mean, the green goblin or the pale goblin?"
+    );
+  }
+
+  #[test]
+  pub fn test_unknown_word() {
+    init();
+    let error = Error::UnknownWord("xyzzy".into(), vec![]);
+    assert_eq!(error.to_string(), "I don't know the word \"xyzzy\".");
+    let error = Error::UnknownWord("loak".into(), vec!["load".into(), "look".into()]);
+    assert_eq!(
+      error.to_string(),
+      "I don't know the word \"loak\". Did you mean \"load\" or \"look\"?"
+    );
+  }
+}
@@ -0,0 +1,2 @@
+/// The grammar built into the game, from `src/_data/input/grammar.yaml`.
+pub const BUILT_IN_GRAMMAR: &str = include_str!("../../../_data/input/grammar.yaml");
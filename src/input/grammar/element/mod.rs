@@ -0,0 +1,56 @@
+use super::error::Error;
+use std::str::FromStr;
+
+/// The `Element` enum.
+///
+/// One part of a syntax's pattern.
+#[derive(Clone, Debug, Display, Eq, Hash, PartialEq)]
+pub enum Element {
+  /// A noun phrase, e.g. "the goblin's knife", "it", or "all".
+  #[display(fmt = "NOUN")]
+  Noun,
+  /// A direction, e.g. "north" or "ne".
+  #[display(fmt = "DIRECTION")]
+  Direction,
+  /// A preposition, e.g. "on" or "under".
+  #[display(fmt = "PREPOSITION")]
+  Preposition,
+  /// Everything left in the command, verbatim.
+  #[display(fmt = "TEXT")]
+  Text,
+  /// A word that has to appear as it is, e.g. "at".
+  #[display(fmt = "{}", _0)]
+  Word(String),
+}
+
+impl Element {
+  /// Describe this for the player, e.g. "<something>" for a noun phrase.
+  pub fn describe(&self) -> String {
+    use Element::*;
+    match self {
+      Noun => "<something>".to_string(),
+      Direction => "<direction>".to_string(),
+      Preposition => "<preposition>".to_string(),
+      Text => "<text>".to_string(),
+      Word(word) => word.clone(),
+    }
+  }
+}
+
+impl FromStr for Element {
+  type Err = Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    use Element::*;
+    match string {
+      "NOUN" => Ok(Noun),
+      "DIRECTION" => Ok(Direction),
+      "PREPOSITION" => Ok(Preposition),
+      "TEXT" => Ok(Text),
+      placeholder if placeholder.chars().all(|char| char.is_ascii_uppercase()) => {
+        Err(Error::UnknownElement(placeholder.to_string()))
+      },
+      word => Ok(Word(word.to_lowercase())),
+    }
+  }
+}
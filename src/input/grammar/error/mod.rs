@@ -0,0 +1,16 @@
+use serde_yaml::Error as YamlError;
+use std::io::Error as IoError;
+
+/// Errors encountered loading a grammar.
+#[derive(Debug, Error)]
+pub enum Error {
+  /// An I/O error occurred.
+  #[error("an error occurred ({0})")]
+  IoError(#[from] IoError),
+  /// The grammar couldn't be read.
+  #[error("the grammar couldn't be read ({0})")]
+  YamlError(#[from] YamlError),
+  /// A pattern had a placeholder we don't know, e.g. "NOUNS".
+  #[error("unknown pattern element ({0})")]
+  UnknownElement(String),
+}
@@ -0,0 +1,99 @@
+use super::token::r#type::DIRECTION_WORDS;
+use lazy_static::lazy_static;
+use std::fs;
+use std::path::Path;
+
+pub mod _constant;
+pub use _constant::*;
+pub mod element;
+pub use element::Element as GrammarElement;
+pub mod error;
+pub use error::Error as GrammarError;
+pub mod pattern;
+pub use pattern::Pattern as GrammarPattern;
+pub mod syntax;
+pub use syntax::Syntax as GrammarSyntax;
+pub mod verb;
+pub use verb::Verb as GrammarVerb;
+
+lazy_static! {
+  /// The grammar the player's parser uses.
+  pub static ref GRAMMAR: Grammar = Grammar::from_yaml(BUILT_IN_GRAMMAR).expect("The built-in grammar is invalid!");
+}
+
+/// The `Grammar` type.
+///
+/// This is the table of verbs that drives the parser: what each verb can be
+/// called, the sentences that can be made with it, and which command each of
+/// those sentences stands for.  A new synonym or sentence only needs a line
+/// in the grammar file.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Grammar {
+  pub verbs: Vec<GrammarVerb>,
+}
+
+impl Grammar {
+  /// Read a grammar from YAML.
+  pub fn from_yaml(yaml: &str) -> Result<Self, GrammarError> {
+    Ok(serde_yaml::from_str(yaml)?)
+  }
+
+  /// Read a grammar from a YAML file.
+  pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, GrammarError> {
+    Self::from_yaml(&fs::read_to_string(path)?)
+  }
+
+  /// Get the verb a word stands for, if any.
+  pub fn get_verb(&self, word: &str) -> Option<&GrammarVerb> {
+    self
+      .verbs
+      .iter()
+      .find(|verb| verb.synonyms.iter().any(|synonym| synonym == word))
+  }
+
+  /// Get every word that can begin a command, for correcting misspellings.
+  ///
+  /// Abbreviations like "l" and "go" are left out, since they're too short
+  /// to tell a typo from something else.
+  pub fn get_command_words(&self) -> Vec<&str> {
+    self
+      .verbs
+      .iter()
+      .flat_map(|verb| verb.synonyms.iter().map(String::as_str))
+      .chain(DIRECTION_WORDS.iter().copied())
+      .filter(|word| word.len() > 2)
+      .collect()
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::command::CommandKind;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_grammar() {
+    init();
+    let look = GRAMMAR.get_verb("examine").unwrap();
+    assert!(look.synonyms.contains(&"look".to_string()));
+    assert!(look.is_in_character());
+    assert!(!GRAMMAR.get_verb("save").unwrap().is_in_character());
+    assert!(GRAMMAR.get_verb("goblin").is_none());
+    let grammar = Grammar::from_yaml(
+      "verbs:\n  - synonyms: [peer]\n    syntaxes:\n      - pattern: into DIRECTION\n        command: LookDirection\n",
+    )
+    .unwrap();
+    let syntax = &grammar.get_verb("peer").unwrap().syntaxes[0];
+    assert_eq!(
+      syntax.pattern.0,
+      vec![GrammarElement::Word("into".into()), GrammarElement::Direction]
+    );
+    assert_eq!(syntax.command, CommandKind::LookDirection);
+    assert!(Grammar::from_yaml(
+      "verbs:\n  - synonyms: [peer]\n    syntaxes:\n      - pattern: NOUNS\n        command: LookAround\n"
+    )
+    .is_err());
+  }
+}
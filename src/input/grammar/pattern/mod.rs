@@ -0,0 +1,42 @@
+use super::element::Element;
+use super::error::Error;
+use std::str::FromStr;
+
+/// The `Pattern` type.
+///
+/// What comes after the verb in a syntax, e.g. "at NOUN".  It's written as a
+/// string in the grammar file.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Pattern(pub Vec<Element>);
+
+impl FromStr for Pattern {
+  type Err = Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    let elements = string
+      .split_whitespace()
+      .map(Element::from_str)
+      .collect::<Result<Vec<Element>, Error>>()?;
+    Ok(Self(elements))
+  }
+}
+
+impl TryFrom<String> for Pattern {
+  type Error = Error;
+
+  fn try_from(string: String) -> Result<Self, Self::Error> {
+    Self::from_str(&string)
+  }
+}
+
+impl From<Pattern> for String {
+  fn from(pattern: Pattern) -> Self {
+    pattern
+      .0
+      .iter()
+      .map(|element| element.to_string())
+      .collect::<Vec<String>>()
+      .join(" ")
+  }
+}
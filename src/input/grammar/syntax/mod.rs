@@ -0,0 +1,13 @@
+use super::pattern::Pattern;
+use crate::command::CommandKind;
+
+/// The `Syntax` type.
+///
+/// One sentence that can be made with a verb, and what it means.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Syntax {
+  /// What comes after the verb.
+  pub pattern: Pattern,
+  /// The command the sentence stands for.
+  pub command: CommandKind,
+}
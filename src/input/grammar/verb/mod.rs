@@ -0,0 +1,38 @@
+use super::syntax::Syntax;
+
+/// The `Verb` type.
+///
+/// A verb, by all of its names, and the sentences that can be made with it.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Verb {
+  /// The words that mean this verb, e.g. "look", "l", and "examine".
+  pub synonyms: Vec<String>,
+  /// The sentences that can be made with it, in the order they're tried.
+  pub syntaxes: Vec<Syntax>,
+}
+
+impl Verb {
+  /// Is everything that can be done with this verb done in the game world?
+  ///
+  /// Only such verbs can be given as orders to someone else.
+  pub fn is_in_character(&self) -> bool {
+    self.syntaxes.iter().all(|syntax| syntax.command.is_in_character())
+  }
+
+  /// Describe the sentences that can be made with this verb, for the player,
+  /// e.g. "look at <something>".
+  pub fn describe_syntaxes(&self) -> Vec<String> {
+    let verb = self.synonyms.first().cloned().unwrap_or_default();
+    let mut result = Vec::new();
+    for syntax in self.syntaxes.iter() {
+      let description = std::iter::once(verb.clone())
+        .chain(syntax.pattern.0.iter().map(|element| element.describe()))
+        .collect::<Vec<String>>()
+        .join(" ");
+      if !result.contains(&description) {
+        result.push(description);
+      }
+    }
+    result
+  }
+}
@@ -0,0 +1,101 @@
+use std::fs::{self, File};
+use std::io::{BufWriter, Error as IoError, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+/// How many lines of input are kept from one session to the next.
+pub const MAXIMUM_HISTORY: usize = 1_000;
+
+/// The `History` type.
+///
+/// The lines of input the player has entered, kept in a file so that they
+/// can be recalled in a later session.  Each line is appended as it's
+/// entered, so nothing is lost if the game ends abruptly.
+#[derive(Debug)]
+pub struct History {
+  /// The lines, oldest first.
+  pub entries: Vec<String>,
+  /// The file being appended to.
+  pub writer: BufWriter<File>,
+}
+
+impl History {
+  /// Read the history from a file, creating it if there isn't one.
+  ///
+  /// Only the most recent lines are kept, and the file is rewritten to hold
+  /// just those, so that it doesn't grow without end.
+  pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, IoError> {
+    let contents = match fs::read_to_string(&path) {
+      Ok(contents) => contents,
+      Err(error) if error.kind() == ErrorKind::NotFound => String::new(),
+      Err(error) => return Err(error),
+    };
+    let mut entries = contents
+      .lines()
+      .filter(|line| !line.trim().is_empty())
+      .map(String::from)
+      .collect::<Vec<String>>();
+    entries.drain(..entries.len().saturating_sub(MAXIMUM_HISTORY));
+    let mut writer = BufWriter::new(File::create(&path)?);
+    for entry in entries.iter() {
+      writeln!(writer, "{}", entry)?;
+    }
+    writer.flush()?;
+    Ok(Self { entries, writer })
+  }
+
+  /// Add a line, unless it's blank or the same as the last one.
+  ///
+  /// Returns whether it was added.
+  pub fn add(&mut self, entry: &str) -> Result<bool, IoError> {
+    let entry = entry.trim();
+    if entry.is_empty() || self.entries.last().map(String::as_str) == Some(entry) {
+      return Ok(false);
+    }
+    self.entries.push(entry.to_owned());
+    writeln!(self.writer, "{}", entry)?;
+    self.writer.flush()?;
+    Ok(true)
+  }
+}
+
+/// Get the default history file, in the player's home directory if there is
+/// one, or else in the working directory.
+pub fn get_default_history_path() -> PathBuf {
+  let file_name = ".hornvale_history";
+  match std::env::var_os("HOME") {
+    Some(home) => Path::new(&home).join(file_name),
+    None => PathBuf::from(file_name),
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_history() {
+    init();
+    let path = std::env::temp_dir().join(format!("hornvale_history_{}", std::process::id()));
+    let _ = fs::remove_file(&path);
+    let mut history = History::open(&path).unwrap();
+    assert!(history.entries.is_empty());
+    assert!(history.add("look at the goblin").unwrap());
+    assert!(!history.add("look at the goblin").unwrap());
+    assert!(!history.add("   ").unwrap());
+    assert!(history.add("n").unwrap());
+    drop(history);
+    // The next session picks up where this one left off.
+    let mut history = History::open(&path).unwrap();
+    assert_eq!(history.entries, vec!["look at the goblin", "n"]);
+    for index in 0..MAXIMUM_HISTORY {
+      history.add(&format!("wait {}", index)).unwrap();
+    }
+    drop(history);
+    let history = History::open(&path).unwrap();
+    assert_eq!(history.entries.len(), MAXIMUM_HISTORY);
+    assert_eq!(history.entries[0], "wait 0");
+    fs::remove_file(&path).unwrap();
+  }
+}
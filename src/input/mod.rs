@@ -2,10 +2,25 @@ use crate::command::Command;
 use anyhow::Error;
 pub mod _trait;
 pub use _trait::parser_data::ParserData;
+pub mod completion;
+pub use completion::get_completions;
+pub mod context;
+pub use context::Context as ParserContext;
+pub mod error;
+pub use error::Error as InputError;
+pub mod grammar;
+pub use grammar::{Grammar, GRAMMAR};
+pub mod history;
+pub use history::History as InputHistory;
 pub mod parser;
 pub use parser::Parser;
+pub mod preposition;
+pub use preposition::Preposition;
+pub mod referent;
+pub use referent::Referent;
 pub mod scanner;
 pub use scanner::Scanner;
+pub mod spelling;
 pub mod token;
 pub use token::{Token, TokenType};
 
@@ -14,12 +29,48 @@ pub use token::{Token, TokenType};
 pub struct Input {}
 
 impl Input {
-  pub fn interpret(&self, input: &str, data: &impl ParserData) -> Result<(Command, Option<String>), Error> {
-    let mut scanner = Scanner::new(input);
+  /// Interpret a line of input in the player's context, updating it.
+  ///
+  /// The commands are returned in the order they should be carried out,
+  /// along with a note of any misspellings we took the liberty of correcting.
+  pub fn interpret(
+    &self,
+    input: &str,
+    data: &impl ParserData,
+    context: &mut ParserContext,
+  ) -> Result<(Vec<Command>, Option<String>), Error> {
+    let input = context.expand(input)?;
+    let mut scanner = Scanner::new(&input);
     scanner.scan_tokens()?;
-    let mut parser = Parser::new(scanner.tokens, input);
-    let command = parser.parse(data)?;
-    Ok((command, None))
+    let mut parser = Parser::new(scanner.tokens, &input);
+    parser.referent_ids = context.referent_ids.clone();
+    match parser.parse(data) {
+      Ok(commands) if commands.is_empty() => Ok((commands, None)),
+      Ok(commands) => {
+        context.record_success(&input, &parser.referent_ids);
+        let mut corrections = scanner.corrections;
+        corrections.append(&mut parser.corrections);
+        Ok((commands, describe_corrections(&corrections)))
+      },
+      Err(error) => {
+        context.record_failure(&input, &error);
+        Err(error)
+      },
+    }
+  }
+}
+
+/// Describe the misspellings that were corrected, e.g. "(Taking "lok" to mean
+/// "look".)", if there were any.
+fn describe_corrections(corrections: &[(String, String)]) -> Option<String> {
+  let descriptions = corrections
+    .iter()
+    .map(|(word, correction)| format!("\"{}\" to mean \"{}\"", word, correction))
+    .collect::<Vec<String>>();
+  match descriptions.as_slice() {
+    [] => None,
+    [only] => Some(format!("(Taking {}.)", only)),
+    [others @ .., last] => Some(format!("(Taking {} and {}.)", others.join(", "), last)),
   }
 }
 
@@ -29,17 +80,17 @@ pub mod test {
   use super::Input as InputSystem;
   use super::*;
   use crate::ecs::component::*;
-  use crate::ecs::entity::{EntityId, PlayerId};
+  use crate::ecs::entity::PlayerId;
   use crate::ecs::resource::*;
   use crate::test::*;
   use anyhow::Error as AnyError;
-  use rand_seeder::SipHasher;
   use specs::prelude::*;
 
   pub struct InputProcessor {
     pub input: Option<String>,
     pub output: Option<String>,
     pub input_system: InputSystem,
+    pub context: ParserContext,
   }
 
   impl InputProcessor {}
@@ -60,16 +111,8 @@ pub mod test {
     fn get_player_id(&self) -> Result<PlayerId, AnyError> {
       Ok(PlayerId(3))
     }
-    /// Retrieve a list of nouns.
-    fn get_nouns(&self) -> Result<Vec<(String, EntityId)>, AnyError> {
-      Ok(vec![])
-    }
-    /// Retrieve a list of genitives.
-    fn get_genitives(&self) -> Result<Vec<String>, AnyError> {
-      Ok(vec![])
-    }
-    /// Retrieve a list of adjectives.
-    fn get_adjectives(&self) -> Result<Vec<String>, AnyError> {
+    /// Retrieve everything the player might refer to.
+    fn get_referents(&self) -> Result<Vec<Referent>, AnyError> {
       Ok(vec![])
     }
   }
@@ -80,7 +123,7 @@ pub mod test {
     /// Run system.
     fn run(&mut self, data: Self::SystemData) {
       let input = self.input.as_ref().unwrap().clone();
-      self.output = self.input_system.interpret(&input, &data).unwrap().1;
+      self.output = self.input_system.interpret(&input, &data, &mut self.context).unwrap().1;
     }
   }
 
@@ -89,14 +132,29 @@ pub mod test {
     init();
     let mut ecs = World::new();
     let seed = "goat boy";
-    let rng = SipHasher::from(seed).into_rng();
-    ecs.insert(CameraResource(None));
     ecs.insert(PlayerResource(None));
-    ecs.insert(RandomResource(rng));
+    ecs.insert(RandomResource::new(seed));
     ecs.insert(SpawnRoomResource(None));
     ecs.insert(TickResource(0));
     ecs.insert(TileMapResource(None));
     register_components(&mut ecs);
     let _input = Input::default();
   }
+
+  #[test]
+  pub fn test_corrections() {
+    use crate::input::parser::test::TestData;
+    init();
+    let mut context = ParserContext::default();
+    let (commands, output) = Input::default()
+      .interpret("loook at the speckled mushrom", &TestData {}, &mut context)
+      .unwrap();
+    assert_eq!(commands.len(), 1);
+    assert_eq!(
+      output.as_deref(),
+      Some("(Taking \"loook\" to mean \"look\" and \"mushrom\" to mean \"mushroom\".)")
+    );
+    let (_, output) = Input::default().interpret("look", &TestData {}, &mut context).unwrap();
+    assert_eq!(output, None);
+  }
 }
@@ -1,7 +1,16 @@
-use super::token::{Token, TokenType};
-use super::ParserData;
+use super::error::Error as InputError;
+use super::grammar::{GrammarElement, GrammarSyntax, GrammarVerb, GRAMMAR};
+use super::spelling;
+use super::token::{Token, TokenLiteral, TokenType};
+use super::{ParserData, Preposition, Referent};
 use crate::command::*;
+use crate::ecs::entity::{EntityId, PlayerId};
+use crate::map::Direction;
 use anyhow::Error as AnyError;
+use std::str::FromStr;
+
+/// The place "all" took in a pattern, and the objects it stands for.
+pub type AllObjects = (usize, Vec<EntityId>);
 
 /// The `Parser` type.
 #[derive(Clone, Debug, Display, Eq, Hash, PartialEq)]
@@ -10,18 +19,104 @@ pub struct Parser<'input> {
   pub input: &'input str,
   pub tokens: Vec<Token<'input>>,
   pub current: usize,
+  /// The things referred to most recently, for pronouns to stand for.
+  pub referent_ids: Vec<EntityId>,
+  /// Misspelled words that were taken to be something else, and what.
+  pub corrections: Vec<(String, String)>,
 }
 
 impl<'input> Parser<'input> {
   /// Constructor.
   pub fn new(tokens: Vec<Token<'input>>, input: &'input str) -> Self {
     let current = 0;
-    Self { input, tokens, current }
+    let referent_ids = Vec::new();
+    let corrections = Vec::new();
+    Self {
+      input,
+      tokens,
+      current,
+      referent_ids,
+      corrections,
+    }
+  }
+
+  /// Parse input, which may hold several commands, e.g. "n then look".
+  ///
+  /// Each command is parsed on its own, in order, but pronouns can refer to
+  /// things named in an earlier one, e.g. "look at the goblin then look at
+  /// him".  "Again" partway through repeats the command before it, e.g. "n.
+  /// again".
+  pub fn parse(&mut self, data: &impl ParserData) -> Result<Vec<Command>, AnyError> {
+    let mut result: Vec<Command> = Vec::new();
+    for (start, end) in self.get_command_ranges() {
+      if end - start == 1 && self.tokens[start].r#type == TokenType::Again {
+        let command = result.last().cloned().ok_or(InputError::NothingToRepeat)?;
+        result.push(command);
+        continue;
+      }
+      let mut tokens = self.tokens[start..end].to_vec();
+      tokens.push(Token {
+        r#type: TokenType::Eof,
+        lexeme: "",
+        literal: None,
+        entity_id: None,
+      });
+      let mut parser = Parser::new(tokens, self.get_input_between(start, end));
+      parser.referent_ids = self.referent_ids.clone();
+      let command = parser.parse_command(data);
+      self.corrections.append(&mut parser.corrections);
+      result.push(command?);
+      self.referent_ids = parser.referent_ids;
+    }
+    Ok(result)
   }
 
-  /// Parse input.
-  pub fn parse(&mut self, data: &impl ParserData) -> Result<Command, AnyError> {
-    self.parse_command(data)
+  /// Find the range of tokens for each command in the input.
+  ///
+  /// Commands are separated by "then", or by a period, comma, or "and" that
+  /// comes right before something a command can begin with, e.g. a verb.  A comma after
+  /// anything else is addressing an order, e.g. "goblin, go north".
+  pub fn get_command_ranges(&self) -> Vec<(usize, usize)> {
+    use TokenType::*;
+    let end = self
+      .tokens
+      .iter()
+      .position(|token| token.r#type == Eof)
+      .unwrap_or(self.tokens.len());
+    let mut result = Vec::new();
+    let mut start = 0;
+    for index in 0..end {
+      let begins_command = |index: usize| {
+        index == end
+          || self.tokens[index].r#type.begins_command()
+          || GRAMMAR.get_verb(&get_word(&self.tokens[index])).is_some()
+      };
+      let is_separator = match self.tokens[index].r#type {
+        Then => true,
+        Comma if index > start && !begins_command(start) => false,
+        And | Comma | Period => begins_command(index + 1),
+        _ => false,
+      };
+      if is_separator {
+        if index > start {
+          result.push((start, index));
+        }
+        start = index + 1;
+      }
+    }
+    if end > start {
+      result.push((start, end));
+    }
+    result
+  }
+
+  /// Get the part of the input that a range of tokens was scanned from.
+  pub fn get_input_between(&self, start: usize, end: usize) -> &'input str {
+    // Lexemes are slices of the input, so we can tell where they came from.
+    let get_offset = |lexeme: &str| lexeme.as_ptr() as usize - self.input.as_ptr() as usize;
+    let first = self.tokens[start].lexeme;
+    let last = self.tokens[end - 1].lexeme;
+    &self.input[get_offset(first)..get_offset(last) + last.len()]
   }
 
   /// Parse a command.
@@ -29,98 +124,407 @@ impl<'input> Parser<'input> {
     if self.check(TokenType::Direction)? && self.peek_next()?.r#type == TokenType::Eof {
       return self.parse_direction_command(data);
     }
-    if self.match_verb()? {
-      self.match_noun_phrase(data)?;
-      return self.parse_action_command(data);
+    if let Some(verb) = GRAMMAR.get_verb(&get_word(&self.peek()?)) {
+      return self.parse_verb_command(verb, data);
     }
     self.parse_order_command(data)
   }
 
-  /// Parse an order command; that is, tell another actor to do something.
-  pub fn parse_order_command(&mut self, data: &impl ParserData) -> Result<Command, AnyError> {
-    let string = self.get_raw_arguments()?;
-    let original_input = self.input.to_owned();
-    if self.match_noun_phrase(data)? {
-      self.consume(
-        TokenType::Comma,
-        &format!("Expected a comma after the addressee, {}", self.previous()?),
-      )?;
+  /// Parse a command beginning with a verb, e.g. "look at the goblin".
+  ///
+  /// The verb's syntaxes are tried in the order the grammar gives them, and
+  /// the first that fits the rest of the command wins.  If none does, the
+  /// first error any of them ran into is likely the most useful to report.
+  pub fn parse_verb_command(&mut self, verb: &GrammarVerb, data: &impl ParserData) -> Result<Command, AnyError> {
+    let verb_word = get_word(&self.advance()?);
+    let start = self.current;
+    let tokens = self.tokens.clone();
+    let referent_ids = self.referent_ids.clone();
+    let corrections = self.corrections.len();
+    let mut first_error = None;
+    for syntax in verb.syntaxes.iter() {
+      match self.match_syntax(syntax, data) {
+        Ok(Some(command)) => return Ok(command),
+        Ok(None) => {},
+        Err(error) => {
+          first_error.get_or_insert(error);
+        },
+      }
+      self.current = start;
+      self.tokens = tokens.clone();
+      self.referent_ids = referent_ids.clone();
+      self.corrections.truncate(corrections);
     }
-    Command::from_data(original_input, string, self.tokens.clone(), data)
+    Err(first_error.unwrap_or_else(|| InputError::PartlyUnderstood(verb_word).into()))
   }
 
-  /// Parse an action command; that is, a simple command.
-  pub fn parse_action_command(&mut self, data: &impl ParserData) -> Result<Command, AnyError> {
-    self.advance()?;
-    let string = self.get_raw_arguments()?;
-    let original_input = self.input.to_owned();
-    Command::from_data(original_input, string, self.tokens.clone(), data)
+  /// Match the rest of the command to a syntax, and create the command that
+  /// it stands for if it fits.
+  ///
+  /// "All" in place of a noun phrase makes one command for each object.
+  pub fn match_syntax(&mut self, syntax: &GrammarSyntax, data: &impl ParserData) -> Result<Option<Command>, AnyError> {
+    let (arguments, all) = match self.match_pattern(syntax, data)? {
+      Some(result) => result,
+      None => return Ok(None),
+    };
+    let (index, entity_ids) = match all {
+      Some(all) => all,
+      None => return syntax.command.create(arguments).map(Some),
+    };
+    let referents = data.get_referents()?;
+    let mut commands = Vec::new();
+    for entity_id in entity_ids {
+      let name = referents
+        .iter()
+        .find(|referent| referent.entity_id == entity_id)
+        .map(|referent| referent.name.clone())
+        .unwrap_or_default();
+      let mut arguments = arguments.clone();
+      arguments.entity_ids[index] = Some(entity_id);
+      commands.push((name, syntax.command.create(arguments)?));
+    }
+    let player_id = arguments.player_id;
+    Ok(Some(create_command!(EachObjectCommand { player_id, commands })))
+  }
+
+  /// Match the rest of the command to a syntax's pattern, and get the
+  /// arguments for the command if it fits, along with the place "all" took,
+  /// if it did, and the objects it stands for.
+  ///
+  /// A noun phrase that can't be made out still fills its place, as nothing
+  /// in particular.  A preposition, whether it's a placeholder or a word, is
+  /// kept, so that e.g. "put X on Y" and "put X under Y" can be told apart.
+  pub fn match_pattern(
+    &mut self,
+    syntax: &GrammarSyntax,
+    data: &impl ParserData,
+  ) -> Result<Option<(CommandArguments, Option<AllObjects>)>, AnyError> {
+    let mut arguments = CommandArguments::new(data.get_player_id()?, self.input);
+    let mut all = None;
+    for element in syntax.pattern.0.iter() {
+      let is_match = match element {
+        GrammarElement::Word(word) => {
+          let is_match = !self.is_at_end()? && get_word(&self.peek()?) == *word;
+          if is_match {
+            self.advance()?;
+            if let Ok(preposition) = Preposition::from_str(word) {
+              arguments.preposition = Some(preposition);
+            }
+          }
+          is_match
+        },
+        GrammarElement::Preposition => match Preposition::from_str(&get_word(&self.peek()?)) {
+          Ok(preposition) if !self.is_at_end()? => {
+            self.advance()?;
+            arguments.preposition = Some(preposition);
+            true
+          },
+          _ => false,
+        },
+        GrammarElement::Direction => match self.check(TokenType::Direction)? {
+          true => {
+            arguments.direction = Some(Direction::from_str(&get_word(&self.advance()?))?);
+            true
+          },
+          false => false,
+        },
+        GrammarElement::Noun if self.check(TokenType::All)? => {
+          all = Some((arguments.entity_ids.len(), self.match_all(syntax.command, data)?));
+          arguments.entity_ids.push(None);
+          true
+        },
+        GrammarElement::Noun => {
+          let start = self.current;
+          let entity_id = self.match_noun_phrase(data)?;
+          if entity_id.is_none() {
+            let referents = data.get_referents()?;
+            while !self.is_at_end()? && self.is_noun_phrase_token(&self.peek()?, &referents) {
+              self.current += 1;
+            }
+          }
+          arguments.entity_ids.push(entity_id);
+          self.current > start
+        },
+        GrammarElement::Text => match self.is_at_end()? {
+          true => false,
+          false => {
+            let end = self.tokens.len() - 1;
+            arguments.text = Some(self.get_input_between(self.current, end).to_owned());
+            self.current = end;
+            true
+          },
+        },
+      };
+      if !is_match {
+        return Ok(None);
+      }
+    }
+    if !self.is_at_end()? {
+      return Ok(None);
+    }
+    Ok(Some((arguments, all)))
+  }
+
+  /// Parse an order command; that is, tell another actor to do something.
+  ///
+  /// The rest of the input is parsed as a command of its own, but on behalf
+  /// of the addressee, e.g. "goblin, go north" has the goblin go north.
+  pub fn parse_order_command(&mut self, data: &impl ParserData) -> Result<Command, AnyError> {
+    let addressee_id = match self.match_noun_phrase(data)? {
+      Some(addressee_id) => addressee_id,
+      None => return Err(InputError::NotUnderstood.into()),
+    };
+    self.consume(
+      TokenType::Comma,
+      &format!("Expected a comma after the addressee, {}", self.previous()?),
+    )?;
+    let next = self.peek()?;
+    let is_in_character = match GRAMMAR.get_verb(&get_word(&next)) {
+      Some(verb) => verb.is_in_character(),
+      None => next.r#type == TokenType::Direction,
+    };
+    if !is_in_character {
+      let name = data
+        .get_referents()?
+        .into_iter()
+        .find(|referent| referent.entity_id == addressee_id)
+        .map(|referent| referent.name.to_lowercase())
+        .unwrap_or_default();
+      return Err(InputError::CannotOrder(name).into());
+    }
+    let start = self.current;
+    let end = self.tokens.len() - 1;
+    let order = self.get_input_between(start, end);
+    let mut parser = Parser::new(self.tokens[start..].to_vec(), order);
+    parser.referent_ids = self.referent_ids.clone();
+    let command = parser.parse_command(&Addressee { data, addressee_id });
+    self.corrections.append(&mut parser.corrections);
+    let command = command?;
+    self.referent_ids = parser.referent_ids;
+    let player_id = data.get_player_id()?;
+    Ok(create_command!(OrderCommand {
+      player_id,
+      addressee_id,
+      order: order.to_owned(),
+      command: Box::new(command),
+    }))
   }
 
   /// Parse a direction command, e.g. "sw".
   pub fn parse_direction_command(&mut self, data: &impl ParserData) -> Result<Command, AnyError> {
-    self.consume(TokenType::Direction, "Expected a valid direction.")?;
-    let string = self.get_raw_arguments()?;
-    let original_input = self.input.to_owned();
-    let mut tokens = vec![Token {
-      r#type: TokenType::Go,
-      lexeme: "go",
-      literal: None,
-      entity_id: None,
-    }];
-    tokens.append(&mut self.tokens);
-    Command::from_data(original_input, string, tokens, data)
-  }
-
-  /// Look for a match to a noun phrase.
-  pub fn match_noun_phrase(&mut self, data: &impl ParserData) -> Result<bool, AnyError> {
-    while self.match_descriptor(data)? {
-      self.current += 1;
+    let word = get_word(&self.consume(TokenType::Direction, "Expected a valid direction.")?);
+    let mut arguments = CommandArguments::new(data.get_player_id()?, self.input);
+    arguments.direction = Some(Direction::from_str(&word)?);
+    CommandKind::GoDirection.create(arguments)
+  }
+
+  /// Look for a noun phrase at the current token, e.g. "the goblin's knife".
+  ///
+  /// Phrases are tried longest first, so that "the speckled mushroom" isn't
+  /// taken for "the speckled".  If a phrase refers to one thing better than
+  /// to any other, its tokens are marked as adjectives and nouns, the last is
+  /// given that thing's entity ID, and we advance past it.  If it refers to
+  /// several things equally well, we have to ask which was meant.
+  ///
+  /// A pronoun stands for the most recently referred-to thing still around.
+  /// A word that isn't in anything's vocabulary is taken to be a misspelling
+  /// of one that is, if there's an obvious candidate; otherwise, a phrase that
+  /// can't be resolved because of it is an error, so that the player can
+  /// correct it.
+  pub fn match_noun_phrase(&mut self, data: &impl ParserData) -> Result<Option<EntityId>, AnyError> {
+    let referents = data.get_referents()?;
+    if self.check(TokenType::Pronoun)? {
+      return self.resolve_pronoun(&referents).map(Some);
     }
-    if self.match_noun(data)? {
-      self.current += 1;
-    } else {
-      return Ok(false);
+    let mut end = self.current;
+    while end < self.tokens.len() && self.is_noun_phrase_token(&self.tokens[end], &referents) {
+      end += 1;
+    }
+    self.correct_noun_phrase(self.current, end, &referents);
+    for phrase_end in (self.current + 1..=end).rev() {
+      let start = self.current;
+      if let Some(entity_id) = self.resolve_noun_phrase(start, phrase_end, &referents)? {
+        self.current = phrase_end;
+        self.referent_ids = vec![entity_id];
+        return Ok(Some(entity_id));
+      }
+    }
+    let unknown_word = self.tokens[self.current..end]
+      .iter()
+      .filter(|token| matches!(token.r#type, TokenType::Genitive | TokenType::Identifier))
+      .map(get_noun_phrase_word)
+      .find(|word| !is_known_word(word, &referents));
+    match unknown_word {
+      Some(word) => {
+        let vocabulary = get_vocabulary(&referents);
+        // Only the first word could have been meant for a verb.
+        let command_words = match self.current {
+          0 => GRAMMAR.get_command_words(),
+          _ => Vec::new(),
+        };
+        let candidates = vocabulary.iter().map(String::as_str).chain(command_words);
+        let suggestions = spelling::suggest(&word, candidates);
+        Err(InputError::UnknownWord(word, suggestions).into())
+      },
+      None => Ok(None),
     }
-    Ok(true)
   }
 
-  /// Look for a match to a descriptor.
-  pub fn match_descriptor(&mut self, data: &impl ParserData) -> Result<bool, AnyError> {
-    if self.check(TokenType::Article)? {
-      return Ok(true);
+  /// Correct misspelled words in the given range of tokens, e.g. "goblni".
+  ///
+  /// The correction is kept as the token's literal, since the lexeme has to
+  /// remain a slice of the input.
+  pub fn correct_noun_phrase(&mut self, start: usize, end: usize, referents: &[Referent]) {
+    let vocabulary = get_vocabulary(referents);
+    for token in self.tokens[start..end].iter_mut() {
+      if !matches!(token.r#type, TokenType::Genitive | TokenType::Identifier) {
+        continue;
+      }
+      let word = get_noun_phrase_word(token);
+      if is_known_word(&word, referents) {
+        continue;
+      }
+      if let Some(correction) = spelling::correct(&word, vocabulary.iter().map(String::as_str)) {
+        self.corrections.push((word, correction.to_string()));
+        token.literal = Some(TokenLiteral::String(correction.to_string()));
+      }
     }
-    if self.check(TokenType::Genitive)? {
-      return Ok(true);
+  }
+
+  /// Match "all", and anything it's followed by to leave out, e.g. "all but
+  /// the goblin and the mushroom".
+  ///
+  /// "All" is whatever the command can sensibly be given for, out of
+  /// everything the player might refer to, e.g. what they're carrying for
+  /// "drop all"; afterward, so is "them".
+  pub fn match_all(&mut self, kind: CommandKind, data: &impl ParserData) -> Result<Vec<EntityId>, AnyError> {
+    self.consume(TokenType::All, "Expected \"all\".")?;
+    let player_id = EntityId::from(data.get_player_id()?);
+    let mut result = data
+      .get_referents()?
+      .iter()
+      .filter(|referent| kind.is_included_in_all(referent, player_id))
+      .map(|referent| referent.entity_id)
+      .collect::<Vec<EntityId>>();
+    if self.r#match(vec![TokenType::But])? {
+      while let Some(entity_id) = self.match_noun_phrase(data)? {
+        result.retain(|candidate| *candidate != entity_id);
+        if !self.r#match(vec![TokenType::And, TokenType::Comma])? {
+          break;
+        }
+      }
     }
-    let adjectives = data.get_adjectives()?;
-    let lexeme = self.peek_next()?.lexeme;
-    let next = self.current + 1;
-    if adjectives.iter().any(|adjective| adjective == lexeme) {
-      self.tokens[next].r#type = TokenType::Adjective;
-      return Ok(true);
+    if result.is_empty() {
+      return Err(InputError::NothingForAll.into());
     }
-    Ok(false)
+    self.referent_ids = result.clone();
+    Ok(result)
   }
 
-  /// Look for a match to a noun.
-  pub fn match_noun(&mut self, data: &impl ParserData) -> Result<bool, AnyError> {
-    let nouns = data.get_nouns()?;
-    let lexeme = self.peek_next()?.lexeme;
-    let next = self.current + 1;
-    for (noun, entity_id) in nouns {
-      if noun == lexeme {
-        self.tokens[next].r#type = TokenType::Noun;
-        self.tokens[next].entity_id = Some(entity_id);
-        return Ok(true);
-      }
+  /// Resolve the pronoun at the current token, marking it as a noun.
+  pub fn resolve_pronoun(&mut self, referents: &[Referent]) -> Result<EntityId, AnyError> {
+    let token = &mut self.tokens[self.current];
+    let entity_id = self
+      .referent_ids
+      .iter()
+      .copied()
+      .find(|entity_id| referents.iter().any(|referent| referent.entity_id == *entity_id))
+      .ok_or_else(|| InputError::UnknownPronoun(token.lexeme.to_lowercase()))?;
+    token.r#type = TokenType::Noun;
+    token.entity_id = Some(entity_id);
+    self.current += 1;
+    Ok(entity_id)
+  }
+
+  /// Could this token be part of a noun phrase?
+  pub fn is_noun_phrase_token(&self, token: &Token, referents: &[Referent]) -> bool {
+    use TokenType::*;
+    if matches!(token.r#type, Adjective | Article | Genitive | Identifier | Noun) {
+      return true;
     }
-    Ok(false)
+    is_known_word(&token.lexeme.to_lowercase(), referents)
   }
 
-  /// Look for a match to a verb.
-  pub fn match_verb(&mut self) -> Result<bool, AnyError> {
-    Ok(self.peek()?.is_verb())
+  /// Resolve the noun phrase in the given range of tokens, marking them.
+  ///
+  /// Anything up to a genitive names the owner of the thing named by the rest
+  /// of the phrase, and both have to match.
+  pub fn resolve_noun_phrase(
+    &mut self,
+    start: usize,
+    end: usize,
+    referents: &[Referent],
+  ) -> Result<Option<EntityId>, AnyError> {
+    let genitive = self.tokens[start..end]
+      .iter()
+      .rposition(|token| token.r#type == TokenType::Genitive)
+      .map(|offset| start + offset);
+    let get_words = |tokens: &[Token]| {
+      tokens
+        .iter()
+        .filter(|token| token.r#type != TokenType::Article)
+        .map(get_noun_phrase_word)
+        .collect::<Vec<String>>()
+    };
+    let (owner_words, words) = match genitive {
+      Some(genitive) => (
+        Some(get_words(&self.tokens[start..=genitive])),
+        get_words(&self.tokens[genitive + 1..end]),
+      ),
+      None => (None, get_words(&self.tokens[start..end])),
+    };
+    if words.is_empty() {
+      return Ok(None);
+    }
+    let scores = referents
+      .iter()
+      .filter_map(|referent| {
+        let score = referent.get_score(&words)?;
+        match &owner_words {
+          Some(owner_words) => {
+            let owner = referents
+              .iter()
+              .find(|owner| Some(owner.entity_id) == referent.owner_id)?;
+            Some((score + owner.get_score(owner_words)?, referent))
+          },
+          None => Some((score, referent)),
+        }
+      })
+      .collect::<Vec<(usize, &Referent)>>();
+    let best_score = match scores.iter().map(|(score, _)| *score).max() {
+      Some(best_score) => best_score,
+      None => return Ok(None),
+    };
+    let best = scores
+      .into_iter()
+      .filter(|(score, _)| *score == best_score)
+      .map(|(_, referent)| referent.clone())
+      .collect::<Vec<Referent>>();
+    if best.len() > 1 {
+      let alternatives = best
+        .iter()
+        .map(|referent| referent.get_distinct_description(&best))
+        .collect();
+      return Err(InputError::AmbiguousNounPhrase(alternatives).into());
+    }
+    let referent = &best[0];
+    let phrase_start = genitive.map(|genitive| genitive + 1).unwrap_or(start);
+    for token in self.tokens[phrase_start..end].iter_mut() {
+      if token.r#type == TokenType::Article {
+        continue;
+      }
+      token.r#type = match referent.adjectives.contains(&get_noun_phrase_word(token)) {
+        true => TokenType::Adjective,
+        false => TokenType::Noun,
+      };
+    }
+    if let Some(genitive) = genitive {
+      self.tokens[genitive].entity_id = referent.owner_id;
+    }
+    // The last noun stands for the whole phrase.
+    self.tokens[end - 1].r#type = TokenType::Noun;
+    self.tokens[end - 1].entity_id = Some(referent.entity_id);
+    Ok(Some(referent.entity_id))
   }
 
   /// Look for a match of the current token.
@@ -134,16 +538,6 @@ impl<'input> Parser<'input> {
     Ok(false)
   }
 
-  /// Get the raw arguments to the command (for Echo, Eval, etc).
-  pub fn get_raw_arguments(&self) -> Result<String, AnyError> {
-    let result = self.tokens[1..]
-      .iter()
-      .map(|token| token.lexeme.to_string())
-      .collect::<Vec<String>>()
-      .join(" ");
-    Ok(result)
-  }
-
   /// Check that the next token is a specific type.
   pub fn check(&mut self, r#type: TokenType) -> Result<bool, AnyError> {
     if self.is_at_end()? {
@@ -201,3 +595,333 @@ impl<'input> Parser<'input> {
     bail!("{}", message);
   }
 }
+
+/// Get a token's lexeme as a word, e.g. "look" for "LOOK", or whatever it
+/// was corrected to.
+fn get_word(token: &Token) -> String {
+  match &token.literal {
+    Some(TokenLiteral::String(correction)) => correction.clone(),
+    _ => token.lexeme.to_lowercase(),
+  }
+}
+
+/// Get a token's lexeme as a word of a noun phrase, e.g. "goblin" for
+/// "Goblin's", or whatever it was corrected to.
+fn get_noun_phrase_word(token: &Token) -> String {
+  if let Some(TokenLiteral::String(correction)) = &token.literal {
+    return correction.clone();
+  }
+  let lexeme = token.lexeme.to_lowercase();
+  match token.r#type {
+    TokenType::Genitive => lexeme.trim_end_matches("'s").trim_end_matches('\'').to_string(),
+    _ => lexeme,
+  }
+}
+
+/// Get every word in any referent's vocabulary.
+fn get_vocabulary(referents: &[Referent]) -> Vec<String> {
+  let mut result = referents
+    .iter()
+    .flat_map(|referent| referent.nouns.iter().chain(referent.adjectives.iter()))
+    .flat_map(|vocabulary| vocabulary.split_whitespace())
+    .map(String::from)
+    .collect::<Vec<String>>();
+  result.sort();
+  result.dedup();
+  result
+}
+
+/// Is this word in any referent's vocabulary?
+fn is_known_word(word: &str, referents: &[Referent]) -> bool {
+  referents.iter().any(|referent| {
+    referent
+      .nouns
+      .iter()
+      .chain(referent.adjectives.iter())
+      .any(|vocabulary| vocabulary.split_whitespace().any(|known| known == word))
+  })
+}
+
+/// The parser data for an order, as seen by the actor it's addressed to.
+///
+/// Commands are built for whoever `get_player_id()` names, so this names the
+/// addressee; the things that can be referred to are still the player's.  It
+/// wraps a trait object so that parsing an order doesn't instantiate itself.
+struct Addressee<'data> {
+  data: &'data dyn ParserData,
+  addressee_id: EntityId,
+}
+
+impl<'data> ParserData for Addressee<'data> {
+  fn get_player_id(&self) -> Result<PlayerId, AnyError> {
+    Ok(PlayerId(self.addressee_id.0))
+  }
+
+  fn get_referents(&self) -> Result<Vec<Referent>, AnyError> {
+    self.data.get_referents()
+  }
+
+  fn get_exit_directions(&self) -> Result<Vec<Direction>, AnyError> {
+    self.data.get_exit_directions()
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::ecs::entity::PlayerId;
+  use crate::input::grammar::GrammarPattern;
+  use crate::input::Scanner;
+  use crate::test::*;
+
+  pub struct TestData {}
+
+  impl ParserData for TestData {
+    fn get_player_id(&self) -> Result<PlayerId, AnyError> {
+      Ok(PlayerId(0))
+    }
+
+    fn get_referents(&self) -> Result<Vec<Referent>, AnyError> {
+      let referent = |id, name: &str, nouns: &[&str], adjectives: &[&str], owner_id, is_an_object| Referent {
+        entity_id: EntityId(id),
+        name: name.to_string(),
+        nouns: nouns.iter().map(|noun| noun.to_string()).collect(),
+        adjectives: adjectives.iter().map(|adjective| adjective.to_string()).collect(),
+        owner_id,
+        container_id: owner_id,
+        is_an_object,
+      };
+      Ok(vec![
+        referent(1, "Goblin", &["goblin"], &["ugly", "green"], None, false),
+        referent(2, "Goblin", &["goblin"], &["ugly", "pale"], None, false),
+        referent(3, "Mushroom", &["mushroom"], &["speckled"], None, true),
+        referent(4, "Knife", &["knife"], &["rusty"], Some(EntityId(1)), true),
+        referent(5, "Ice Cream", &["ice cream"], &["melting"], None, true),
+        referent(6, "Lantern", &["lantern"], &["brass"], Some(EntityId(0)), true),
+        referent(7, "Coin", &["coin"], &["gold"], Some(EntityId(0)), true),
+      ])
+    }
+
+    fn get_exit_directions(&self) -> Result<Vec<Direction>, AnyError> {
+      Ok(vec![Direction::North, Direction::Northeast, Direction::South])
+    }
+  }
+
+  /// Parse the input and get the entity its noun phrase refers to.
+  pub fn get_target(input: &str) -> Result<Option<EntityId>, AnyError> {
+    let mut scanner = Scanner::new(input);
+    scanner.scan_tokens()?;
+    let mut parser = Parser::new(scanner.tokens, input);
+    parser.parse(&TestData {})?;
+    Ok(parser.referent_ids.first().copied())
+  }
+
+  #[test]
+  pub fn test_noun_phrases() {
+    init();
+    assert_eq!(get_target("look at the speckled mushroom").unwrap(), Some(EntityId(3)));
+    assert_eq!(get_target("look mushroom").unwrap(), Some(EntityId(3)));
+    assert_eq!(get_target("look at the green goblin").unwrap(), Some(EntityId(1)));
+    assert_eq!(get_target("look at the ugly pale goblin").unwrap(), Some(EntityId(2)));
+    assert_eq!(get_target("look at the melting ice cream").unwrap(), Some(EntityId(5)));
+    assert_eq!(
+      get_target("look at the goblin's rusty knife").unwrap(),
+      Some(EntityId(4))
+    );
+  }
+
+  #[test]
+  pub fn test_unknown_words() {
+    init();
+    let error = get_target("look at the purple mushroom").unwrap_err();
+    assert_eq!(error.to_string(), "I don't know the word \"purple\".");
+    assert!(error.is::<InputError>());
+    // Known words that just don't go together aren't a matter of spelling.
+    assert_eq!(get_target("look at the rusty mushroom").unwrap(), None);
+    assert!(get_target("eval 2 + 2;").is_ok());
+  }
+
+  #[test]
+  pub fn test_misspellings() {
+    init();
+    let input = "loook at the speckled mushrom";
+    let mut scanner = Scanner::new(input);
+    scanner.scan_tokens().unwrap();
+    assert_eq!(scanner.corrections, vec![("loook".to_string(), "look".to_string())]);
+    let mut parser = Parser::new(scanner.tokens, input);
+    parser.parse(&TestData {}).unwrap();
+    assert_eq!(parser.referent_ids, vec![EntityId(3)]);
+    assert_eq!(
+      parser.corrections,
+      vec![("mushrom".to_string(), "mushroom".to_string())]
+    );
+    let error = get_target("look at the rusty nief").unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      "I don't know the word \"nief\". Did you mean \"knife\"?"
+    );
+    let error = get_target("xyzzy").unwrap_err();
+    assert_eq!(error.to_string(), "I don't know the word \"xyzzy\".");
+  }
+
+  #[test]
+  pub fn test_syntaxes() {
+    init();
+    let get_command = |input: &str| -> Result<String, AnyError> {
+      let mut scanner = Scanner::new(input);
+      scanner.scan_tokens()?;
+      let mut parser = Parser::new(scanner.tokens, input);
+      Ok(format!("{:?}", parser.parse(&TestData {})?[0]))
+    };
+    assert!(get_command("x").unwrap().contains("LookAround"));
+    assert!(get_command("l ne").unwrap().contains("direction: Northeast"));
+    assert!(get_command("examine at the mushroom")
+      .unwrap()
+      .contains("target_entity_id: Id(3)"));
+    assert!(get_command("save goat.sav").unwrap().contains("path: \"goat.sav\""));
+    assert!(get_command("look under the mushroom")
+      .unwrap()
+      .contains("preposition: Under"));
+    assert!(get_command("put the mushroom on the green goblin")
+      .unwrap()
+      .contains("target_entity_id: Id(3), container_entity_id: Id(1)"));
+    assert!(get_command("place the mushroom into the green goblin")
+      .unwrap()
+      .contains("PutIn"));
+    let error = get_command("put the mushroom behind the green goblin").unwrap_err();
+    assert_eq!(error.to_string(), "You can't put anything behind anything else.");
+    let error = get_command("look with the mushroom").unwrap_err();
+    assert_eq!(error.to_string(), "I only understood you as far as wanting to look.");
+    let error = get_command("go").unwrap_err();
+    assert_eq!(error.to_string(), "I only understood you as far as wanting to go.");
+    let error = get_command("the").unwrap_err();
+    assert_eq!(error.to_string(), "I didn't understand that.");
+  }
+
+  #[test]
+  pub fn test_prepositional_phrases() {
+    init();
+    let input = "the speckled mushroom on the green goblin";
+    let mut scanner = Scanner::new(input);
+    scanner.scan_tokens().unwrap();
+    let mut parser = Parser::new(scanner.tokens, input);
+    let syntax = GrammarSyntax {
+      pattern: GrammarPattern::from_str("NOUN PREPOSITION NOUN").unwrap(),
+      command: CommandKind::Put,
+    };
+    let (arguments, all) = parser.match_pattern(&syntax, &TestData {}).unwrap().unwrap();
+    assert_eq!(arguments.entity_ids, vec![Some(EntityId(3)), Some(EntityId(1))]);
+    assert_eq!(arguments.preposition, Some(Preposition::On));
+    assert_eq!(all, None);
+    // The preposition has to be there, or the pattern doesn't fit.
+    let input = "the speckled mushroom the green goblin";
+    let mut scanner = Scanner::new(input);
+    scanner.scan_tokens().unwrap();
+    let mut parser = Parser::new(scanner.tokens, input);
+    assert_eq!(parser.match_pattern(&syntax, &TestData {}).unwrap(), None);
+  }
+
+  #[test]
+  pub fn test_orders() {
+    init();
+    let input = "green goblin, look at it";
+    let mut scanner = Scanner::new(input);
+    scanner.scan_tokens().unwrap();
+    let mut parser = Parser::new(scanner.tokens, input);
+    let commands = parser.parse(&TestData {}).unwrap();
+    assert_eq!(commands.len(), 1);
+    // The command is the goblin's to carry out, and "it" is the goblin.
+    let debug = format!("{:?}", commands[0]);
+    assert!(debug.contains("addressee_id: Id(1)"));
+    assert!(debug.contains("order: \"look at it\""));
+    assert!(debug.contains("LookAtEntity { player_id: Id(1), target_entity_id: Id(1)"));
+    assert_eq!(parser.referent_ids, vec![EntityId(1)]);
+    let input = "mushroom, save";
+    let mut scanner = Scanner::new(input);
+    scanner.scan_tokens().unwrap();
+    let mut parser = Parser::new(scanner.tokens, input);
+    let error = parser.parse(&TestData {}).unwrap_err();
+    assert_eq!(error.to_string(), "You can't tell mushroom to do that.");
+  }
+
+  /// Get the input for each command in a line.
+  pub fn get_commands(input: &str) -> Vec<&str> {
+    let mut scanner = Scanner::new(input);
+    scanner.scan_tokens().unwrap();
+    let parser = Parser::new(scanner.tokens, input);
+    parser
+      .get_command_ranges()
+      .into_iter()
+      .map(|(start, end)| parser.get_input_between(start, end))
+      .collect()
+  }
+
+  #[test]
+  pub fn test_compound_commands() {
+    init();
+    assert_eq!(get_commands("look"), vec!["look"]);
+    assert_eq!(get_commands("n then look"), vec!["n", "look"]);
+    assert_eq!(get_commands("n. look at the goblin."), vec!["n", "look at the goblin"]);
+    assert_eq!(get_commands("n, e and look"), vec!["n", "e", "look"]);
+    assert_eq!(
+      get_commands("goblin, go north then look"),
+      vec!["goblin, go north", "look"]
+    );
+    assert_eq!(
+      get_commands("save goat.sav then speed 2.5x"),
+      vec!["save goat.sav", "speed 2.5x"]
+    );
+    assert!(get_commands("").is_empty());
+    // Pronouns can refer to something named earlier in the same line.
+    let mut scanner = Scanner::new("look at the mushroom then look at it");
+    scanner.scan_tokens().unwrap();
+    let mut parser = Parser::new(scanner.tokens, "look at the mushroom then look at it");
+    assert_eq!(parser.parse(&TestData {}).unwrap().len(), 2);
+    assert_eq!(parser.referent_ids, vec![EntityId(3)]);
+  }
+
+  /// Parse the input and get everything it referred to.
+  pub fn get_objects(input: &str) -> Result<Vec<EntityId>, AnyError> {
+    let mut scanner = Scanner::new(input);
+    scanner.scan_tokens()?;
+    let mut parser = Parser::new(scanner.tokens, input);
+    parser.parse(&TestData {})?;
+    Ok(parser.referent_ids)
+  }
+
+  #[test]
+  pub fn test_all() {
+    init();
+    let everything = (1..=7).map(EntityId).collect::<Vec<EntityId>>();
+    assert_eq!(get_objects("look at all").unwrap(), everything);
+    assert_eq!(get_objects("examine everything").unwrap(), everything);
+    assert_eq!(
+      get_objects("look at all but the mushroom and the rusty knife").unwrap(),
+      vec![EntityId(1), EntityId(2), EntityId(5), EntityId(6), EntityId(7)]
+    );
+    assert_eq!(
+      get_objects("x all except the ice cream, the green goblin").unwrap(),
+      vec![EntityId(2), EntityId(3), EntityId(4), EntityId(6), EntityId(7)]
+    );
+    let error = get_objects("look at all but the ugly goblin").unwrap_err();
+    assert!(error.is::<InputError>());
+    // Only what's lying around can be taken, and only what's carried dropped.
+    assert_eq!(get_objects("take all").unwrap(), vec![EntityId(3), EntityId(5)]);
+    assert_eq!(get_objects("drop all").unwrap(), vec![EntityId(6), EntityId(7)]);
+    assert_eq!(get_objects("drop all but the lantern").unwrap(), vec![EntityId(7)]);
+    let error = get_objects("drop all but the lantern and the coin").unwrap_err();
+    assert!(error.is::<InputError>());
+  }
+
+  #[test]
+  pub fn test_ambiguous_noun_phrases() {
+    init();
+    let error = get_target("look at the ugly goblin").unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      "Which do you mean, the green goblin or the pale goblin?"
+    );
+    assert!(error.is::<InputError>());
+  }
+}
@@ -0,0 +1,45 @@
+use anyhow::Error as AnyError;
+use std::str::FromStr;
+
+/// The `Preposition` enum.
+///
+/// How the object of a prepositional phrase relates to the rest of the
+/// command, e.g. "on" in "put the mushroom on the rock".
+#[derive(Clone, Copy, Debug, Deserialize, Display, Eq, Hash, PartialEq, Serialize)]
+pub enum Preposition {
+  #[display(fmt = "at")]
+  At,
+  #[display(fmt = "behind")]
+  Behind,
+  #[display(fmt = "from")]
+  From,
+  #[display(fmt = "in")]
+  In,
+  #[display(fmt = "on")]
+  On,
+  #[display(fmt = "to")]
+  To,
+  #[display(fmt = "under")]
+  Under,
+  #[display(fmt = "with")]
+  With,
+}
+
+impl FromStr for Preposition {
+  type Err = AnyError;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    use Preposition::*;
+    match string {
+      "at" => Ok(At),
+      "behind" => Ok(Behind),
+      "from" => Ok(From),
+      "in" | "inside" | "into" => Ok(In),
+      "on" | "onto" | "atop" | "upon" => Ok(On),
+      "to" => Ok(To),
+      "under" | "beneath" | "below" => Ok(Under),
+      "with" => Ok(With),
+      unknown => Err(anyhow!("Unknown preposition {}", unknown)),
+    }
+  }
+}
@@ -0,0 +1,127 @@
+use crate::ecs::entity::EntityId;
+
+/// The `Referent` type.
+///
+/// Something in the world that a player might refer to, along with the words
+/// they might use to refer to it.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Referent {
+  pub entity_id: EntityId,
+  /// What the entity is called, e.g. "Goblin".
+  pub name: String,
+  /// The nouns that refer to the entity, in lowercase, e.g. "ice cream".
+  pub nouns: Vec<String>,
+  /// The adjectives that describe the entity, in lowercase, e.g. "green".
+  pub adjectives: Vec<String>,
+  /// Whoever has the entity, for genitives like "the goblin's knife".
+  pub owner_id: Option<EntityId>,
+  /// Whatever the entity is directly in or on, or carried by, if anything.
+  pub container_id: Option<EntityId>,
+  /// Whether the entity is an object, rather than e.g. an actor or a door.
+  pub is_an_object: bool,
+}
+
+impl Referent {
+  /// Score how well a phrase refers to this.
+  ///
+  /// The words should be in lowercase, without articles.  The phrase has to
+  /// end in one of our nouns, and every other word has to be one of our
+  /// adjectives, or else it doesn't refer to us at all.  Nouns count for more
+  /// than adjectives, so "ice cream" prefers the ice cream to the cream.
+  pub fn get_score(&self, words: &[String]) -> Option<usize> {
+    self
+      .nouns
+      .iter()
+      .filter_map(|noun| {
+        let noun_words = noun.split_whitespace().collect::<Vec<&str>>();
+        if noun_words.is_empty() || noun_words.len() > words.len() {
+          return None;
+        }
+        let (adjectives, tail) = words.split_at(words.len() - noun_words.len());
+        if tail
+          .iter()
+          .zip(noun_words.iter())
+          .any(|(word, noun_word)| word != noun_word)
+        {
+          return None;
+        }
+        if !adjectives.iter().all(|adjective| self.adjectives.contains(adjective)) {
+          return None;
+        }
+        Some(2 * noun_words.len() + adjectives.len())
+      })
+      .max()
+  }
+
+  /// Describe this so as to tell it apart from the others, e.g. "the green
+  /// goblin" rather than just "the goblin".
+  pub fn get_distinct_description(&self, others: &[Referent]) -> String {
+    let name = self.name.to_lowercase();
+    let adjective = self.adjectives.iter().find(|adjective| {
+      !name.split_whitespace().any(|word| word == adjective.as_str())
+        && others
+          .iter()
+          .filter(|other| other.entity_id != self.entity_id)
+          .all(|other| !other.adjectives.contains(adjective))
+    });
+    match adjective {
+      Some(adjective) => format!("the {} {}", adjective, name),
+      None => format!("the {}", name),
+    }
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  pub fn get_words(phrase: &str) -> Vec<String> {
+    phrase.split_whitespace().map(String::from).collect()
+  }
+
+  #[test]
+  pub fn test_get_score() {
+    init();
+    let ice_cream = Referent {
+      entity_id: EntityId(1),
+      name: "Ice Cream".into(),
+      nouns: vec!["ice cream".into(), "dessert".into()],
+      adjectives: vec!["melting".into()],
+      owner_id: None,
+      container_id: None,
+      is_an_object: true,
+    };
+    assert_eq!(ice_cream.get_score(&get_words("dessert")), Some(2));
+    assert_eq!(ice_cream.get_score(&get_words("melting ice cream")), Some(5));
+    assert_eq!(ice_cream.get_score(&get_words("frozen ice cream")), None);
+    assert_eq!(ice_cream.get_score(&get_words("ice")), None);
+  }
+
+  #[test]
+  pub fn test_get_distinct_description() {
+    init();
+    let green = Referent {
+      entity_id: EntityId(1),
+      name: "Goblin".into(),
+      nouns: vec!["goblin".into()],
+      adjectives: vec!["ugly".into(), "green".into()],
+      owner_id: None,
+      container_id: None,
+      is_an_object: true,
+    };
+    let pale = Referent {
+      entity_id: EntityId(2),
+      name: "Goblin".into(),
+      nouns: vec!["goblin".into()],
+      adjectives: vec!["ugly".into(), "pale".into()],
+      owner_id: None,
+      container_id: None,
+      is_an_object: true,
+    };
+    let both = vec![green.clone(), pale.clone()];
+    assert_eq!(green.get_distinct_description(&both), "the green goblin");
+    assert_eq!(pale.get_distinct_description(&both), "the pale goblin");
+  }
+}
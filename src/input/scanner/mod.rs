@@ -1,3 +1,5 @@
+use super::grammar::GRAMMAR;
+use super::spelling;
 use super::token::{Token, TokenLiteral, TokenType};
 use anyhow::Error as AnyError;
 use std::str::FromStr;
@@ -11,6 +13,8 @@ pub struct Scanner<'input> {
   pub current: usize,
   pub input: &'input str,
   pub input_bytes: Vec<u8>,
+  /// Misspelled words that were taken to be something else, and what.
+  pub corrections: Vec<(String, String)>,
 }
 
 impl<'input> Scanner<'input> {
@@ -20,12 +24,14 @@ impl<'input> Scanner<'input> {
     let current = 0;
     let input_bytes = input.as_bytes().to_vec();
     let tokens = Vec::new();
+    let corrections = Vec::new();
     Self {
       start,
       current,
       input,
       input_bytes,
       tokens,
+      corrections,
     }
   }
 
@@ -51,7 +57,7 @@ impl<'input> Scanner<'input> {
     match char {
       '&' => self.add_token(Ampersand, None),
       '*' => self.add_token(Asterisk, None),
-      '@' => self.add_token(At, None),
+      '@' => self.add_token(AtSign, None),
       '\\' => self.add_token(BackSlash, None),
       '^' => self.add_token(Caret, None),
       ':' => self.add_token(Colon, None),
@@ -117,7 +123,9 @@ impl<'input> Scanner<'input> {
       self.advance()?;
     }
     let value = &self.input[self.start..self.current];
-    let parsed = value.parse::<i32>().unwrap();
+    let parsed = value
+      .parse::<i32>()
+      .map_err(|_| anyhow!("the number {} is too large", value))?;
     let number = TokenLiteral::Number(parsed);
     self.add_token(TokenType::Number, Some(number));
     Ok(())
@@ -151,17 +159,43 @@ impl<'input> Scanner<'input> {
       }
     }
     let value = &self.input[self.start..self.current];
-    let value_type = match TokenType::from_str(value) {
-      Ok(token_type) => token_type,
-      Err(_) => match genitive_flag {
-        true => TokenType::Genitive,
-        false => TokenType::Identifier,
+    match TokenType::from_str(value) {
+      Ok(token_type) => self.add_token(token_type, None),
+      Err(_) if genitive_flag => self.add_token(TokenType::Genitive, None),
+      Err(_) => match self.correct_command_word(value) {
+        // The correction is kept as the literal, since the lexeme has to
+        // remain a slice of the input.
+        Some(word) => {
+          let token_type = TokenType::from_str(&word).unwrap_or(TokenType::Identifier);
+          self.add_token(token_type, Some(TokenLiteral::String(word)));
+        },
+        None => self.add_token(TokenType::Identifier, None),
       },
-    };
-    self.add_token(value_type, None);
+    }
     Ok(())
   }
 
+  /// Correct a misspelled word at the beginning of a command, e.g. "lok".
+  ///
+  /// Anywhere else, the word is more likely to be a misspelled noun, which
+  /// the parser will have to deal with.  A separator has to be followed by a
+  /// space, so that e.g. the extension in "save goat.sav" is left alone.
+  pub fn correct_command_word(&mut self, value: &str) -> Option<String> {
+    use TokenType::*;
+    let begins_command = match self.tokens.last() {
+      Some(token) => {
+        matches!(token.r#type, And | Comma | Period | Then) && self.input_bytes[self.start - 1].is_ascii_whitespace()
+      },
+      None => true,
+    };
+    if !begins_command {
+      return None;
+    }
+    let word = spelling::correct(value, GRAMMAR.get_command_words())?.to_string();
+    self.corrections.push((value.to_string(), word.clone()));
+    Some(word)
+  }
+
   /// Is it a digit?
   pub fn is_digit(&self, char: char) -> bool {
     ('0'..='9').contains(&char)
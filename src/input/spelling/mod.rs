@@ -0,0 +1,134 @@
+/// Get the edit distance between two words.
+///
+/// This is the number of letters that have to be inserted, deleted, replaced,
+/// or swapped with their neighbor to turn one word into the other, so that
+/// "lok" is one away from "look" and "goblni" one away from "goblin".
+pub fn get_edit_distance(first: &str, second: &str) -> usize {
+  let first = first.chars().collect::<Vec<char>>();
+  let second = second.chars().collect::<Vec<char>>();
+  // Each row holds the distances from a prefix of the first word to every
+  // prefix of the second; we only ever need the last two rows.
+  let mut before_previous = vec![0; second.len() + 1];
+  let mut previous = (0..=second.len()).collect::<Vec<usize>>();
+  for i in 1..=first.len() {
+    let mut current = vec![i; second.len() + 1];
+    for j in 1..=second.len() {
+      let cost = usize::from(first[i - 1] != second[j - 1]);
+      current[j] = (previous[j] + 1).min(current[j - 1] + 1).min(previous[j - 1] + cost);
+      if i > 1 && j > 1 && first[i - 1] == second[j - 2] && first[i - 2] == second[j - 1] {
+        current[j] = current[j].min(before_previous[j - 2] + 1);
+      }
+    }
+    before_previous = previous;
+    previous = current;
+  }
+  previous[second.len()]
+}
+
+/// Get how many mistakes a word can have and still be recognized.
+///
+/// Short words have so many neighbors that any guess would be a wild one.
+pub fn get_tolerance(word: &str) -> usize {
+  match word.chars().count() {
+    0..=2 => 0,
+    3..=5 => 1,
+    _ => 2,
+  }
+}
+
+/// Get how many mistakes a word can have and still be worth suggesting.
+///
+/// This reaches a little further than the tolerance, since the player gets
+/// to choose among the suggestions.
+pub fn get_reach(word: &str) -> usize {
+  match word.chars().count() {
+    0..=2 => 1,
+    3..=7 => 2,
+    _ => 3,
+  }
+}
+
+/// Correct a misspelled word, if there's one obvious candidate for it.
+///
+/// The candidate has to be within the word's tolerance, and closer to it than
+/// any other candidate.
+pub fn correct<'a>(word: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+  let word = word.to_lowercase();
+  let mut best: Option<(usize, &str)> = None;
+  let mut is_tied = false;
+  for candidate in candidates {
+    let distance = get_edit_distance(&word, candidate);
+    match best {
+      Some((best_distance, best_candidate)) if distance == best_distance && candidate != best_candidate => {
+        is_tied = true;
+      },
+      Some((best_distance, _)) if distance >= best_distance => {},
+      _ => {
+        best = Some((distance, candidate));
+        is_tied = false;
+      },
+    }
+  }
+  match best {
+    Some((distance, candidate)) if distance > 0 && distance <= get_tolerance(&word) && !is_tied => Some(candidate),
+    _ => None,
+  }
+}
+
+/// Suggest the words that might have been meant, closest first.
+pub fn suggest<'a>(word: &str, candidates: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+  let word = word.to_lowercase();
+  let reach = get_reach(&word);
+  let mut result = candidates
+    .into_iter()
+    .map(|candidate| (get_edit_distance(&word, candidate), candidate))
+    .filter(|(distance, _)| *distance > 0 && *distance <= reach)
+    .collect::<Vec<(usize, &str)>>();
+  result.sort();
+  result.dedup();
+  result
+    .into_iter()
+    .take(MAXIMUM_SUGGESTIONS)
+    .map(|(_, candidate)| candidate.to_string())
+    .collect()
+}
+
+/// The most words we'll suggest for a misspelling.
+pub const MAXIMUM_SUGGESTIONS: usize = 3;
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_get_edit_distance() {
+    init();
+    assert_eq!(get_edit_distance("look", "look"), 0);
+    assert_eq!(get_edit_distance("lok", "look"), 1);
+    assert_eq!(get_edit_distance("goblni", "goblin"), 1);
+    assert_eq!(get_edit_distance("kitten", "sitting"), 3);
+    assert_eq!(get_edit_distance("", "save"), 4);
+  }
+
+  #[test]
+  pub fn test_correct() {
+    init();
+    let candidates = ["look", "load", "save", "goblin"];
+    assert_eq!(correct("lok", candidates), Some("look"));
+    assert_eq!(correct("Goblni", candidates), Some("goblin"));
+    // Too short to guess at, too far from anything, or too close to a tie.
+    assert_eq!(correct("lo", candidates), None);
+    assert_eq!(correct("xyzzy", candidates), None);
+    assert_eq!(correct("loak", candidates), None);
+  }
+
+  #[test]
+  pub fn test_suggest() {
+    init();
+    let candidates = ["look", "load", "save", "goblin"];
+    assert_eq!(suggest("loak", candidates), vec!["load", "look"]);
+    assert!(suggest("xyzzy", candidates).is_empty());
+  }
+}
@@ -15,9 +15,3 @@ pub struct Token<'input> {
   pub literal: Option<Literal>,
   pub entity_id: Option<EntityId>,
 }
-
-impl<'input> Token<'input> {
-  pub fn is_verb(&self) -> bool {
-    self.r#type.is_verb()
-  }
-}
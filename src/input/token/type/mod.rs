@@ -1,6 +1,24 @@
 use anyhow::Error as AnyError;
 use std::str::FromStr;
 
+/// The directions in full, for correcting misspellings of them.
+///
+/// Abbreviations like "ne" are left out, since they're too short to tell a
+/// typo from something else.
+pub const DIRECTION_WORDS: &[&str] = &[
+  "down",
+  "east",
+  "inside",
+  "north",
+  "northeast",
+  "northwest",
+  "outside",
+  "south",
+  "southeast",
+  "southwest",
+  "west",
+];
+
 /// The `Type` enum.
 #[derive(Clone, Copy, Debug, Deserialize, Display, Eq, Hash, PartialEq, Serialize)]
 pub enum Type {
@@ -11,7 +29,6 @@ pub enum Type {
   And,
   Article,
   Asterisk,
-  At,
   AtSign,
   BackSlash,
   But,
@@ -22,14 +39,11 @@ pub enum Type {
   Direction,
   Dollar,
   DoubleQuotation,
-  Echo,
   Eof,
   Equals,
-  Eval,
   ExclamationPoint,
   ForwardSlash,
   Genitive,
-  Go,
   GreaterThan,
   Identifier,
   Idle,
@@ -38,10 +52,8 @@ pub enum Type {
   LeftParenthesis,
   LessThan,
   Literal,
-  Look,
   Noun,
   Number,
-  On,
   Oops,
   Other,
   Percent,
@@ -49,7 +61,8 @@ pub enum Type {
   Pipe,
   Plus,
   Pound,
-  Quit,
+  Preposition,
+  Pronoun,
   RightBrace,
   RightCurlyBrace,
   RightParenthesis,
@@ -57,7 +70,6 @@ pub enum Type {
   SingleQuotation,
   String,
   Then,
-  Under,
   Underscore,
   Question,
 }
@@ -75,10 +87,9 @@ impl FromStr for Type {
       "a" | "an" | "the" | "some" => Ok(Article),
       "then" => Ok(Then),
       "*" => Ok(Asterisk),
-      "at" => Ok(At),
       "@" => Ok(AtSign),
       "\\" => Ok(BackSlash),
-      "but" => Ok(But),
+      "but" | "except" => Ok(But),
       "^" => Ok(Caret),
       ":" => Ok(Colon),
       "," => Ok(Comma),
@@ -88,18 +99,14 @@ impl FromStr for Type {
       "$" => Ok(Dollar),
       "\"" => Ok(DoubleQuotation),
       "=" => Ok(Equals),
-      "eval" => Ok(Eval),
       "!" => Ok(ExclamationPoint),
       "/" => Ok(ForwardSlash),
-      "go" | "walk" => Ok(Go),
       ">" => Ok(GreaterThan),
       "idle" | "z" => Ok(Idle),
       "[" => Ok(LeftBrace),
       "{" => Ok(LeftCurlyBrace),
       "(" => Ok(LeftParenthesis),
       "<" => Ok(LessThan),
-      "look" | "l" => Ok(Look),
-      "atop" | "on" => Ok(On),
       "oops" => Ok(Oops),
       "other" => Ok(Other),
       "%" => Ok(Percent),
@@ -107,13 +114,15 @@ impl FromStr for Type {
       "|" => Ok(Pipe),
       "+" => Ok(Plus),
       "#" => Ok(Pound),
-      "quit" => Ok(Quit),
+      // "In" and "inside" are directions too, so they're left to those.
+      "at" | "atop" | "behind" | "below" | "beneath" | "from" | "into" | "on" | "onto" | "to" | "under" | "upon"
+      | "with" => Ok(Preposition),
+      "it" | "them" | "him" | "her" => Ok(Pronoun),
       "]" => Ok(RightBrace),
       "}" => Ok(RightCurlyBrace),
       ")" => Ok(RightParenthesis),
       ";" => Ok(Semicolon),
       "'" => Ok(SingleQuotation),
-      "under" | "beneath" => Ok(Under),
       "_" => Ok(Underscore),
       "?" => Ok(Question),
       unknown => Err(anyhow!("Unknown keyword {}", unknown)),
@@ -122,8 +131,26 @@ impl FromStr for Type {
 }
 
 impl Type {
-  pub fn is_verb(&self) -> bool {
+  /// Can a command begin with this, whatever the grammar says?
+  ///
+  /// A command can also begin with any of the grammar's verbs.
+  pub fn begins_command(&self) -> bool {
     use Type::*;
-    matches!(self, Again | Echo | Eval | Go | Look)
+    matches!(self, Again | Direction)
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_direction_words() {
+    init();
+    for word in DIRECTION_WORDS {
+      assert_eq!(Type::from_str(word).unwrap(), Type::Direction, "{}", word);
+    }
   }
 }
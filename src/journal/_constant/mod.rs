@@ -0,0 +1,5 @@
+/// The version of the journal format.
+pub const JOURNAL_VERSION: u32 = 2;
+
+/// How many of the most recent player turns can be undone.
+pub const MAXIMUM_UNDO_TURNS: usize = 100;
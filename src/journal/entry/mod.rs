@@ -0,0 +1,14 @@
+use crate::effect::EffectRecord;
+
+/// The `Entry` enum.
+///
+/// A single line in the journal.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum Entry {
+  /// The player entered a command that led to an action.
+  Turn { tick: u64 },
+  /// An effect was processed.
+  Effect { tick: u64, effect: EffectRecord },
+  /// The most recent turn was undone.
+  Undo { tick: u64 },
+}
@@ -0,0 +1,19 @@
+use serde_json::Error as JsonError;
+use std::io::Error as IoError;
+
+/// Errors encountered reading or writing a journal.
+#[derive(Debug, Error)]
+pub enum Error {
+  /// An I/O error occurred.
+  #[error("an I/O error occurred ({0})")]
+  IoError(#[from] IoError),
+  /// An entry could not be serialized or deserialized.
+  #[error("a JSON error occurred ({0})")]
+  JsonError(#[from] JsonError),
+  /// The journal was written by an incompatible version of the game.
+  #[error("unsupported journal version ({0})")]
+  UnsupportedVersion(u32),
+  /// The journal has no header.
+  #[error("the journal is empty")]
+  Empty,
+}
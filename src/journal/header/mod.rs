@@ -0,0 +1,13 @@
+use crate::game::GameSettings;
+
+/// The `Header` type.
+///
+/// The first line of a journal file; this is what's needed to recreate the
+/// world in which the journal was recorded.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Header {
+  /// The version of the journal format.
+  pub version: u32,
+  /// The settings with which the world was created.
+  pub settings: GameSettings,
+}
@@ -0,0 +1,126 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use crate::effect::{Effect, Effectable};
+
+pub mod _constant;
+pub use _constant::*;
+pub mod entry;
+pub use entry::Entry as JournalEntry;
+use entry::Entry;
+pub mod error;
+pub use error::Error as JournalError;
+use error::Error;
+pub mod header;
+pub use header::Header as JournalHeader;
+use header::Header;
+pub mod turn;
+pub use turn::Turn;
+pub mod writer;
+pub use writer::Writer as JournalWriter;
+
+/// The `Journal` type.
+///
+/// Every change to the world flows through an `Effect`, so a list of all of
+/// the effects processed, in order, is enough to rebuild a session from the
+/// world's initial state.  Turn markers divide the effects into the player's
+/// turns, which can be undone.
+#[derive(Clone, Debug, Default)]
+pub struct Journal {
+  /// Everything that has happened, in order, including undos, since the
+  /// journal was last written out.
+  pub entries: Vec<Entry>,
+  /// The turns that can still be undone, most recent last.
+  pub turns: Vec<Turn>,
+}
+
+impl Journal {
+  /// Note the start of a new player turn.
+  ///
+  /// Only so many turns are kept; the oldest can no longer be undone.
+  pub fn record_turn(&mut self, tick: u64) {
+    self.entries.push(Entry::Turn { tick });
+    self.turns.push(Turn {
+      tick,
+      inverses: Vec::new(),
+    });
+    self.turns.drain(..self.turns.len().saturating_sub(MAXIMUM_UNDO_TURNS));
+  }
+
+  /// Note that an effect was processed, along with the effects that undo it.
+  ///
+  /// Effects processed before the first turn (e.g. while the map is being
+  /// built) are recorded, but can't be undone.
+  pub fn record_effect(&mut self, tick: u64, effect: &Effect, inverse: Vec<Effect>) {
+    self.entries.push(Entry::Effect {
+      tick,
+      effect: effect.get_record(),
+    });
+    if let Some(turn) = self.turns.last_mut() {
+      turn.inverses.push(inverse);
+    }
+  }
+
+  /// Undo the most recent turn.
+  ///
+  /// This returns the effects that should be processed to revert the world
+  /// to the start of the turn, in the order they should be processed, or
+  /// `None` if there's nothing left to undo.
+  pub fn undo(&mut self, tick: u64) -> Option<Vec<Effect>> {
+    let turn = self.turns.pop()?;
+    self.entries.push(Entry::Undo { tick });
+    Some(turn.inverses.into_iter().rev().flatten().collect())
+  }
+
+  /// Forget the turns recorded so far, so that they can't be undone.
+  ///
+  /// This is necessary when the world is replaced wholesale, e.g. restored
+  /// from a snapshot.
+  pub fn forget_turns(&mut self) {
+    self.turns.clear();
+  }
+
+  /// Read a journal file, returning the header and the entries.
+  pub fn read_from_file<P: AsRef<Path>>(path: P) -> Result<(Header, Vec<Entry>), Error> {
+    let mut lines = BufReader::new(File::open(path)?).lines();
+    let header: Header = match lines.next() {
+      Some(line) => serde_json::from_str(&line?)?,
+      None => return Err(Error::Empty),
+    };
+    if header.version != JOURNAL_VERSION {
+      return Err(Error::UnsupportedVersion(header.version));
+    }
+    let mut entries = Vec::new();
+    for line in lines {
+      let line = line?;
+      if !line.trim().is_empty() {
+        entries.push(serde_json::from_str(&line)?);
+      }
+    }
+    Ok((header, entries))
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_undo_window() {
+    init();
+    let mut journal = Journal::default();
+    let turn_count = MAXIMUM_UNDO_TURNS as u64 + 5;
+    for tick in 0..turn_count {
+      journal.record_turn(tick);
+    }
+    assert_eq!(journal.turns.len(), MAXIMUM_UNDO_TURNS);
+    assert_eq!(journal.turns[0].tick, 5);
+    for _ in 0..MAXIMUM_UNDO_TURNS {
+      assert!(journal.undo(turn_count).is_some());
+    }
+    assert!(journal.undo(turn_count).is_none());
+  }
+}
@@ -0,0 +1,13 @@
+use crate::effect::Effect;
+
+/// The `Turn` type.
+///
+/// This tracks what it would take to undo a single player turn.
+#[derive(Clone, Debug, Default)]
+pub struct Turn {
+  /// The tick on which the turn began.
+  pub tick: u64,
+  /// The inverses of the effects processed during the turn, in the order in
+  /// which the effects themselves were processed.
+  pub inverses: Vec<Vec<Effect>>,
+}
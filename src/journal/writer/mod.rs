@@ -0,0 +1,52 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use super::error::Error;
+use super::{Header, Journal, JOURNAL_VERSION};
+use crate::game::GameSettings;
+
+/// The `Writer` type.
+///
+/// This appends journal entries to a file, one JSON object per line, as they
+/// are recorded.  Nothing is rewritten, so an interrupted session still
+/// leaves a usable journal behind.
+#[derive(Debug)]
+pub struct Writer {
+  /// The file being written.
+  pub writer: BufWriter<File>,
+  /// How many entries have been written so far.
+  pub written: usize,
+}
+
+impl Writer {
+  /// Create the file and write the header.
+  pub fn new<P: AsRef<Path>>(path: P, settings: &GameSettings) -> Result<Self, Error> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    let header = Header {
+      version: JOURNAL_VERSION,
+      settings: settings.clone(),
+    };
+    serde_json::to_writer(&mut writer, &header)?;
+    writeln!(writer)?;
+    writer.flush()?;
+    Ok(Self { writer, written: 0 })
+  }
+
+  /// Write any entries that haven't been written yet.
+  ///
+  /// Once they're safely in the file, the journal lets go of them.
+  pub fn write_new_entries(&mut self, journal: &mut Journal) -> Result<(), Error> {
+    if journal.entries.is_empty() {
+      return Ok(());
+    }
+    for entry in journal.entries.iter() {
+      serde_json::to_writer(&mut self.writer, entry)?;
+      writeln!(self.writer)?;
+    }
+    self.writer.flush()?;
+    self.written += journal.entries.len();
+    journal.entries.clear();
+    Ok(())
+  }
+}
@@ -11,7 +11,6 @@ extern crate cpu_time;
 extern crate derivative;
 #[macro_use]
 extern crate derive_more;
-#[macro_use]
 extern crate lazy_static;
 #[macro_use]
 extern crate log;
@@ -48,11 +47,12 @@ pub mod biology;
 pub mod combat;
 pub mod command;
 pub mod condition;
+pub mod containment;
+pub mod disposition;
 pub mod downdelving;
 pub mod economics;
 pub mod ecs;
 pub mod effect;
-pub mod formatting;
 pub mod game;
 pub mod gender;
 pub mod geology;
@@ -60,9 +60,12 @@ pub mod goap;
 pub mod initiative;
 pub mod input;
 pub mod intent;
+pub mod journal;
 pub mod linguistics;
 pub mod map;
 pub mod mythopoetics;
+pub mod need;
+pub mod output;
 pub mod perception;
 pub mod priority;
 pub mod scripting;
@@ -73,7 +76,13 @@ pub mod supernatural;
 #[cfg(test)]
 pub mod test {
 
+  use crate::ailment::Ailment;
+  use crate::ecs::component::{HasAilments, HasName, IsInRoom};
+  use crate::ecs::resource::PlayerResource;
+  use crate::game::{GameSettings, Headless};
+  use crate::map::MapBuilderKind;
   use pretty_env_logger::env_logger::builder;
+  use specs::prelude::*;
   use std::env::set_var;
 
   #[allow(unused_imports)]
@@ -83,4 +92,81 @@ pub mod test {
     let _ = builder().is_test(true).try_init();
     set_var("RUST_BACKTRACE", "1");
   }
+
+  /// A headless game on the compass-rose map, which most tests play through.
+  pub fn compass_rose_headless() -> Headless {
+    Headless::from_settings(&GameSettings {
+      map_builder: MapBuilderKind::CompassRose,
+      ..GameSettings::default()
+    })
+  }
+
+  /// Get everything shown in response to the last input.
+  pub fn get_response(headless: &Headless) -> Vec<String> {
+    let start = headless
+      .transcript
+      .iter()
+      .rposition(|line| line.starts_with("> "))
+      .unwrap();
+    headless.transcript[start + 1..].to_vec()
+  }
+
+  /// Whether any line of the response to the last input contains a string.
+  pub fn response_contains(headless: &Headless, string: &str) -> bool {
+    get_response(headless).iter().any(|line| line.contains(string))
+  }
+
+  /// Assert that the response to the last input contains a string.
+  pub fn assert_response_contains(headless: &Headless, string: &str) {
+    assert!(
+      response_contains(headless, string),
+      "expected {:?} in {:#?}",
+      string,
+      get_response(headless)
+    );
+  }
+
+  /// Assert that the response to the last input doesn't contain a string.
+  pub fn assert_response_lacks(headless: &Headless, string: &str) {
+    assert!(
+      !response_contains(headless, string),
+      "did not expect {:?} in {:#?}",
+      string,
+      get_response(headless)
+    );
+  }
+
+  /// Get the player's entity.
+  pub fn get_player(headless: &Headless) -> Entity {
+    let ecs = &headless.game.ecs;
+    ecs
+      .entities()
+      .entity(ecs.read_resource::<PlayerResource>().0.unwrap().0)
+  }
+
+  /// Replace whatever ailments the player has with these.
+  pub fn set_player_ailments(headless: &mut Headless, ailments: &[Ailment]) {
+    let player = get_player(headless);
+    let has_ailments = HasAilments(ailments.iter().cloned().collect());
+    let ecs = &mut headless.game.ecs;
+    ecs.write_storage::<HasAilments>().insert(player, has_ailments).unwrap();
+  }
+
+  /// Get the first entity with the given name, e.g. "Goblin".
+  pub fn get_named_entity(headless: &Headless, name: &str) -> Entity {
+    let ecs = &headless.game.ecs;
+    (&ecs.entities(), &ecs.read_storage::<HasName>())
+      .join()
+      .find(|(_entity, has_name)| has_name.0 == name)
+      .map(|(entity, _has_name)| entity)
+      .unwrap()
+  }
+
+  /// Get the name of the room the player is in.
+  pub fn get_player_room_name(headless: &Headless) -> String {
+    let ecs = &headless.game.ecs;
+    let room_id = ecs.read_storage::<IsInRoom>().get(get_player(headless)).unwrap().0;
+    let room = ecs.entities().entity(room_id.0);
+    ecs.read_storage::<HasName>().get(room).unwrap().0.clone()
+  }
 }
@@ -1,15 +1,132 @@
 use log::LevelFilter;
 use simplelog::Config;
 use simplelog::WriteLogger;
+use std::env::args;
+use std::fs::File;
+use std::io::Write;
+use std::process::exit;
 
 use hornvale::game::Game;
 use hornvale::game::GameError;
+use hornvale::game::GameSettings;
+use hornvale::input::history::get_default_history_path;
+use hornvale::map::MapBuilderKind;
+
+/// Options parsed from the command line.
+#[derive(Clone, Debug)]
+pub struct Options {
+  /// The settings for the world itself.
+  pub settings: GameSettings,
+  /// How much to log.
+  pub log_level: LevelFilter,
+  /// Where to log, if not to the terminal.
+  pub log_file: Option<String>,
+  /// Where to record the journal, if anywhere.
+  pub journal_file: Option<String>,
+  /// Where to keep the input history, if anywhere.
+  pub history_file: Option<String>,
+}
+
+/// Print usage information.
+pub fn print_usage() {
+  let map_builders = MapBuilderKind::get_all()
+    .iter()
+    .map(|kind| kind.to_string())
+    .collect::<Vec<String>>()
+    .join("|");
+  eprintln!("Usage: hornvale [OPTIONS]");
+  eprintln!();
+  eprintln!("Options:");
+  eprintln!("  --seed <SEED>         The seed for the world (random by default)");
+  eprintln!("  --map <MAP>           The map builder to use ({})", map_builders);
+  eprintln!("  --log-level <LEVEL>   off|error|warn|info|debug|trace (off by default)");
+  eprintln!("  --log-file <PATH>     Write the log to a file rather than the terminal");
+  eprintln!("  --journal <PATH>      Record every change to the world, for replay");
+  eprintln!("  --history <PATH>      Keep the input history here (~/.hornvale_history by default)");
+  eprintln!("  --no-history          Don't keep the input history");
+  eprintln!("  --help                Print this message");
+}
+
+/// Parse the command-line arguments (not including the program name).
+pub fn parse_options(arguments: &[String]) -> Result<Options, String> {
+  let mut seed = None;
+  let mut map_builder = MapBuilderKind::default();
+  let mut log_level = LevelFilter::Off;
+  let mut log_file = None;
+  let mut journal_file = None;
+  let mut history_file = Some(get_default_history_path().to_string_lossy().into_owned());
+  let mut arguments = arguments.iter();
+  while let Some(argument) = arguments.next() {
+    let mut value = || {
+      arguments
+        .next()
+        .cloned()
+        .ok_or_else(|| format!("missing value for {}", argument))
+    };
+    match argument.as_str() {
+      "--seed" => seed = Some(value()?),
+      "--map" => map_builder = value()?.parse().map_err(|error| format!("{}", error))?,
+      "--log-level" => {
+        let level = value()?;
+        log_level = level.parse().map_err(|_| format!("unknown log level ({})", level))?;
+      },
+      "--log-file" => log_file = Some(value()?),
+      "--journal" => journal_file = Some(value()?),
+      "--history" => history_file = Some(value()?),
+      "--no-history" => history_file = None,
+      unknown => return Err(format!("unknown option ({})", unknown)),
+    }
+  }
+  let mut settings = match seed {
+    Some(seed) => GameSettings::new(&seed),
+    None => GameSettings::with_random_seed(),
+  };
+  settings.map_builder = map_builder;
+  Ok(Options {
+    settings,
+    log_level,
+    log_file,
+    journal_file,
+    history_file,
+  })
+}
 
 #[async_std::main]
 async fn main() -> Result<(), GameError> {
-  let mut game = Game::new("goat boy");
-  let stdout = game.output.clone();
-  WriteLogger::init(LevelFilter::Off, Config::default(), stdout).unwrap();
+  let arguments = args().skip(1).collect::<Vec<String>>();
+  if arguments
+    .iter()
+    .any(|argument| argument == "--help" || argument == "-h")
+  {
+    print_usage();
+    exit(0);
+  }
+  let options = parse_options(&arguments).unwrap_or_else(|error| {
+    eprintln!("Error: {}\n", error);
+    print_usage();
+    exit(2);
+  });
+  let mut game = Game::from_settings(&options.settings);
+  let mut stdout = game.output.clone().unwrap();
+  match &options.log_file {
+    Some(path) => WriteLogger::init(options.log_level, Config::default(), File::create(path)?).unwrap(),
+    None => WriteLogger::init(options.log_level, Config::default(), stdout.clone()).unwrap(),
+  }
+  // Print the seed, so that the world can be recreated from a bug report.
+  writeln!(
+    stdout,
+    "Seed: {} (map: {})\n",
+    options.settings.seed, options.settings.map_builder
+  )?;
+  if let Some(path) = &options.journal_file {
+    game.record_journal(path)?;
+  }
+  if let Some(path) = &options.history_file {
+    // Losing the history isn't worth refusing to play over.
+    if let Err(error) = game.record_history(path) {
+      writeln!(stdout, "Unable to keep the input history in {} ({})\n", path, error)?;
+    }
+  }
   game.run().await?;
   Ok(())
 }
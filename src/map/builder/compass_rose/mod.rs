@@ -1,5 +1,7 @@
-use super::super::Direction;
-use crate::ecs::entity::RoomId;
+use super::super::{Direction, PassageProperties};
+use crate::condition::Condition;
+use crate::containment::Containment;
+use crate::ecs::entity::{EntityId, RoomId};
 use crate::ecs::system::create_map::CreateMapData as Data;
 use crate::effect::*;
 
@@ -15,22 +17,49 @@ impl<'a> CompassRose {
         data,
         create_effect!(EntityLooksAround {
           entity_id: player_id.into(),
+          verbose: false,
         })
       );
     }
-    let _mushroom = create_object!(
+    let mushroom = create_object!(
       data,
       "Mushroom",
       "A speckled mushroom grows out of the sodden earth, on a long stalk.",
       RoomId(spawn_room.id())
     );
-    let _goblin = create_actor!(
+    has_adjectives!(data, mushroom, ["speckled"]);
+    let basket = create_object!(
+      data,
+      "Basket",
+      "A wicker basket lies on its side among the roots.",
+      RoomId(spawn_room.id())
+    );
+    has_adjectives!(data, basket, ["wicker"]);
+    has_capacity!(data, basket, Containment::In, 5, 10);
+    let stone = create_object!(
+      data,
+      "Stone",
+      "A broad, flat stone juts out of the ground.",
+      RoomId(spawn_room.id())
+    );
+    has_adjectives!(data, stone, ["broad", "flat"]);
+    has_capacity!(data, stone, Containment::On, 3, 30);
+    has_weight!(data, stone, 200);
+    let key = create_object!(
+      data,
+      "Key",
+      "A small brass key glints in the mud.",
+      RoomId(spawn_room.id())
+    );
+    has_adjectives!(data, key, ["small", "brass"]);
+    let goblin = create_actor!(
       data,
       "Goblin",
       "The goblin is short, stout, and ugly.",
       Gender::Male,
       RoomId(spawn_room.id())
     );
+    has_adjectives!(data, goblin, ["short", "stout", "ugly"]);
     let ne_room = create_room!(data, "Northeast Room", "This is the Northeastern Room.");
     let n_room = create_room!(data, "North Room", "This is the Northern Room.");
     let nw_room = create_room!(data, "Northwest Room", "This is the Northwestern Room.");
@@ -49,6 +78,50 @@ impl<'a> CompassRose {
     create_passage!(data, spawn_room, se_room, &Direction::Southeast, true);
     create_passage!(data, spawn_room, s_room, &Direction::South, true);
     create_passage!(data, spawn_room, sw_room, &Direction::Southwest, true);
+    // The shed is locked, and the key is close at hand.
+    let shed = create_room!(data, "Shed", "Rakes and spades hang from nails in the plank walls.");
+    create_passage!(data, w_room, shed, &Direction::West, true);
+    let door = create_door!(
+      data,
+      w_room,
+      &Direction::West,
+      "Door",
+      "A stout wooden door stands in the way.",
+      Some(EntityId(key.id()))
+    );
+    has_adjectives!(data, door, ["stout", "wooden"]);
+    // A hidden gap among the roots drops into a burrow; it's a squeeze on the
+    // way down, but the way back up is plain to see from below.
+    let burrow = create_room!(
+      data,
+      "Burrow",
+      "Pale roots thread the packed earth of the walls and ceiling."
+    );
+    create_passage!(
+      data,
+      s_room,
+      burrow,
+      &Direction::Down,
+      false,
+      PassageProperties {
+        is_hidden: true,
+        concealment: 50,
+        condition: Some(Condition::NotInMidair),
+        extra_cost: 20,
+        message: Some("You squeeze down between the roots.".to_string()),
+      }
+    );
+    create_passage!(
+      data,
+      burrow,
+      s_room,
+      &Direction::Up,
+      false,
+      PassageProperties {
+        message: Some("You scramble up through the roots.".to_string()),
+        ..PassageProperties::default()
+      }
+    );
     data.spawn_room_resource.0 = Some(RoomId(spawn_room.id()));
   }
 }
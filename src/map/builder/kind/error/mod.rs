@@ -0,0 +1,7 @@
+/// Errors encountered dealing with map builder kinds.
+#[derive(Clone, Debug, Deserialize, Eq, Error, Hash, PartialEq, Serialize)]
+pub enum Error {
+  /// Unknown map builder.
+  #[error("unknown map builder ({0})")]
+  UnknownMapBuilder(String),
+}
@@ -0,0 +1,73 @@
+use std::str::FromStr;
+
+use super::{CompassRoseBuilder, RandomBuilder, TrivialMazeBuilder};
+use crate::ecs::system::create_map::CreateMapData as Data;
+
+pub mod error;
+use error::Error;
+
+/// The `Kind` enum.
+///
+/// This selects which builder creates the map at the start of the game.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Display, Eq, Hash, PartialEq, Serialize)]
+pub enum Kind {
+  /// A single room with a passage in every direction.
+  #[display(fmt = "compass-rose")]
+  CompassRose,
+  /// A grid of rooms connected by a binary-tree maze.
+  #[display(fmt = "trivial-maze")]
+  TrivialMaze,
+  /// Any of the above, chosen by the seed.
+  #[display(fmt = "random")]
+  #[default]
+  Random,
+}
+
+impl Kind {
+  /// All of the kinds, for listing in help text and the like.
+  pub fn get_all() -> Vec<Kind> {
+    use Kind::*;
+    vec![CompassRose, TrivialMaze, Random]
+  }
+
+  /// Build the map.
+  pub fn build(&self, data: &mut Data) {
+    use Kind::*;
+    match self {
+      CompassRose => CompassRoseBuilder {}.build(data),
+      TrivialMaze => TrivialMazeBuilder {}.build(data),
+      Random => RandomBuilder {}.build(data),
+    }
+  }
+}
+
+impl FromStr for Kind {
+  type Err = Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    use Kind::*;
+    match string.to_lowercase().replace('_', "-").as_str() {
+      "compass-rose" => Ok(CompassRose),
+      "trivial-maze" => Ok(TrivialMaze),
+      "random" => Ok(Random),
+      _ => Err(Error::UnknownMapBuilder(string.to_owned())),
+    }
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_from_str() {
+    init();
+    for kind in Kind::get_all() {
+      assert_eq!(kind.to_string().parse::<Kind>().unwrap(), kind);
+    }
+    assert_eq!("Trivial_Maze".parse::<Kind>().unwrap(), Kind::TrivialMaze);
+    assert!("labyrinth".parse::<Kind>().is_err());
+  }
+}
@@ -1,5 +1,8 @@
 pub mod compass_rose;
 pub use compass_rose::CompassRose as CompassRoseBuilder;
+pub mod kind;
+pub use kind::error::Error as MapBuilderError;
+pub use kind::Kind as MapBuilderKind;
 pub mod random;
 pub use random::Random as RandomBuilder;
 pub mod trivial_maze;
@@ -0,0 +1,31 @@
+/// Errors encountered in opening, closing, locking or unlocking a door.
+///
+/// These are reported as the reason an action failed, so they're lowercase and
+/// unpunctuated, like the other action errors.
+#[derive(Clone, Copy, Debug, Eq, Error, Hash, PartialEq)]
+pub enum Error {
+  /// The door is already closed.
+  #[error("it is already closed")]
+  AlreadyClosed,
+  /// The door is already locked.
+  #[error("it is already locked")]
+  AlreadyLocked,
+  /// The door is already open.
+  #[error("it is already open")]
+  AlreadyOpen,
+  /// The door is already unlocked.
+  #[error("it is already unlocked")]
+  AlreadyUnlocked,
+  /// The door is locked, and won't open.
+  #[error("it is locked")]
+  Locked,
+  /// The door has no lock to speak of.
+  #[error("it has no lock")]
+  NoLock,
+  /// A door can't be locked while it's open.
+  #[error("you will have to close it first")]
+  Open,
+  /// The key doesn't belong to this door.
+  #[error("that does not fit the lock")]
+  WrongKey,
+}
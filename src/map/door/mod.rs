@@ -0,0 +1,115 @@
+use crate::ecs::entity::EntityId;
+
+pub mod error;
+pub use error::Error as DoorError;
+
+/// The `Door` type.
+///
+/// The state of a door.  A door is an entity of its own, shared by the
+/// passages on either side of it, so it looks the same from both.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Door {
+  /// Whether the door stands open.
+  pub is_open: bool,
+  /// Whether the door is locked.
+  pub is_locked: bool,
+  /// The key that fits the lock, if the door has one.
+  pub key_id: Option<EntityId>,
+}
+
+impl Door {
+  /// A closed door, locked with the given key if there is one.
+  pub fn new(key_id: Option<EntityId>) -> Self {
+    Self {
+      is_open: false,
+      is_locked: key_id.is_some(),
+      key_id,
+    }
+  }
+
+  /// Describe how the door looks, i.e. "open" or "closed".
+  ///
+  /// Whether it's locked can't be seen; it has to be tried.
+  pub fn get_lowercase(&self) -> &'static str {
+    match self.is_open {
+      true => "open",
+      false => "closed",
+    }
+  }
+
+  /// Check that the door can be opened.
+  pub fn check_open(&self) -> Result<(), DoorError> {
+    if self.is_open {
+      return Err(DoorError::AlreadyOpen);
+    }
+    if self.is_locked {
+      return Err(DoorError::Locked);
+    }
+    Ok(())
+  }
+
+  /// Check that the door can be closed.
+  pub fn check_close(&self) -> Result<(), DoorError> {
+    if !self.is_open {
+      return Err(DoorError::AlreadyClosed);
+    }
+    Ok(())
+  }
+
+  /// Check that the door can be locked with the given key.
+  pub fn check_lock(&self, key_id: EntityId) -> Result<(), DoorError> {
+    self.check_key(key_id)?;
+    if self.is_open {
+      return Err(DoorError::Open);
+    }
+    if self.is_locked {
+      return Err(DoorError::AlreadyLocked);
+    }
+    Ok(())
+  }
+
+  /// Check that the door can be unlocked with the given key.
+  pub fn check_unlock(&self, key_id: EntityId) -> Result<(), DoorError> {
+    self.check_key(key_id)?;
+    if !self.is_locked {
+      return Err(DoorError::AlreadyUnlocked);
+    }
+    Ok(())
+  }
+
+  /// Check that the door has a lock, and that the key fits it.
+  pub fn check_key(&self, key_id: EntityId) -> Result<(), DoorError> {
+    match self.key_id {
+      None => Err(DoorError::NoLock),
+      Some(door_key_id) if door_key_id != key_id => Err(DoorError::WrongKey),
+      Some(_) => Ok(()),
+    }
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_checks() {
+    init();
+    let key_id = EntityId(1);
+    let mut door = Door::new(Some(key_id));
+    assert_eq!(door.check_open(), Err(DoorError::Locked));
+    assert_eq!(door.check_unlock(EntityId(2)), Err(DoorError::WrongKey));
+    assert_eq!(door.check_unlock(key_id), Ok(()));
+    door.is_locked = false;
+    assert_eq!(door.check_open(), Ok(()));
+    door.is_open = true;
+    assert_eq!(door.get_lowercase(), "open");
+    assert_eq!(door.check_open(), Err(DoorError::AlreadyOpen));
+    assert_eq!(door.check_lock(key_id), Err(DoorError::Open));
+    assert_eq!(door.check_close(), Ok(()));
+    let door = Door::new(None);
+    assert_eq!(door.check_close(), Err(DoorError::AlreadyClosed));
+    assert_eq!(door.check_lock(key_id), Err(DoorError::NoLock));
+  }
+}
@@ -3,8 +3,11 @@ pub use builder::*;
 pub mod direction;
 pub use direction::error::Error as DirectionError;
 pub use direction::Direction;
+pub mod door;
+pub use door::{Door, DoorError};
 pub mod passage;
 pub use passage::destination::Destination as PassageDestination;
+pub use passage::properties::Properties as PassageProperties;
 pub use passage::Passage;
 pub mod tile;
 pub use tile::Tile;
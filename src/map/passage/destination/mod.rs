@@ -2,7 +2,8 @@ use crate::ecs::entity::RoomId;
 
 /// The `Destination` enum.
 ///
-/// At some point, I should add support for doors.
+/// Doors aren't destinations of their own; a passage to a room may have one
+/// across it.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize, Variation)]
 pub enum Destination {
   /// A message, in lieu of actual travel.
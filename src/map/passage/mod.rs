@@ -1,9 +1,11 @@
 use super::Direction;
 
-use crate::ecs::entity::RoomId;
+use crate::ecs::entity::{EntityId, RoomId};
 
 pub mod destination;
 use destination::Destination;
+pub mod properties;
+use properties::Properties;
 
 /// The `Passage` enum.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
@@ -14,4 +16,8 @@ pub struct Passage {
   pub from: RoomId,
   /// The destination to which this passage leads.
   pub to: Destination,
+  /// The door across this passage, if there is one.
+  pub door: Option<EntityId>,
+  /// Anything out of the ordinary about the passage.
+  pub properties: Properties,
 }
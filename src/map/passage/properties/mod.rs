@@ -0,0 +1,26 @@
+use crate::condition::Condition;
+
+/// The `Properties` of a passage.
+///
+/// The defaults describe an ordinary passage: in plain sight, open to anyone,
+/// and no harder to get through than any other.  A passage with no way back is
+/// simply one-way; see `create_passage!`.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Properties {
+  /// Whether the passage has yet to be found.
+  pub is_hidden: bool,
+  /// The chance, out of a hundred, that searching for it comes up empty.
+  pub concealment: u8,
+  /// What has to be true of anyone who would go this way.
+  pub condition: Option<Condition>,
+  /// The ticks it takes to get through, on top of the usual.
+  ///
+  /// This is the passage's cost in initiative, in effect: going through it is
+  /// an action in progress for that long, and the actor can do nothing else
+  /// meanwhile.  Unlike a debt of initiative, though, it can be stopped or
+  /// interrupted partway, which leaves the actor where they started.
+  pub extra_cost: usize,
+  /// What anyone going this way is told, e.g. "You squeeze between the
+  /// roots."
+  pub message: Option<String>,
+}
@@ -1,5 +1,6 @@
 use super::Tile;
 use crate::ecs::entity::RoomId;
+use crate::output::Span;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
@@ -82,27 +83,47 @@ impl Map {
     self.room_index_map.insert(room_id, index);
     self.room_coords_map.insert(room_id, (self.get_x(x), self.get_y(y)));
   }
-}
 
-impl Display for Map {
-  fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+  /// Get the visible tiles, with the player marked, one row per line.
+  pub fn get_visible_tiles(&self) -> Vec<Vec<Tile>> {
     let mut map = self.map.clone();
     if let Some((p_x, p_y)) = self.player_coordinates {
       map[p_y * self.width + p_x] = Tile::Player;
     }
-    let mut strings = Vec::new();
-    for y in 0..self.height {
-      for x in 0..self.width {
-        let index = y * self.width + x;
-        if self.visibility[index] {
-          strings.push(map[index].get_string());
-        } else {
-          strings.push(Tile::Void.get_string());
-        }
-      }
-      strings.push("\n".to_string());
+    (0..self.height)
+      .map(|y| {
+        (0..self.width)
+          .map(|x| {
+            let index = y * self.width + x;
+            if self.visibility[index] {
+              map[index]
+            } else {
+              Tile::Void
+            }
+          })
+          .collect()
+      })
+      .collect()
+  }
+
+  /// Get the visible tiles as styled spans.
+  pub fn get_spans(&self) -> Vec<Span> {
+    let mut spans = Vec::new();
+    for row in self.get_visible_tiles() {
+      spans.extend(row.iter().map(|tile| tile.get_span()));
+      spans.push(Span::new("\n"));
+    }
+    spans
+  }
+}
+
+impl Display for Map {
+  fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+    for row in self.get_visible_tiles() {
+      let string = row.iter().map(|tile| tile.get_char()).collect::<String>();
+      writeln!(formatter, "{}", string)?;
     }
-    write!(formatter, "{}", strings.join(""))
+    Ok(())
   }
 }
 
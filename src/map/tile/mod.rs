@@ -1,5 +1,7 @@
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
+use crate::output::{Color, Span, Style};
+
 pub mod map;
 pub use map::Map as TileMap;
 
@@ -40,15 +42,18 @@ impl Tile {
     }
   }
 
-  /// Get string.
-  pub fn get_string(&self) -> String {
-    format!("<fg_ext{}>{}<reset>", self.get_color_code(), self.get_char())
+  /// Get span.
+  pub fn get_span(&self) -> Span {
+    Span::styled(
+      &self.get_char().to_string(),
+      Style::foreground(Color::Extended(self.get_color_code())),
+    )
   }
 }
 
 impl Display for Tile {
   fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
-    write!(formatter, "{}", self.get_string())
+    write!(formatter, "{}", self.get_char())
   }
 }
 
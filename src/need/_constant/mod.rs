@@ -0,0 +1,2 @@
+/// How pressing a need can become; zero means it is fully satisfied.
+pub const MAX_NEED_LEVEL: u8 = 100;
@@ -1,3 +1,6 @@
+pub mod _constant;
+pub use _constant::*;
+
 /// The `Need` enum.
 ///
 /// This is basically a de-generalization of Maslow's Hierarchy of Needs.
@@ -8,7 +11,7 @@ pub enum Need {
   Meaning,
   Family,
   Friendship,
-  Intimacy,  
+  Intimacy,
   Trust,
   Acceptance,
   Health,
@@ -0,0 +1,11 @@
+/// The width to lay output out for when we can't tell how wide the screen is.
+pub const DEFAULT_WIDTH: usize = 80;
+
+/// The narrowest width we'll lay output out for, however narrow the screen.
+pub const MINIMUM_WIDTH: usize = 20;
+
+/// What marks each item of a list.
+pub const LIST_BULLET: &str = "  - ";
+
+/// What continues each item of a list onto subsequent lines.
+pub const LIST_INDENT: &str = "    ";
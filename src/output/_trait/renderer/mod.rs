@@ -0,0 +1,10 @@
+use crate::output::Document;
+
+/// The `Renderer` trait.
+///
+/// This turns a document into text for a particular front-end, e.g. a
+/// terminal, a log file, or a client speaking JSON.
+pub trait Renderer: Send + Sync {
+  /// Render the document.
+  fn render(&self, document: &Document) -> String;
+}
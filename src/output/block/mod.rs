@@ -0,0 +1,87 @@
+use crate::map::Direction;
+use crate::output::{Color, Span, Style, LIST_BULLET};
+
+/// The `Block` enum.
+///
+/// A document is a sequence of blocks.  Some are just text; others say what
+/// they are, e.g. a room header, so each renderer can present them however
+/// suits its front-end.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum Block {
+  /// Running text.
+  Paragraph(Vec<Span>),
+  /// The name of a room, shown above its description.
+  RoomHeader(String),
+  /// The directions in which one can leave a room.
+  Exits(Vec<Direction>),
+  /// A list of items, each of which is set off from the others.
+  List(Vec<Vec<Span>>),
+  /// Text whose layout must be kept exactly, e.g. a map.
+  Preformatted(Vec<Span>),
+}
+
+impl Block {
+  /// Lower this block into styled spans, for renderers that deal in text.
+  pub fn get_spans(&self) -> Vec<Span> {
+    use Block::*;
+    match self {
+      Paragraph(spans) | Preformatted(spans) => spans.clone(),
+      RoomHeader(name) => vec![Span::styled(name, Style::bold())],
+      Exits(directions) => vec![Span::styled(
+        &Self::describe_exits(directions),
+        Style::foreground(Color::Green),
+      )],
+      List(items) => {
+        let mut spans = Vec::new();
+        for (index, item) in items.iter().enumerate() {
+          if index > 0 {
+            spans.push(Span::new("\n"));
+          }
+          spans.push(Span::new(LIST_BULLET));
+          spans.extend(item.iter().cloned());
+        }
+        spans
+      },
+    }
+  }
+
+  /// Describe a list of exits in a sentence.
+  pub fn describe_exits(directions: &[Direction]) -> String {
+    let names = directions
+      .iter()
+      .map(|direction| direction.get_lowercase())
+      .collect::<Vec<&'static str>>();
+    match names.as_slice() {
+      [] => "There are no visible passages.".into(),
+      [only] => format!("There is a visible passage to the {}.", only),
+      [first, second] => format!("There are visible passages to the {} and {}.", first, second),
+      [others @ .., last] => format!("There are visible passages to the {}, and {}.", others.join(", "), last),
+    }
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_describe_exits() {
+    init();
+    use Direction::*;
+    assert_eq!(Block::describe_exits(&[]), "There are no visible passages.");
+    assert_eq!(
+      Block::describe_exits(&[North]),
+      "There is a visible passage to the north."
+    );
+    assert_eq!(
+      Block::describe_exits(&[North, East]),
+      "There are visible passages to the north and east."
+    );
+    assert_eq!(
+      Block::describe_exits(&[North, East, Up]),
+      "There are visible passages to the north, east, and up."
+    );
+  }
+}
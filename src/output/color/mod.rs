@@ -0,0 +1,68 @@
+/// The `Color` enum.
+///
+/// The basic terminal colors, plus the 256-color extended palette for the
+/// finer distinctions, e.g. the colors of map tiles.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum Color {
+  Black,
+  Red,
+  Green,
+  Yellow,
+  Blue,
+  Magenta,
+  Cyan,
+  White,
+  Extended(u8),
+}
+
+impl Color {
+  /// Get the ANSI parameters that set this as the foreground color.
+  pub fn get_foreground_code(&self) -> String {
+    use Color::*;
+    match self {
+      Extended(code) => format!("38;5;{}", code),
+      _ => format!("{}", 30 + self.get_offset()),
+    }
+  }
+
+  /// Get the ANSI parameters that set this as the background color.
+  pub fn get_background_code(&self) -> String {
+    use Color::*;
+    match self {
+      Extended(code) => format!("48;5;{}", code),
+      _ => format!("{}", 40 + self.get_offset()),
+    }
+  }
+
+  /// Get the offset of a basic color within the ANSI color codes.
+  fn get_offset(&self) -> u8 {
+    use Color::*;
+    match self {
+      Black => 0,
+      Red => 1,
+      Green => 2,
+      Yellow => 3,
+      Blue => 4,
+      Magenta => 5,
+      Cyan => 6,
+      White => 7,
+      Extended(_) => unreachable!(),
+    }
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_codes() {
+    init();
+    assert_eq!(Color::Green.get_foreground_code(), "32");
+    assert_eq!(Color::Green.get_background_code(), "42");
+    assert_eq!(Color::Extended(180).get_foreground_code(), "38;5;180");
+    assert_eq!(Color::Extended(180).get_background_code(), "48;5;180");
+  }
+}
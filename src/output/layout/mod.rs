@@ -0,0 +1,233 @@
+use crossterm::terminal::size as get_terminal_size;
+use unicode_width::UnicodeWidthChar;
+
+use crate::output::{Block, Document, Span, DEFAULT_WIDTH, LIST_BULLET, LIST_INDENT, MINIMUM_WIDTH};
+
+/// Measure how many columns a string takes up on the screen.
+///
+/// ANSI escape sequences take up no room at all, however long they are.
+pub fn get_display_width(string: &str) -> usize {
+  let mut result = 0;
+  let mut chars = string.chars();
+  while let Some(char) = chars.next() {
+    if char == '\x1B' {
+      // Skip a control sequence: `ESC [`, parameters, and a final letter.
+      if chars.next() == Some('[') {
+        for char in chars.by_ref() {
+          if ('\x40'..='\x7E').contains(&char) {
+            break;
+          }
+        }
+      }
+      continue;
+    }
+    result += char.width().unwrap_or(0);
+  }
+  result
+}
+
+/// The `Layout` type.
+///
+/// This is the stage between a document and a text renderer.  It wraps
+/// paragraphs on word boundaries to fit the screen and indents lists; the
+/// blocks it produces are preformatted, so nothing downstream rearranges them.
+///
+/// Front-ends that do their own layout, e.g. anything consuming JSON, should
+/// skip this stage altogether.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Layout {
+  /// The number of columns available.
+  pub width: usize,
+}
+
+impl Layout {
+  /// Lay out for a given width.
+  pub fn new(width: usize) -> Self {
+    Self {
+      width: width.max(MINIMUM_WIDTH),
+    }
+  }
+
+  /// Lay out for the width of the terminal.
+  pub fn from_terminal() -> Self {
+    match get_terminal_size() {
+      Ok((columns, _rows)) if columns > 0 => Self::new(columns as usize),
+      _ => Self::default(),
+    }
+  }
+
+  /// Lay out a document.
+  pub fn apply(&self, document: &Document) -> Document {
+    let blocks = document
+      .blocks
+      .iter()
+      .map(|block| {
+        use Block::*;
+        match block {
+          Preformatted(_) => block.clone(),
+          List(items) => {
+            let mut spans = Vec::new();
+            for (index, item) in items.iter().enumerate() {
+              if index > 0 {
+                spans.push(Span::new("\n"));
+              }
+              spans.extend(self.wrap(item, LIST_BULLET, LIST_INDENT));
+            }
+            Preformatted(spans)
+          },
+          _ => Preformatted(self.wrap(&block.get_spans(), "", "")),
+        }
+      })
+      .collect();
+    Document { blocks }
+  }
+
+  /// Wrap spans on word boundaries.
+  ///
+  /// The first line begins with `first_indent`, and every subsequent line with
+  /// `indent`.  Runs of spaces are collapsed, and line breaks in the text are
+  /// kept.  Words too long for a line of their own are left to overflow.
+  pub fn wrap(&self, spans: &[Span], first_indent: &str, indent: &str) -> Vec<Span> {
+    let mut result = Vec::new();
+    push_span(&mut result, Span::new(first_indent));
+    let mut line_start = get_display_width(first_indent);
+    let mut column = line_start;
+    // A space between words takes the style of the span it came from.
+    let mut pending_space: Option<Span> = None;
+    for span in spans {
+      for (line_index, line) in span.text.split('\n').enumerate() {
+        if line_index > 0 {
+          push_span(&mut result, Span::new("\n"));
+          push_span(&mut result, Span::new(indent));
+          line_start = get_display_width(indent);
+          column = line_start;
+          pending_space = None;
+        }
+        for (word_index, word) in line.split(' ').enumerate() {
+          if word_index > 0 {
+            pending_space = Some(Span {
+              text: " ".to_string(),
+              ..span.clone()
+            });
+          }
+          if word.is_empty() {
+            continue;
+          }
+          let width = get_display_width(word);
+          let space = pending_space.take().filter(|_| column > line_start);
+          if column > line_start && column + usize::from(space.is_some()) + width > self.width {
+            push_span(&mut result, Span::new("\n"));
+            push_span(&mut result, Span::new(indent));
+            line_start = get_display_width(indent);
+            column = line_start;
+          } else if let Some(space) = space {
+            push_span(&mut result, space);
+            column += 1;
+          }
+          push_span(
+            &mut result,
+            Span {
+              text: word.to_string(),
+              ..span.clone()
+            },
+          );
+          column += width;
+        }
+      }
+    }
+    result
+  }
+}
+
+impl Default for Layout {
+  fn default() -> Self {
+    Self::new(DEFAULT_WIDTH)
+  }
+}
+
+/// Add a span, merging it into the last one if they look the same.
+fn push_span(spans: &mut Vec<Span>, span: Span) {
+  if span.text.is_empty() {
+    return;
+  }
+  if let Some(last) = spans.last_mut() {
+    if last.style == span.style && last.entity_id == span.entity_id {
+      last.text.push_str(&span.text);
+      return;
+    }
+  }
+  spans.push(span);
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::ecs::entity::EntityId;
+  use crate::output::{Color, PlainRenderer, Renderer, Style};
+  use crate::test::*;
+
+  #[test]
+  pub fn test_get_display_width() {
+    init();
+    assert_eq!(get_display_width("goblin"), 6);
+    assert_eq!(get_display_width("\x1B[1mgoblin\x1B[0m"), 6);
+    assert_eq!(get_display_width("\x1B[38;5;162mgob\x1B[0mlin"), 6);
+  }
+
+  #[test]
+  pub fn test_wrap() {
+    init();
+    let layout = Layout::new(20);
+    let document = Document::from("The goblin is short,  stout, and ugly, and he smells.");
+    assert_eq!(
+      PlainRenderer.render(&layout.apply(&document)),
+      "The goblin is short,\nstout, and ugly, and\nhe smells."
+    );
+    let document = Document::from("First line.\nSecond line.");
+    assert_eq!(
+      PlainRenderer.render(&layout.apply(&document)),
+      "First line.\nSecond line."
+    );
+  }
+
+  #[test]
+  pub fn test_wrap_keeps_styles() {
+    init();
+    let layout = Layout::new(20);
+    let style = Style::foreground(Color::Extended(162));
+    let spans = layout.wrap(
+      &[
+        Span::new("You see a very ugly "),
+        Span::entity(EntityId(1), "green goblin", style),
+        Span::new(" here."),
+      ],
+      "",
+      "",
+    );
+    assert_eq!(
+      spans,
+      vec![
+        Span::new("You see a very ugly\n"),
+        Span::entity(EntityId(1), "green goblin", style),
+        Span::new(" here."),
+      ]
+    );
+  }
+
+  #[test]
+  pub fn test_wrap_list() {
+    init();
+    let layout = Layout::new(20);
+    let document = Document {
+      blocks: vec![Block::List(vec![
+        vec![Span::new("a speckled mushroom growing on a long stalk")],
+        vec![Span::new("a goblin")],
+      ])],
+    };
+    assert_eq!(
+      PlainRenderer.render(&layout.apply(&document)),
+      "  - a speckled\n    mushroom growing\n    on a long stalk\n  - a goblin"
+    );
+  }
+}
@@ -0,0 +1,78 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+pub mod _constant;
+pub use _constant::*;
+pub mod _trait;
+pub use _trait::*;
+pub mod block;
+pub use block::Block;
+pub mod color;
+pub use color::Color;
+pub mod layout;
+pub use layout::{get_display_width, Layout};
+pub mod renderer;
+pub use renderer::*;
+pub mod span;
+pub use span::Span;
+pub mod style;
+pub use style::Style;
+pub mod verbosity;
+pub use verbosity::Verbosity;
+
+/// The `Document` type.
+///
+/// This is a piece of structured output: a sequence of blocks made of styled
+/// spans.  It says nothing about how it should look; that's left to whatever
+/// `Renderer` the front-end uses.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Document {
+  pub blocks: Vec<Block>,
+}
+
+impl Document {
+  /// Create an empty document.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Add a block to the end of the document.
+  pub fn push(&mut self, block: Block) {
+    self.blocks.push(block);
+  }
+
+  /// Add the blocks of another document to the end of this one.
+  pub fn append(&mut self, other: Document) {
+    self.blocks.extend(other.blocks);
+  }
+
+  /// Whether the document has nothing in it.
+  pub fn is_empty(&self) -> bool {
+    self.blocks.is_empty()
+  }
+}
+
+impl From<&str> for Document {
+  fn from(string: &str) -> Self {
+    Self {
+      blocks: vec![Block::Paragraph(vec![Span::new(string)])],
+    }
+  }
+}
+
+impl From<String> for Document {
+  fn from(string: String) -> Self {
+    Self::from(string.as_str())
+  }
+}
+
+impl From<&String> for Document {
+  fn from(string: &String) -> Self {
+    Self::from(string.as_str())
+  }
+}
+
+impl Display for Document {
+  fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+    write!(formatter, "{}", PlainRenderer.render(self))
+  }
+}
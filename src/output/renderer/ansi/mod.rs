@@ -0,0 +1,82 @@
+use crate::output::{Document, Renderer, Span};
+
+/// The `Ansi` renderer.
+///
+/// This presents styles with ANSI escape codes, for a terminal.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Ansi;
+
+impl Ansi {
+  /// Render a single span, resetting its style afterward.
+  pub fn render_span(&self, span: &Span) -> String {
+    let style = &span.style;
+    if style.is_plain() {
+      return span.text.clone();
+    }
+    let mut codes = Vec::new();
+    if style.bold {
+      codes.push("1".to_string());
+    }
+    if style.dim {
+      codes.push("2".to_string());
+    }
+    if style.italic {
+      codes.push("3".to_string());
+    }
+    if style.underline {
+      codes.push("4".to_string());
+    }
+    if let Some(color) = style.foreground {
+      codes.push(color.get_foreground_code());
+    }
+    if let Some(color) = style.background {
+      codes.push(color.get_background_code());
+    }
+    format!("\x1B[{}m{}\x1B[0m", codes.join(";"), span.text)
+  }
+}
+
+impl Renderer for Ansi {
+  fn render(&self, document: &Document) -> String {
+    document
+      .blocks
+      .iter()
+      .map(|block| {
+        block
+          .get_spans()
+          .iter()
+          .map(|span| self.render_span(span))
+          .collect::<String>()
+      })
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::output::{Block, Color, Style};
+  use crate::test::*;
+
+  #[test]
+  pub fn test_render() {
+    init();
+    assert_eq!(Ansi.render(&"innocuous string".into()), "innocuous string");
+    let document = Document {
+      blocks: vec![
+        Block::RoomHeader("Kitchen".into()),
+        Block::Paragraph(vec![
+          Span::new("A "),
+          Span::styled("goblin", Style::foreground(Color::Extended(162))),
+          Span::new(" is here."),
+        ]),
+      ],
+    };
+    assert_eq!(
+      Ansi.render(&document),
+      "\x1B[1mKitchen\x1B[0m\nA \x1B[38;5;162mgoblin\x1B[0m is here."
+    );
+  }
+}
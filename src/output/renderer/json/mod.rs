@@ -0,0 +1,34 @@
+use crate::output::{Document, Renderer};
+
+/// The `Json` renderer.
+///
+/// This serializes the document as is, structure and all, for external
+/// front-ends that want to do their own presentation.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Json;
+
+impl Renderer for Json {
+  fn render(&self, document: &Document) -> String {
+    serde_json::to_string(document).unwrap()
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::map::Direction;
+  use crate::output::Block;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_render() {
+    init();
+    let document = Document {
+      blocks: vec![Block::Exits(vec![Direction::North, Direction::Up])],
+    };
+    let string = Json.render(&document);
+    assert_eq!(string, r#"{"blocks":[{"Exits":["North","Up"]}]}"#);
+    assert_eq!(serde_json::from_str::<Document>(&string).unwrap(), document);
+  }
+}
@@ -0,0 +1,6 @@
+pub mod ansi;
+pub use ansi::Ansi as AnsiRenderer;
+pub mod json;
+pub use json::Json as JsonRenderer;
+pub mod plain;
+pub use plain::Plain as PlainRenderer;
@@ -0,0 +1,49 @@
+use crate::output::{Document, Renderer};
+
+/// The `Plain` renderer.
+///
+/// This drops all styling, leaving only the text; it's meant for logs,
+/// transcripts, and tests.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Plain;
+
+impl Renderer for Plain {
+  fn render(&self, document: &Document) -> String {
+    document
+      .blocks
+      .iter()
+      .map(|block| {
+        block
+          .get_spans()
+          .iter()
+          .map(|span| span.text.as_str())
+          .collect::<String>()
+      })
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::map::Direction;
+  use crate::output::Block;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_render() {
+    init();
+    let document = Document {
+      blocks: vec![
+        Block::RoomHeader("Kitchen".into()),
+        Block::Exits(vec![Direction::North]),
+      ],
+    };
+    assert_eq!(
+      Plain.render(&document),
+      "Kitchen\nThere is a visible passage to the north."
+    );
+  }
+}
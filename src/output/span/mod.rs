@@ -0,0 +1,42 @@
+use crate::ecs::entity::EntityId;
+use crate::output::Style;
+
+/// The `Span` type.
+///
+/// A run of text with a single style.  A span may also refer to an entity,
+/// e.g. the goblin in a room description, so that a front-end can make it
+/// something the player can click on or hover over.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Span {
+  pub text: String,
+  pub style: Style,
+  pub entity_id: Option<EntityId>,
+}
+
+impl Span {
+  /// Create an unstyled span.
+  pub fn new(text: &str) -> Self {
+    Self {
+      text: text.to_string(),
+      ..Self::default()
+    }
+  }
+
+  /// Create a styled span.
+  pub fn styled(text: &str, style: Style) -> Self {
+    Self {
+      text: text.to_string(),
+      style,
+      ..Self::default()
+    }
+  }
+
+  /// Create a styled span that refers to an entity.
+  pub fn entity(entity_id: EntityId, text: &str, style: Style) -> Self {
+    Self {
+      text: text.to_string(),
+      style,
+      entity_id: Some(entity_id),
+    }
+  }
+}
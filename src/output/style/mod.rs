@@ -0,0 +1,38 @@
+use crate::output::Color;
+
+/// The `Style` type.
+///
+/// How a span of text should be presented.  Renderers that can't present a
+/// style, e.g. plain text, are free to ignore it.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Style {
+  pub bold: bool,
+  pub dim: bool,
+  pub italic: bool,
+  pub underline: bool,
+  pub foreground: Option<Color>,
+  pub background: Option<Color>,
+}
+
+impl Style {
+  /// A bold style.
+  pub fn bold() -> Self {
+    Self {
+      bold: true,
+      ..Self::default()
+    }
+  }
+
+  /// A style with only a foreground color.
+  pub fn foreground(color: Color) -> Self {
+    Self {
+      foreground: Some(color),
+      ..Self::default()
+    }
+  }
+
+  /// Whether this style changes nothing about the text.
+  pub fn is_plain(&self) -> bool {
+    *self == Self::default()
+  }
+}
@@ -0,0 +1,11 @@
+/// The `Verbosity` enum.
+///
+/// How much a player wants to be told about a room upon entering it.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum Verbosity {
+  /// Describe a room in full only the first time it's visited.
+  Brief,
+  /// Describe a room in full every time.
+  #[default]
+  Verbose,
+}
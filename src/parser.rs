@@ -5,8 +5,7 @@ use hornvale::ecs::component::*;
 use hornvale::ecs::entity::{EntityId, PlayerId};
 use hornvale::ecs::resource::*;
 use hornvale::input::Input as InputSystem;
-use hornvale::input::ParserData;
-use rand_seeder::SipHasher;
+use hornvale::input::{ParserContext, ParserData, Referent};
 use specs::prelude::*;
 use std::env::args;
 use std::io::{self, BufRead, Write as IoWrite};
@@ -16,6 +15,7 @@ pub struct InputProcessor {
   pub input: Option<String>,
   pub output: Option<String>,
   pub input_system: InputSystem,
+  pub context: ParserContext,
 }
 
 impl InputProcessor {}
@@ -36,29 +36,25 @@ impl<'a> ParserData for Data<'a> {
   fn get_player_id(&self) -> Result<PlayerId, Error> {
     Ok(PlayerId(3))
   }
-  /// Retrieve a list of nouns.
-  fn get_nouns(&self) -> Result<Vec<(String, EntityId)>, Error> {
-    Ok(vec![
-      ("cow".to_string(), EntityId(1)),
-      ("echo".to_string(), EntityId(2)),
-      ("priest".to_string(), EntityId(3)),
-      ("rock".to_string(), EntityId(4)),
-    ])
-  }
-  /// Retrieve a list of adjectives.
-  fn get_adjectives(&self) -> Result<Vec<String>, Error> {
+  /// Retrieve everything the player might refer to.
+  fn get_referents(&self) -> Result<Vec<Referent>, Error> {
+    let referent = |id, name: &str, nouns: &[&str], adjectives: &[&str], owner_id, is_an_object| Referent {
+      entity_id: EntityId(id),
+      name: name.to_string(),
+      nouns: nouns.iter().map(|noun| noun.to_string()).collect(),
+      adjectives: adjectives.iter().map(|adjective| adjective.to_string()).collect(),
+      owner_id,
+      container_id: owner_id,
+      is_an_object,
+    };
     Ok(vec![
-      "brown".to_string(),
-      "green".to_string(),
-      "pale".to_string(),
-      "arrogant".to_string(),
-      "granite".to_string(),
+      referent(1, "Cow", &["cow"], &["brown"], None, false),
+      referent(2, "Echo", &["echo"], &[], None, false),
+      referent(3, "Priest", &["priest"], &["pale", "arrogant"], None, false),
+      referent(4, "Rock", &["rock"], &["granite"], Some(EntityId(3)), true),
+      referent(5, "Rock", &["rock"], &["green"], Some(EntityId(1)), true),
     ])
   }
-  /// Retrieve a list of genitives.
-  fn get_genitives(&self) -> Result<Vec<String>, Error> {
-    Ok(vec!["priest's".to_string(), "cow's".to_string()])
-  }
 }
 
 impl<'a> System<'a> for InputProcessor {
@@ -67,8 +63,8 @@ impl<'a> System<'a> for InputProcessor {
   /// Run system.
   fn run(&mut self, data: Self::SystemData) {
     let input = self.input.as_ref().unwrap().clone();
-    self.output = match self.input_system.interpret(&input, &data) {
-      Ok((_command, string_opt)) => Some(string_opt.unwrap_or_else(|| "OK".to_string())),
+    self.output = match self.input_system.interpret(&input, &data, &mut self.context) {
+      Ok((_commands, string_opt)) => Some(string_opt.unwrap_or_else(|| "OK".to_string())),
       Err(error) => Some(error.to_string()),
     };
   }
@@ -81,10 +77,8 @@ where
 {
   let mut ecs = World::new();
   let seed = "goat boy";
-  let rng = SipHasher::from(seed).into_rng();
-  ecs.insert(CameraResource(None));
   ecs.insert(PlayerResource(None));
-  ecs.insert(RandomResource(rng));
+  ecs.insert(RandomResource::new(seed));
   ecs.insert(SpawnRoomResource(None));
   ecs.insert(TickResource(0));
   ecs.insert(TileMapResource(None));
@@ -93,6 +87,7 @@ where
     input: None,
     output: None,
     input_system: InputSystem::default(),
+    context: ParserContext::default(),
   };
   loop {
     write!(&mut output, "> ")?;
@@ -1 +1,24 @@
+use crate::ecs::entity::EntityId;
 
+pub mod perspective;
+pub use perspective::Perspective;
+pub mod rendering;
+pub use rendering::Rendering;
+pub mod sensation;
+pub use sensation::Sensation;
+
+/// The `Perception` type.
+///
+/// This is a sensation as a single entity experienced it: the rendering it
+/// was given, from its own perspective.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Perception {
+  /// The entity doing the perceiving.
+  pub perceiver_id: EntityId,
+  /// How the perceiver was involved.
+  pub perspective: Perspective,
+  /// What the perceiver was told.
+  pub string: String,
+  /// What actually happened.
+  pub sensation: Sensation,
+}
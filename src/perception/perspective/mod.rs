@@ -0,0 +1,13 @@
+/// The `Perspective` enum.
+///
+/// This is how an entity is involved in something it perceives, which decides
+/// how that something is described to it.
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum Perspective {
+  /// The entity did it, e.g. "You look at the goblin."
+  FirstPerson,
+  /// It was done to the entity, e.g. "Player looks at you."
+  SecondPerson,
+  /// The entity only watched it happen, e.g. "Player looks at the goblin."
+  ThirdPerson,
+}
@@ -0,0 +1,27 @@
+use crate::perception::Perspective;
+
+/// The `Rendering` type.
+///
+/// These are the descriptions of a single sensation from each perspective.
+/// A perspective without a description doesn't notice anything.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Rendering {
+  /// For the entity doing it.
+  pub first_person: Option<String>,
+  /// For the entity it's done to; bystanders' description if missing.
+  pub second_person: Option<String>,
+  /// For everyone else watching.
+  pub third_person: Option<String>,
+}
+
+impl Rendering {
+  /// The description for a specific perspective, if any.
+  pub fn get(&self, perspective: Perspective) -> Option<&str> {
+    use Perspective::*;
+    match perspective {
+      FirstPerson => self.first_person.as_deref(),
+      SecondPerson => self.second_person.as_deref().or(self.third_person.as_deref()),
+      ThirdPerson => self.third_person.as_deref(),
+    }
+  }
+}
@@ -0,0 +1,76 @@
+use crate::ecs::entity::{EntityId, RoomId};
+use crate::perception::{Perspective, Rendering};
+
+/// The `Sensation` type.
+///
+/// This is something that happened and could be perceived, e.g. an entity
+/// walking into a room.  Sensations are objective; each entity that perceives
+/// one gets its own rendering of it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sensation {
+  /// The entity responsible for it.
+  pub actor_id: EntityId,
+  /// The entity it was done to, if any.
+  pub target_id: Option<EntityId>,
+  /// Where it can be perceived.
+  pub room_id: Option<RoomId>,
+  /// How it's described from each perspective.
+  pub rendering: Rendering,
+}
+
+impl Sensation {
+  /// How the specified entity is involved.
+  pub fn get_perspective(&self, perceiver_id: EntityId) -> Perspective {
+    if perceiver_id == self.actor_id {
+      Perspective::FirstPerson
+    } else if Some(perceiver_id) == self.target_id {
+      Perspective::SecondPerson
+    } else {
+      Perspective::ThirdPerson
+    }
+  }
+
+  /// The description for the specified entity, if it notices anything.
+  pub fn get_rendering_for(&self, perceiver_id: EntityId) -> Option<&str> {
+    self.rendering.get(self.get_perspective(perceiver_id))
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[test]
+  pub fn test_get_rendering_for() {
+    init();
+    let mut sensation = Sensation {
+      actor_id: EntityId(1),
+      target_id: Some(EntityId(2)),
+      room_id: Some(RoomId(0)),
+      rendering: Rendering {
+        first_person: Some("You look at the goblin.".into()),
+        second_person: Some("Player looks at you.".into()),
+        third_person: Some("Player looks at the goblin.".into()),
+      },
+    };
+    assert_eq!(
+      sensation.get_rendering_for(EntityId(1)),
+      Some("You look at the goblin.")
+    );
+    assert_eq!(sensation.get_rendering_for(EntityId(2)), Some("Player looks at you."));
+    assert_eq!(
+      sensation.get_rendering_for(EntityId(3)),
+      Some("Player looks at the goblin.")
+    );
+    // The target notices what everyone else does, if nothing more specific.
+    sensation.rendering.second_person = None;
+    assert_eq!(
+      sensation.get_rendering_for(EntityId(2)),
+      Some("Player looks at the goblin.")
+    );
+    sensation.rendering.first_person = None;
+    assert_eq!(sensation.get_rendering_for(EntityId(1)), None);
+  }
+}
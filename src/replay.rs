@@ -0,0 +1,23 @@
+use std::env::args;
+use std::process::exit;
+
+use hornvale::game::GameError;
+use hornvale::game::Headless;
+
+/// Rebuild a session from a journal file and print what happened.
+fn main() -> Result<(), GameError> {
+  let arguments = args().skip(1).collect::<Vec<String>>();
+  if arguments.len() != 1 {
+    eprintln!("Usage: replay <JOURNAL>");
+    exit(2);
+  }
+  let headless = Headless::replay(&arguments[0])?;
+  println!(
+    "Seed: {} (map: {})\n",
+    headless.game.settings.seed, headless.game.settings.map_builder
+  );
+  for line in headless.transcript.iter() {
+    println!("{}\n", line);
+  }
+  Ok(())
+}
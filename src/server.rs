@@ -0,0 +1,99 @@
+use std::env::args;
+use std::process::exit;
+
+use hornvale::game::GameError;
+use hornvale::game::GameSettings;
+use hornvale::game::Server;
+use hornvale::map::MapBuilderKind;
+use hornvale::output::{AnsiRenderer, JsonRenderer, PlainRenderer, Renderer};
+use hornvale::output::{Layout, DEFAULT_WIDTH};
+
+/// The address to listen on by default.
+const DEFAULT_ADDRESS: &str = "127.0.0.1:4000";
+
+/// Print usage information.
+fn print_usage() {
+  eprintln!("Usage: server [OPTIONS]");
+  eprintln!();
+  eprintln!("Options:");
+  eprintln!(
+    "  --address <ADDRESS>   The address to listen on ({} by default)",
+    DEFAULT_ADDRESS
+  );
+  eprintln!("  --seed <SEED>         The seed for the world (random by default)");
+  eprintln!("  --map <MAP>           The map builder to use");
+  eprintln!("  --format <FORMAT>     How to send output: ansi (default), plain, or json");
+  eprintln!(
+    "  --width <WIDTH>       The width to wrap text to ({} by default)",
+    DEFAULT_WIDTH
+  );
+  eprintln!("  --help                Print this message");
+}
+
+/// Serve a single world to any number of players over TCP.
+fn main() -> Result<(), GameError> {
+  pretty_env_logger::init();
+  let mut address = DEFAULT_ADDRESS.to_string();
+  let mut settings = GameSettings::with_random_seed();
+  let mut map_builder = MapBuilderKind::default();
+  let mut renderer: Box<dyn Renderer> = Box::new(AnsiRenderer);
+  let mut layout = Some(Layout::default());
+  let mut arguments = args().skip(1);
+  while let Some(argument) = arguments.next() {
+    let value = arguments.next();
+    match (argument.as_str(), value) {
+      ("--address", Some(value)) => address = value,
+      ("--seed", Some(value)) => settings = GameSettings::new(&value),
+      ("--map", Some(value)) => {
+        map_builder = value.parse().unwrap_or_else(|error| {
+          eprintln!("Error: {}\n", error);
+          print_usage();
+          exit(2);
+        })
+      },
+      ("--format", Some(value)) => {
+        renderer = match value.as_str() {
+          "ansi" => Box::new(AnsiRenderer),
+          "plain" => Box::new(PlainRenderer),
+          "json" => {
+            // JSON clients do their own layout.
+            layout = None;
+            Box::new(JsonRenderer)
+          },
+          _ => {
+            eprintln!("Error: unknown format \"{}\"\n", value);
+            print_usage();
+            exit(2);
+          },
+        }
+      },
+      ("--width", Some(value)) => match value.parse::<usize>() {
+        Ok(width) => layout = layout.map(|_| Layout::new(width)),
+        Err(_) => {
+          eprintln!("Error: \"{}\" is not a width\n", value);
+          print_usage();
+          exit(2);
+        },
+      },
+      ("--help" | "-h", _) => {
+        print_usage();
+        exit(0);
+      },
+      _ => {
+        print_usage();
+        exit(2);
+      },
+    }
+  }
+  settings.map_builder = map_builder;
+  let mut server = Server::bind(&address, &settings)?;
+  server.layout = layout;
+  server.renderer = renderer;
+  eprintln!(
+    "Listening on {} (seed: {}, map: {})",
+    server.local_addr()?,
+    settings.seed,
+    settings.map_builder
+  );
+  server.run()
+}